@@ -42,6 +42,8 @@ fn gen_c_file(gen_context: &GenContext, file: &File, gen_out_dir: &str) {
     // 公共头
     let mut ch_header = format!("
 #include <stdio.h>
+#include <stdint.h>
+#include <stdbool.h>
 
 #define API_EXPORT __attribute__((visibility(\"default\"))) __attribute__((used))
 
@@ -52,11 +54,12 @@ extern \"C\" {{
     // 收集所有需要生成 typedef 的类型名
     let mut typedef_names = vec![];
 
-    // 1. 首先收集文件中定义的类
+    // 1. 首先收集文件中定义的类，POD 结构体在下面生成 struct 定义
     for element in &file.children {
         match element {
+            HppElement::Class(class) if class.class_type == ClassType::PodStruct => {}
             HppElement::Class(class) => {
-                let typedef_name = class.type_str.to_string();
+                let typedef_name = class.get_ffi_name();
                 if !typedef_names.contains(&typedef_name) {
                     typedef_names.push(typedef_name.to_string());
                 }
@@ -73,6 +76,16 @@ extern \"C\" {{
         ch_header.push_str(&format!("typedef void* FFI_{};\n", typedef_name));
     }
 
+    // 4. 文件中定义和引用的 POD 结构体，生成内存布局相同的 struct
+    let mut pod_struct_names = vec![];
+    for element in &file.children {
+        collect_element_pod_struct_types(element, &mut pod_struct_names);
+    }
+    let mut defined_pod_struct_names = vec![];
+    for pod_struct_name in &pod_struct_names {
+        ch_header.push_str(&get_str_pod_struct_def(gen_context, pod_struct_name, &mut defined_pod_struct_names));
+    }
+
     ch_str.push_str(&ch_header);
     let mut cc_str = String::new();
     let cc_header = format!("
@@ -89,6 +102,26 @@ extern \"C\" {{
 
 #include \"{}\"
 
+#include <cstring>
+
+// POD 结构体和 ffi 中对应的 struct 内存布局相同，按字节复制
+template <typename To, typename From>
+static To ffi_pod_cast(const From& from) {{
+    static_assert(sizeof(To) == sizeof(From), \"POD 结构体和 ffi 中的 struct 大小不同\");
+    To to;
+    std::memcpy(&to, &from, sizeof(To));
+    return to;
+}}
+
+// const std::unique_ptr<T>& 参数只是借用 dart 对象的 native 对象，调用结束后放弃所有权，不释放对象
+template <typename T>
+struct ffi_borrowed_unique_ptr {{
+    std::unique_ptr<T> ptr;
+    explicit ffi_borrowed_unique_ptr(T* p) : ptr(p) {{}}
+    ~ffi_borrowed_unique_ptr() {{ ptr.release(); }}
+    operator const std::unique_ptr<T>&() const {{ return ptr; }}
+}};
+
 extern \"C\" {{
 
 ", hpp_filename, h_filename);
@@ -105,6 +138,10 @@ extern \"C\" {{
             HppElement::Class(class) => {
                 if class.is_callback() {
                     gen_c_callback_class(&mut c_context, class);
+                } else if class.class_type == ClassType::StdFunction {
+                    gen_c_stdfunction_class(&mut c_context, class);
+                } else if class.class_type == ClassType::PodStruct {
+                    // POD 结构体在头文件中生成 struct 定义，按值传递，不需要函数
                 } else {
                     gen_c_class(&mut c_context, class);
                 }
@@ -117,6 +154,13 @@ extern \"C\" {{
             HppElement::Enum(enum_def) => {
                 gen_c_enum(&mut c_context, enum_def);
             }
+            // 常量直接生成在 dart 中，不需要 c 代码
+            HppElement::Constant(_) => {
+            }
+            // 全局变量
+            HppElement::Variable(variable) => {
+                gen_c_variable(&mut c_context, None, variable);
+            }
             _ => {
                 // clang 解析出现问题时，可能会产生一些预期外的元素
                 // 跳过这些元素，避免程序崩溃
@@ -136,8 +180,8 @@ extern \"C\" {{
 "#;
     cc_str.push_str(&cc_footer);
 
-    ch_file.write_all(ch_str.as_bytes());
-    cc_file.write_all(cc_str.as_bytes());
+    ch_file.write_all(ch_str.as_bytes()).unwrap();
+    cc_file.write_all(cc_str.as_bytes()).unwrap();
 }
 
 fn gen_c_class(c_context: &mut CFileContext, class: &Class) {
@@ -151,18 +195,61 @@ c_context.ch_str.push_str(&c_class_decl);
             }
             HppElement::Field(field) => {
                 let (get_decl, set_decl) = get_str_field_decl(Some(&class), field);
-                c_context.ch_str.push_str(&format!("{}\n", get_decl));
-                c_context.ch_str.push_str(&format!("{}\n", set_decl));
-
                 let (get_impl, set_impl) = get_str_field_impl(Some(&class), field);
+                c_context.ch_str.push_str(&format!("{}\n", get_decl));
                 c_context.cc_str.push_str(&format!("{}\n", get_impl));
-                c_context.cc_str.push_str(&format!("{}\n", set_impl));
+                if field.has_setter() {
+                    c_context.ch_str.push_str(&format!("{}\n", set_decl));
+                    c_context.cc_str.push_str(&format!("{}\n", set_impl));
+                }
+            }
+            HppElement::Constant(_) => {
+            }
+            HppElement::Variable(variable) => {
+                gen_c_variable(c_context, Some(class), variable);
             }
             _ => {
                 unimplemented!("gen_c_class: unknown child, {:?}", child);
             }
         }
     }
+
+    gen_c_class_hash_code(c_context, class);
+
+    // 容器类从连续的内存批量构造，dart 中一次 ffi 调用复制所有元素
+    let bulk_element_types = class.get_bulk_element_types();
+    if let Some(container_type) = class.get_container_type_str().filter(|_| !bulk_element_types.is_empty()) {
+        let mut param_strs = vec![];
+        let mut element_strs = vec![];
+        let mut advance_strs = vec![];
+        for (name, element_type) in &bulk_element_types {
+            if element_type.type_kind == TypeKind::String {
+                // 所有字符串的 utf8 连续存放，每个字符串的长度单独传递
+                param_strs.push(format!("const char* {}, const uint64_t* {}_lengths", name, name));
+                element_strs.push(format!("std::string({}, {}_lengths[i])", name, name));
+                advance_strs.push(format!("\n        {} += {}_lengths[i];", name, name));
+            } else {
+                param_strs.push(format!("const {}* {}", get_str_ffi_type(element_type), name));
+                element_strs.push(format!("({}){}[i]", element_type.type_str, name));
+            }
+        }
+        let element_str = if element_strs.len() == 2 {
+            format!("std::make_pair({}, {})", element_strs[0], element_strs[1])
+        } else {
+            element_strs.join(", ")
+        };
+        let bulk_decl = format!("API_EXPORT FFI_{} ffi_{}_Constructor_from_buffer({}, uint64_t count);",
+            class.get_ffi_name(), class.get_ffi_name(), param_strs.join(", "));
+        let bulk_impl = format!("{} {{
+    auto ptr = new {}();
+    for (uint64_t i = 0; i < count; i++) {{
+        ptr->insert(ptr->end(), {});{}
+    }}
+    return (FFI_{})ptr;
+}}", bulk_decl.trim_end_matches(";"), container_type, element_str, advance_strs.join(""), class.get_ffi_name());
+        c_context.ch_str.push_str(&format!("{}\n", bulk_decl));
+        c_context.cc_str.push_str(&format!("{}\n", bulk_impl));
+    }
 }
 
 fn gen_c_class_method(c_context: &mut CFileContext, class: Option<&Class>, method: &Method) {
@@ -181,12 +268,26 @@ fn gen_c_class_method(c_context: &mut CFileContext, class: Option<&Class>, metho
 }
 
 /// 回调类
+/// 特化了 std::hash 的类，生成 hashCode，普通类和回调类都需要
+fn gen_c_class_hash_code(c_context: &mut CFileContext, class: &Class) {
+    if !class.has_std_hash {
+        return;
+    }
+    let hash_decl = format!("API_EXPORT int64_t ffi_{}_hashCode(FFI_{} obj);", class.get_ffi_name(), class.get_ffi_name());
+    let hash_impl = format!("{} {{
+    {}* ptr = ({}*)obj;
+    return (int64_t)std::hash<{}>()(*ptr);
+}}", hash_decl.trim_end_matches(";"), class.type_str, class.type_str, class.type_str);
+    c_context.ch_str.push_str(&format!("{}\n", hash_decl));
+    c_context.cc_str.push_str(&format!("{}\n", hash_impl));
+}
+
 fn gen_c_callback_class(c_context: &mut CFileContext, class: &Class) {
     let c_class_callback_decl = format!("\n");
     c_context.ch_str.push_str(&c_class_callback_decl);
 
     // 子类化的回调类的名字
-    let subclass_name = format!("Impl_{}", class.type_str);
+    let subclass_name = format!("Impl_{}", class.get_ffi_name());
 
     // 注册函数的声明和实现
     let mut regist_decl = String::new();
@@ -232,7 +333,7 @@ fn gen_c_callback_class(c_context: &mut CFileContext, class: &Class) {
                         // 生成方法 ID 常量
                         method_id_enums.push_str(&format!(
                             "static constexpr int64_t METHOD_ID_{}_{} = {};\n",
-                            class.type_str,
+                            class.get_ffi_name(),
                             method.name,
                             format!("0x{:016x}", {
                                 use std::collections::hash_map::DefaultHasher;
@@ -249,7 +350,7 @@ fn gen_c_callback_class(c_context: &mut CFileContext, class: &Class) {
                     regist_impl.push_str(&local_regist_impl);
                 }
             }
-            HppElement::Field(_field) => {
+            HppElement::Field(_) | HppElement::Constant(_) | HppElement::Variable(_) => {
                 // 回调类的字段在注册阶段不需要特殊处理，会在后续统一生成 getter/setter
             }
             _ => {
@@ -274,7 +375,7 @@ API_EXPORT void FFI_{}_setCallbackResult(int64_t request_id, int64_t result) {{
     callback_results[request_id] = result;
     callback_results_cv.notify_all();
 }}
-", method_id_enums, class.type_str)
+", method_id_enums, class.get_ffi_name())
     } else {
         String::new()
     };
@@ -283,7 +384,7 @@ API_EXPORT void FFI_{}_setCallbackResult(int64_t request_id, int64_t result) {{
     if has_sync_callback {
         c_context.ch_str.push_str(&format!("
 API_EXPORT void FFI_{}_setCallbackResult(int64_t request_id, int64_t result);
-", class.type_str));
+", class.get_ffi_name()));
     }
 
     // 生成回调子类
@@ -301,8 +402,8 @@ c_context.cc_str.push_str(&c_class_callback_impl);
                     c_context.cc_str.push_str(&callback_method_impl);
                 }
             }
-            HppElement::Field(_field) => {
-                // 回调子类继承父类的字段，不需要重新声明
+            HppElement::Field(_) | HppElement::Constant(_) | HppElement::Variable(_) => {
+                // 回调子类继承父类的字段、常量和静态变量，不需要重新声明
             }
             _ => {
                 unimplemented!("gen_c_callback_class: unknown child");
@@ -332,12 +433,18 @@ c_context.cc_str.push_str(&c_class_callback_impl);
             HppElement::Field(field) => {
                 // 为回调类的字段生成 getter 和 setter
                 let (get_decl, set_decl) = get_str_field_decl(Some(&class), field);
-                c_context.ch_str.push_str(&format!("{}\n", get_decl));
-                c_context.ch_str.push_str(&format!("{}\n", set_decl));
-
                 let (get_impl, set_impl) = get_str_field_impl(Some(&class), field);
+                c_context.ch_str.push_str(&format!("{}\n", get_decl));
                 c_context.cc_str.push_str(&format!("{}\n", get_impl));
-                c_context.cc_str.push_str(&format!("{}\n", set_impl));
+                if field.has_setter() {
+                    c_context.ch_str.push_str(&format!("{}\n", set_decl));
+                    c_context.cc_str.push_str(&format!("{}\n", set_impl));
+                }
+            }
+            HppElement::Constant(_) => {
+            }
+            HppElement::Variable(variable) => {
+                gen_c_variable(c_context, Some(class), variable);
             }
             _ => {
                 unimplemented!("gen_c_callback_class: unknown child");
@@ -345,6 +452,8 @@ c_context.cc_str.push_str(&c_class_callback_impl);
         }
     }
 
+    gen_c_class_hash_code(c_context, class);
+
     // 生成注册函数的实现
     c_context.cc_str.push_str(&regist_impl);
 
@@ -357,8 +466,8 @@ c_context.cc_str.push_str(&c_class_callback_impl);
 /// (get, set)
 fn get_str_field_decl(class: Option<&Class>, field: &Field) -> (String, String) {
     // ffi 中的类型名
-    let ffi_class_name = format!("FFI_{}", class.unwrap().type_str);
-    let cur_class_name = class.get_class_name_or_empty();
+    let ffi_class_name = format!("FFI_{}", class.unwrap().get_ffi_name());
+    let cur_class_name = class.get_ffi_class_name_or_empty();
 
     let get_decl = format!("API_EXPORT {} ffi_{}_get_{}({} obj);", 
         get_str_ffi_type(&field.field_type), cur_class_name, field.name, ffi_class_name);
@@ -387,9 +496,12 @@ fn get_str_field_impl(class: Option<&Class>, field: &Field) -> (String, String)
     );
     let ffi_to_cpp_param = get_str_ffi_to_cpp_param_field(&field.field_type, &field.name);
     let mut set_impl_body = format!("ptr->{} = {};", field.name, ffi_to_cpp_param);
-    if field.field_type.ptr_level > 0 && field.field_type.full_str.contains("[") {
+    if field.field_type.is_c_array {
         set_impl_body = format!("memcpy(ptr->{}, {}, sizeof(ptr->{}));", field.name, ffi_to_cpp_param, field.name);        
     }
+    else if field.field_type.is_std_array() {
+        set_impl_body = format!("memcpy(ptr->{}.data(), {}, sizeof(ptr->{}));", field.name, ffi_to_cpp_param, field.name);
+    }
     let set_impl = format!("{} {{
     {}* ptr = ({}*)obj;
     {}
@@ -452,9 +564,9 @@ fn get_str_callback_method_impl(class: Option<&Class>, method: &Method) -> Strin
     }
 
     // ffi 中的类型名
-    let ffi_class_name = format!("FFI_{}", class.unwrap().type_str);
+    let ffi_class_name = format!("FFI_{}", class.unwrap().get_ffi_name());
     // 指向函数指针的变量
-    let fun_ptr_var_str = format!("{}_{}", class.unwrap().type_str, method.name);
+    let fun_ptr_var_str = format!("{}_{}", class.unwrap().get_ffi_name(), method.name);
 
     // 根据返回值类型选择实现方式
     if callback_needs_sync_call(method) {
@@ -466,7 +578,7 @@ fn get_str_callback_method_impl(class: Option<&Class>, method: &Method) -> Strin
 
 /// 生成异步 callback 方法实现（void 返回值）
 fn get_str_callback_method_impl_async(class: Option<&Class>, method: &Method) -> String {
-    let fun_ptr_var_str = format!("{}_{}", class.unwrap().type_str, method.name);
+    let fun_ptr_var_str = format!("{}_{}", class.unwrap().get_ffi_name(), method.name);
 
     // .cpp 中的实现
     // 调用函数指针的函数实现
@@ -482,26 +594,7 @@ fn get_str_callback_method_impl_async(class: Option<&Class>, method: &Method) ->
     let mut values = vec!["&value0".to_string()];
     for i in 0..method.params.len() {
         let param = method.params.get(i).unwrap();
-        let (dart_type_enum, dart_type_set_value, convert_str) = get_str_callback_method_impl_dart_cobject_type(&param.field_type);
-        let mut param_name = param.name.clone();
-        if param.field_type.type_kind == TypeKind::String {
-            param_name = format!("{}.c_str()", param_name);
-        }
-        else if (param.field_type.type_kind == TypeKind::Class) && (param.field_type.ptr_level == 0) {
-            param_name = format!("(new {}({}))", param.field_type.type_str, param_name);
-        }
-        else if param.field_type.type_kind == TypeKind::StdPtr || param.field_type.type_kind == TypeKind::StdVector {
-            param_name = format!("(new {}({}))", param.field_type.full_str, param_name);
-        }
-        gen_values.push(format!("
-        Dart_CObject value{};
-        value{}.type = {};
-        value{}.value.{} = ({}){};
-        ",
-        i+1,
-        i+1, dart_type_enum,
-        i+1, dart_type_set_value, convert_str, param_name,
-        ));
+        gen_values.push(get_str_callback_param_cobject_value(&param.field_type, &param.name, i+1));
 
         values.push(format!("&value{}", i+1));
     }
@@ -511,7 +604,8 @@ fn get_str_callback_method_impl_async(class: Option<&Class>, method: &Method) ->
     // 生成返回语句（异步回调无法立即返回值，返回默认值）
     let return_stmt = match method.return_type.type_kind {
         TypeKind::Void => String::new(),
-        TypeKind::Int64 | TypeKind::Char => "\n        return 0;".to_string(),
+        TypeKind::Char => "\n        return 0;".to_string(),
+        ref kind if kind.is_integer() => "\n        return 0;".to_string(),
         TypeKind::Bool => "\n        return false;".to_string(),
         TypeKind::Float => "\n        return 0.0f;".to_string(),
         TypeKind::Double => "\n        return 0.0;".to_string(),
@@ -555,10 +649,120 @@ fn get_str_callback_method_impl_async(class: Option<&Class>, method: &Method) ->
     return ret_str;
 }
 
+/// 异步回调的参数转换为 Dart_CObject，变量名为 value{index}
+fn get_str_callback_param_cobject_value(field_type: &FieldType, param_name: &str, index: usize) -> String {
+    let (dart_type_enum, dart_type_set_value, convert_str) = get_str_callback_method_impl_dart_cobject_type(field_type);
+    let mut param_name = param_name.to_string();
+    if field_type.type_kind == TypeKind::String && !field_type.is_borrowed_string() {
+        param_name = format!("{}.c_str()", param_name);
+    }
+    else if (field_type.type_kind == TypeKind::Class) && (field_type.ptr_level == 0) {
+        param_name = format!("(new {}({}))", field_type.type_str, param_name);
+    }
+    else if field_type.type_kind == TypeKind::StdPtr || field_type.type_kind == TypeKind::StdVector {
+        param_name = format!("(new {}({}))", field_type.full_str.trim_start_matches("const ").trim_end_matches("&").trim(), param_name);
+    }
+    else if field_type.type_kind == TypeKind::StdOptional || field_type.type_kind == TypeKind::StdVariant
+    || field_type.type_kind == TypeKind::StdPair || field_type.type_kind == TypeKind::StdTuple {
+        // 复制一份，由 dart 对象负责释放
+        param_name = format!("(new {}({}))", field_type.type_str, param_name);
+    }
+    else if field_type.type_kind == TypeKind::StdDuration || field_type.type_kind == TypeKind::StdTimePoint {
+        param_name = get_str_chrono_to_int64(field_type, &param_name);
+    }
+    format!("
+        Dart_CObject value{};
+        value{}.type = {};
+        value{}.value.{} = ({}){};
+        ",
+        index,
+        index, dart_type_enum,
+        index, dart_type_set_value, convert_str, param_name,
+    )
+}
+
+/// 同步回调的参数转换为 int64_t 传给 dart 函数指针，float / double 需要先生成临时变量
+fn get_str_callback_param_sync_value(field_type: &FieldType, param_name: &str, index: usize, param_conversions: &mut Vec<String>) -> String {
+    match field_type.type_kind {
+        TypeKind::Bool => {
+            format!("(int64_t){}", param_name)
+        }
+        ref kind if kind.is_integer() => {
+            format!("(int64_t){}", param_name)
+        }
+        TypeKind::Float | TypeKind::Double => {
+            // 生成临时变量来转换 float/double 到 int64_t
+            let temp_var = format!("_param_{}", index);
+            param_conversions.push(format!(
+                "        {} *_ptr_{} = ({} *)&{};\n        int64_t {} = *((int64_t *)_ptr_{});",
+                field_type.full_str, index, field_type.full_str, param_name, temp_var, index
+            ));
+            temp_var
+        }
+        TypeKind::String if field_type.is_borrowed_string() => {
+            format!("(int64_t){}", param_name)
+        }
+        TypeKind::StdDuration | TypeKind::StdTimePoint => {
+            get_str_chrono_to_int64(field_type, param_name)
+        }
+        TypeKind::String => {
+            // String 需要转换为 const char* 指针
+            format!("(int64_t){}.c_str()", param_name)
+        }
+        TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple => {
+            // 复制一份，由 dart 对象负责释放
+            format!("(int64_t)(new {}({}))", field_type.type_str, param_name)
+        }
+        TypeKind::Class | TypeKind::StdPtr | TypeKind::StdVector if field_type.ptr_level == 0 && field_type.is_mutable_reference() => {
+            // 非 const 引用，dart 中的修改需要反映到原对象，调用期间参数一直有效，直接传地址
+            format!("(int64_t)&{}", param_name)
+        }
+        TypeKind::Class if field_type.ptr_level == 0 => {
+            // 复制一份，由 dart 对象负责释放
+            format!("(int64_t)(new {}({}))", field_type.type_str, param_name)
+        }
+        TypeKind::StdPtr | TypeKind::StdVector if field_type.ptr_level == 0 => {
+            // 复制一份，由 dart 对象负责释放
+            format!("(int64_t)(new {}({}))", field_type.full_str.trim_start_matches("const ").trim_end_matches("&").trim(), param_name)
+        }
+        _ => {
+            format!("(int64_t){}", param_name)
+        }
+    }
+}
+
+/// 同步回调中 dart 函数指针返回的 int64_t 转换为 c++ 的返回值
+fn get_str_callback_sync_return(return_type: &FieldType) -> String {
+    match return_type.type_kind {
+        ref kind if kind.is_integer() => format!("return ({})result;", return_type.type_str),
+        TypeKind::Bool => "return (bool)result;".to_string(),
+        TypeKind::Float => "return *((float *)&result);".to_string(),
+        TypeKind::Double => "return *((double *)&result);".to_string(),
+        TypeKind::StdDuration | TypeKind::StdTimePoint => format!("return {};", get_str_chrono_from_int64(return_type, "result")),
+        _ => "return result;".to_string(),
+    }
+}
+
+/// std::chrono 类型转换为 ffi 中的微秒数
+fn get_str_chrono_to_int64(field_type: &FieldType, value_str: &str) -> String {
+    if field_type.type_kind == TypeKind::StdTimePoint {
+        return format!("(int64_t)std::chrono::duration_cast<std::chrono::microseconds>(({}).time_since_epoch()).count()", value_str);
+    }
+    format!("(int64_t)std::chrono::duration_cast<std::chrono::microseconds>({}).count()", value_str)
+}
+
+/// ffi 中的微秒数转换为 std::chrono 类型，精度低于微秒的 duration 会截断
+fn get_str_chrono_from_int64(field_type: &FieldType, value_str: &str) -> String {
+    if field_type.type_kind == TypeKind::StdTimePoint {
+        return format!("{}(std::chrono::duration_cast<{}::duration>(std::chrono::microseconds({})))", field_type.type_str, field_type.type_str, value_str);
+    }
+    format!("std::chrono::duration_cast<{}>(std::chrono::microseconds({}))", field_type.type_str, value_str)
+}
+
 /// 生成同步 callback 方法实现（使用函数指针）
 /// C++ 直接调用 Dart 函数指针，避免事件循环阻塞
 fn get_str_callback_method_impl_sync(class: Option<&Class>, method: &Method) -> String {
-    let class_name = class.unwrap().type_str.as_str();
+    let class_name = class.unwrap().get_ffi_name();
     let method_name = &method.name;
 
     // 构造参数列表
@@ -573,25 +777,7 @@ fn get_str_callback_method_impl_sync(class: Option<&Class>, method: &Method) ->
         decl_params.push(format!("{} {}", param.field_type.full_str, param.name));
 
         // 转换参数为FFI类型
-        let param_call = match param.field_type.type_kind {
-            TypeKind::Int64 | TypeKind::Bool => {
-                format!("(int64_t){}", param.name)
-            }
-            TypeKind::Float | TypeKind::Double => {
-                // 生成临时变量来转换 float/double 到 int64_t
-                let temp_var = format!("_param_{}", i);
-                param_conversions.push(format!(
-                    "        {} *_ptr_{} = ({} *)&{};\n        int64_t {} = *((int64_t *)_ptr_{});",
-                    param.field_type.full_str, i, param.field_type.full_str, param.name, temp_var, i
-                ));
-                temp_var
-            }
-            TypeKind::String => {
-                // String 需要转换为 const char* 指针
-                format!("(int64_t){}.c_str()", param.name)
-            }
-            _ => format!("(int64_t){}", param.name)
-        };
+        let param_call = get_str_callback_param_sync_value(&param.field_type, &param.name, i, &mut param_conversions);
         call_params.push(param_call);
     }
 
@@ -605,7 +791,7 @@ fn get_str_callback_method_impl_sync(class: Option<&Class>, method: &Method) ->
 
     // 生成默认返回值
     let default_return = match method.return_type.type_kind {
-        TypeKind::Int64 => "0",
+        ref kind if kind.is_integer() => "0",
         TypeKind::Float | TypeKind::Double => "0.0",
         TypeKind::Bool => "false",
         _ => "0",
@@ -651,13 +837,7 @@ fn get_str_callback_method_impl_sync(class: Option<&Class>, method: &Method) ->
             default_return,
             param_conversions_str,
             fnptr_name, call_params_str,
-            match method.return_type.type_kind {
-                TypeKind::Int64 => "return (int)result;".to_string(),
-                TypeKind::Bool => "return (bool)result;".to_string(),
-                TypeKind::Float => "return *((float *)&result);".to_string(),
-                TypeKind::Double => "return *((double *)&result);".to_string(),
-                _ => "return result;".to_string(),
-            }
+            get_str_callback_sync_return(&method.return_type),
         )
     };
 
@@ -673,7 +853,7 @@ fn get_str_callback_method_impl_dart_cobject_type(field_type: &FieldType) -> (St
         TypeKind::Void => {
             return ("Dart_CObject_kNull".to_string(), "as_int64".to_string(), "int64_t".to_string());
         }
-        TypeKind::Int64 => {
+        ref kind if kind.is_integer() => {
             return ("Dart_CObject_kInt64".to_string(), "as_int64".to_string(), "int64_t".to_string());
         }
         TypeKind::Float | TypeKind::Double => {
@@ -688,9 +868,12 @@ fn get_str_callback_method_impl_dart_cobject_type(field_type: &FieldType) -> (St
         TypeKind::String => {
             return ("Dart_CObject_kString".to_string(), "as_string".to_string(), "char*".to_string());
         }
-        TypeKind::Class | TypeKind::StdPtr | TypeKind::StdVector => {
+        TypeKind::Class | TypeKind::StdPtr | TypeKind::StdVector | TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple | TypeKind::Enum => {
             return ("Dart_CObject_kInt64".to_string(), "as_int64".to_string(), "int64_t".to_string());
         }
+        TypeKind::StdDuration | TypeKind::StdTimePoint => {
+            ("Dart_CObject_kInt64".to_string(), "as_int64".to_string(), "int64_t".to_string())
+        }
         _ => {
             return ("".to_string(), "".to_string(), "".to_string());
         }
@@ -754,11 +937,11 @@ fn get_str_callback_method_regist(class: Option<&Class>, method: &Method) -> (St
 /// 生成异步回调的注册代码（void 返回值，使用 port）
 fn get_str_callback_method_regist_async(class: Option<&Class>, method: &Method) -> (String, String, String) {
     // ffi 中的类型名
-    let ffi_class_name = format!("FFI_{}", class.unwrap().type_str);
+    let ffi_class_name = format!("FFI_{}", class.unwrap().get_ffi_name());
     // 函数指针类型的名字
     let fun_ptr_type_str = format!("{}_{}", ffi_class_name, method.name);
     // 指向函数指针的变量
-    let fun_ptr_var_str = format!("{}_{}", class.unwrap().type_str, method.name);
+    let fun_ptr_var_str = format!("{}_{}", class.unwrap().get_ffi_name(), method.name);
     // 函数参数定义列表
     let params_decl_str = get_str_params_decl(class, method);
 
@@ -811,13 +994,13 @@ static std::mutex& get{}Mutex() {{
 /// 生成同步回调的注册代码（有返回值，使用 SendPort）
 fn get_str_callback_method_regist_sync(class: Option<&Class>, method: &Method) -> (String, String, String) {
     // ffi 中的类型名
-    let ffi_class_name = format!("FFI_{}", class.unwrap().type_str);
+    let ffi_class_name = format!("FFI_{}", class.unwrap().get_ffi_name());
     // 函数指针类型的名字
     let fun_ptr_type_str = format!("{}_{}_FnPtr", ffi_class_name, method.name);
     // 指向函数指针的变量
-    let fun_ptr_var_str = format!("{}_{}_fnptr", class.unwrap().type_str, method.name);
+    let fun_ptr_var_str = format!("{}_{}_fnptr", class.unwrap().get_ffi_name(), method.name);
     // Port 集合变量名（用于通过 SendPort 发送消息）
-    let port_var_str = format!("{}_{}", class.unwrap().type_str, method.name);
+    let port_var_str = format!("{}_{}", class.unwrap().get_ffi_name(), method.name);
 
     // 构造函数指针的参数列表（包含 this 指针和实际参数）
     // 注意：Dart 的 Pointer.fromFunction 要求所有参数都是 int64_t
@@ -836,7 +1019,7 @@ API_EXPORT void {}_register(FFI_{} obj, {} fnptr);
 API_EXPORT void {}_regist(int64_t {});
 ",
         return_type, fun_ptr_type_str, fnptr_params_str,
-        fun_ptr_type_str, class.unwrap().type_str, fun_ptr_type_str,
+        fun_ptr_type_str, class.unwrap().get_ffi_name(), fun_ptr_type_str,
         fun_ptr_type_str, method.name
     );
 
@@ -873,8 +1056,8 @@ API_EXPORT void {}_regist(int64_t {}){{
     get{}Set().insert({});
 }};
 ",
-        fun_ptr_type_str, class.unwrap().type_str, fun_ptr_type_str,
-        class.unwrap().type_str, fun_ptr_var_str,
+        fun_ptr_type_str, class.unwrap().get_ffi_name(), fun_ptr_type_str,
+        class.unwrap().get_ffi_name(), fun_ptr_var_str,
         fun_ptr_type_str, method.name,
         method.name,
         port_var_str,
@@ -886,7 +1069,9 @@ API_EXPORT void {}_regist(int64_t {}){{
 
 fn get_str_ffi_type(field_type: &FieldType) -> String {
     match field_type.type_kind {
-        TypeKind::Void | TypeKind::Int64 | TypeKind::Float | TypeKind::Double | TypeKind::Char | TypeKind::Bool => {
+        TypeKind::Void | TypeKind::Float | TypeKind::Double | TypeKind::Char | TypeKind::Bool |
+        TypeKind::Int8 | TypeKind::Int16 | TypeKind::Int32 | TypeKind::Int64 |
+        TypeKind::UInt8 | TypeKind::UInt16 | TypeKind::UInt32 | TypeKind::UInt64 => {
             if field_type.ptr_level == 0 {
                 return field_type.type_str.clone();
             } else {
@@ -900,6 +1085,14 @@ fn get_str_ffi_type(field_type: &FieldType) -> String {
         TypeKind::String => {
             return "const char*".to_string();
         }
+        TypeKind::U16String => {
+            // c 中没有 char16_t，使用 uint16_t
+            "const uint16_t*".to_string()
+        }
+        TypeKind::StdDuration | TypeKind::StdTimePoint => {
+            // 微秒数
+            "int64_t".to_string()
+        }
         TypeKind::Class => {
             // 清理类型名，移除const、&、*等修饰符
             let cleaned = field_type.type_str
@@ -909,7 +1102,10 @@ fn get_str_ffi_type(field_type: &FieldType) -> String {
                 .replace("*", "")
                 .replace(" ", "");
             let clean_type_str = cleaned.trim();
-            return format!("FFI_{}", clean_type_str);
+            format!("FFI_{}", get_ffi_type_name(clean_type_str))
+        }
+        TypeKind::PodStruct => {
+            format!("FFI_{}{}", get_ffi_type_name(&field_type.type_str), "*".repeat(field_type.ptr_level as usize))
         }
         TypeKind::StdPtr => {
             // 清理类型名
@@ -920,7 +1116,22 @@ fn get_str_ffi_type(field_type: &FieldType) -> String {
                 .replace("*", "")
                 .replace(" ", "");
             let clean_type_str = cleaned.trim();
-            return format!("FFI_StdPtr_{}", clean_type_str);
+            format!("FFI_StdPtr_{}", get_ffi_type_name(clean_type_str))
+        }
+        TypeKind::StdUniquePtr => {
+            // 传递的是被指向对象的指针，和 class 指针一样
+            let cleaned = field_type.type_str
+                .replace("const ", "")
+                .replace(" ", "");
+            let clean_type_str = cleaned.trim();
+            format!("FFI_{}", get_ffi_type_name(clean_type_str))
+        }
+        TypeKind::StdWeakPtr => {
+            let cleaned = field_type.type_str
+                .replace("const ", "")
+                .replace(" ", "");
+            let clean_type_str = cleaned.trim();
+            format!("FFI_StdWeakPtr_{}", get_ffi_type_name(clean_type_str))
         }
         TypeKind::StdVector => {
             if field_type.value_type.is_none() {
@@ -930,7 +1141,7 @@ fn get_str_ffi_type(field_type: &FieldType) -> String {
             if value_type.type_kind == TypeKind::String {
                 return format!("FFI_StdVector_String");
             } else {
-                return format!("FFI_StdVector_{}", field_type.get_value_type_str());
+                format!("FFI_StdVector_{}", get_ffi_type_name(&field_type.get_value_type_str()))
             }
         }
         TypeKind::StdMap => {
@@ -954,7 +1165,7 @@ fn get_str_ffi_type(field_type: &FieldType) -> String {
                 field_type.get_value_type_str()
             };
             
-            return format!("FFI_StdMap_{}_{}", key_type_str, value_type_str);
+            get_ffi_type_name(&format!("FFI_StdMap_{}_{}", key_type_str, value_type_str))
         }
         TypeKind::StdUnorderedMap => {
             if field_type.key_type.is_none() || field_type.value_type.is_none() {
@@ -975,7 +1186,7 @@ fn get_str_ffi_type(field_type: &FieldType) -> String {
                 field_type.get_value_type_str()
             };
 
-            return format!("FFI_StdUnorderedMap_{}_{}", key_type_str, value_type_str);
+            get_ffi_type_name(&format!("FFI_StdUnorderedMap_{}_{}", key_type_str, value_type_str))
         }
         TypeKind::StdSet => {
             if field_type.value_type.is_none() {
@@ -985,7 +1196,7 @@ fn get_str_ffi_type(field_type: &FieldType) -> String {
             if value_type.type_kind == TypeKind::String {
                 return format!("FFI_StdSet_String");
             } else {
-                return format!("FFI_StdSet_{}", field_type.get_value_type_str());
+                format!("FFI_StdSet_{}", get_ffi_type_name(&field_type.get_value_type_str()))
             }
         }
         TypeKind::StdUnorderedSet => {
@@ -996,7 +1207,31 @@ fn get_str_ffi_type(field_type: &FieldType) -> String {
             if value_type.type_kind == TypeKind::String {
                 return format!("FFI_StdUnorderedSet_String");
             } else {
-                return format!("FFI_StdUnorderedSet_{}", field_type.get_value_type_str());
+                format!("FFI_StdUnorderedSet_{}", get_ffi_type_name(&field_type.get_value_type_str()))
+            }
+        }
+        TypeKind::StdList | TypeKind::StdDeque | TypeKind::StdMultimap => {
+            format!("FFI_{}", get_ffi_type_name(&field_type.get_type_name_without_ptr()))
+        }
+        TypeKind::StdFunction => {
+            // dart 闭包的 id，见 gen_c_stdfunction_class
+            "int64_t".to_string()
+        }
+        TypeKind::StdVariant => {
+            format!("FFI_{}", get_ffi_type_name(&field_type.get_variant_type_str()))
+        }
+        TypeKind::StdPair | TypeKind::StdTuple => {
+            format!("FFI_{}", get_ffi_type_name(&field_type.get_tuple_type_str()))
+        }
+        TypeKind::StdOptional => {
+            if field_type.value_type.is_none() {
+                return "FFI_StdOptional_Unknown".to_string();
+            }
+            let value_type = field_type.value_type.as_deref().unwrap();
+            if value_type.type_kind == TypeKind::String {
+                "FFI_StdOptional_String".to_string()
+            } else {
+                format!("FFI_StdOptional_{}", get_ffi_type_name(&field_type.get_value_type_str()))
             }
         }
         _ => {
@@ -1031,21 +1266,25 @@ fn get_str_method_impl(class: Option<&Class>, method: &Method) -> String {
     return ({})new std::shared_ptr<{}>({});
 }};", method_prefix, param_prefix, impl_return_type, method.return_type.type_str, param_str);
             }
-            else if method.return_type.type_kind == TypeKind::StdVector {
+            else if method.return_type.type_kind == TypeKind::StdVector
+            || method.return_type.type_kind == TypeKind::StdList
+            || method.return_type.type_kind == TypeKind::StdDeque
+            {
                 method_impl = format!("{} {{
     {}
-    return ({})new std::shared_ptr<{}>({});
+    return ({})new {}({});
 }};", method_prefix, param_prefix, impl_return_type, method.return_type.type_str, param_str);
             }
-            else if method.return_type.type_kind == TypeKind::StdMap {
-                let container_type = method.return_type.full_str.clone();
+            else if method.return_type.type_kind == TypeKind::StdMap || method.return_type.type_kind == TypeKind::StdMultimap {
+                // 容器的 type_str 不带 const、引用修饰
+                let container_type = method.return_type.type_str.clone();
                 method_impl = format!("{} {{
     {}
     return ({})new {}({});
 }};", method_prefix, param_prefix, impl_return_type, container_type, param_str);
             }
             else if method.return_type.type_kind == TypeKind::StdUnorderedMap {
-                let container_type = method.return_type.full_str.clone();
+                let container_type = method.return_type.type_str.clone();
                 method_impl = format!("{} {{
     {}
     return ({})new {}({});
@@ -1053,7 +1292,7 @@ fn get_str_method_impl(class: Option<&Class>, method: &Method) -> String {
             }
             else if method.return_type.type_kind == TypeKind::StdSet {
                 // 确保使用正确的 std::set 类型
-                let container_type = method.return_type.full_str.clone();
+                let container_type = method.return_type.type_str.clone();
                 method_impl = format!("{} {{
     {}
     return ({})new {}({});
@@ -1061,11 +1300,23 @@ fn get_str_method_impl(class: Option<&Class>, method: &Method) -> String {
             }
             else if method.return_type.type_kind == TypeKind::StdUnorderedSet {
                 // 确保使用正确的 std::unordered_set 类型
-                let container_type = method.return_type.full_str.clone();
+                let container_type = method.return_type.type_str.clone();
                 method_impl = format!("{} {{
     {}
     return ({})new {}({});
 }};", method_prefix, param_prefix, impl_return_type, container_type, param_str);
+            }
+            else if method.return_type.type_kind == TypeKind::StdOptional {
+                method_impl = format!("{} {{
+    {}
+    return ({})new {}({});
+}};", method_prefix, param_prefix, impl_return_type, method.return_type.type_str, param_str);
+            }
+            else if method.return_type.type_kind == TypeKind::StdWeakPtr {
+                method_impl = format!("{} {{
+    {}
+    return ({})new std::weak_ptr<{}>({});
+}};", method_prefix, param_prefix, impl_return_type, method.return_type.type_str, param_str);
             }
             else {
                 method_impl = format!("{} {{
@@ -1083,7 +1334,12 @@ fn get_str_method_impl(class: Option<&Class>, method: &Method) -> String {
 }};", method_prefix, param_prefix)
         }
         MethodType::Normal => {
-            let impl_body = get_str_method_impl_body(class, &method.return_type, &method.name, Some(&param_str));
+            // 重载操作符直接调用 operator 方法，如 ptr->operator+(other)
+            let call_name = match &method.operator_str {
+                Some(operator_str) => format!("operator{}", operator_str),
+                None => method.name.clone(),
+            };
+            let impl_body = get_str_method_impl_body(class, &method.return_type, &call_name, Some(&param_str));
             method_impl = format!("{} {{
     {}
     {}
@@ -1129,9 +1385,39 @@ fn get_str_method_impl_body(class: Option<&Class>, return_field_type: &FieldType
         &format!("({})", param_str.unwrap()) 
     };
     
+    // 容器的迭代器方法，迭代器句柄为 new 出来的 const_iterator
+    if let Some(cur_class) = class {
+        if cur_class.is_container() && method_name.starts_with("iter_") {
+            let iterator_ptr_str = format!("(decltype(ptr->cbegin())*){}", param_str.unwrap_or("0"));
+            let is_map = matches!(cur_class.class_type, ClassType::StdMap | ClassType::StdMultimap | ClassType::StdUnorderedMap);
+            match method_name {
+                "iter_begin" => {
+                    return "return (int64_t)new auto(ptr->cbegin());".to_string();
+                }
+                "iter_valid" => {
+                    return format!("return *{} != ptr->cend();", iterator_ptr_str);
+                }
+                "iter_next" => {
+                    return format!("return (void)++*{};", iterator_ptr_str);
+                }
+                "iter_end" => {
+                    return format!("return delete {};", iterator_ptr_str);
+                }
+                "iter_key" => {
+                    return get_str_method_impl_body(None, return_field_type, &format!("(*{})->first", iterator_ptr_str), None);
+                }
+                "iter_value" => {
+                    let value_str = if is_map { format!("(*{})->second", iterator_ptr_str) } else { format!("**{}", iterator_ptr_str) };
+                    return get_str_method_impl_body(None, return_field_type, &value_str, None);
+                }
+                _ => {}
+            }
+        }
+    }
+
     // 特殊处理 Map 和 Set 的方法
     if let Some(cur_class) = class {
-        if cur_class.class_type == ClassType::StdMap {
+        if cur_class.class_type == ClassType::StdMap || cur_class.class_type == ClassType::StdMultimap {
             match method_name {
                 "insert" => {
                     // Map insert() 需要使用 std::make_pair
@@ -1145,20 +1431,7 @@ fn get_str_method_impl_body(class: Option<&Class>, return_field_type: &FieldType
                 }
                 "find" => {
                     // Map find() 返回迭代器，需要检查是否找到并返回值
-                    if return_field_type.type_kind == TypeKind::String {
-                        return format!("static std::string retStr = \"\";
-    auto it = ptr->find{};
-    if (it != ptr->end()) {{
-        retStr = it->second;
-    }} else {{
-        retStr = \"\";
-    }}
-    return (const char*)retStr.c_str();", full_param_str);
-                    } else {
-                        return format!("auto it = ptr->find{};
-    return ({})(it != ptr->end() ? it->second : {});", full_param_str, impl_return_type, 
-                            if return_field_type.type_kind == TypeKind::Int64 { "0" } else { "0" });
-                    }
+                    return get_str_map_find_body(return_field_type, full_param_str);
                 }
                 _ => {}
             }
@@ -1176,20 +1449,7 @@ fn get_str_method_impl_body(class: Option<&Class>, return_field_type: &FieldType
                 }
                 "find" => {
                     // UnorderedMap find() 返回迭代器，需要检查是否找到并返回值
-                    if return_field_type.type_kind == TypeKind::String {
-                        return format!("static std::string retStr = \"\";
-    auto it = ptr->find{};
-    if (it != ptr->end()) {{
-        retStr = it->second;
-    }} else {{
-        retStr = \"\";
-    }}
-    return (const char*)retStr.c_str();", full_param_str);
-                    } else {
-                        return format!("auto it = ptr->find{};
-    return ({})(it != ptr->end() ? it->second : {});", full_param_str, impl_return_type, 
-                            if return_field_type.type_kind == TypeKind::Int64 { "0" } else { "0" });
-                    }
+                    return get_str_map_find_body(return_field_type, full_param_str);
                 }
                 _ => {}
             }
@@ -1217,35 +1477,150 @@ fn get_str_method_impl_body(class: Option<&Class>, return_field_type: &FieldType
                 }
                 _ => {}
             }
+        } else if cur_class.class_type == ClassType::StdList {
+            // std::list 没有 at()，从头开始查找，复杂度为 O(n)，遍历请使用迭代器（dart 中的 toList()）
+            if method_name == "at" {
+                return get_str_method_impl_body(None, return_field_type, "*std::next", Some(&format!("ptr->begin(), {}", param_str.unwrap_or("0"))));
+            }
+        } else if cur_class.class_type == ClassType::StdWeakPtr {
+            if method_name == "lock" {
+                // 对象已经释放时返回空的 optional
+                return format!("auto locked = ptr->lock();
+    if (!locked) {{
+        return ({})new {}();
+    }}
+    return ({})new {}(locked);", impl_return_type, return_field_type.type_str, impl_return_type, return_field_type.type_str);
+            }
+        } else if cur_class.class_type == ClassType::StdVariant {
+            // from_x 以第 x 个备选类型构造，get_x 取第 x 个备选类型的值
+            if let Some(index) = method_name.strip_prefix("from_") {
+                let variant_type_str = &cur_class.value_type.as_deref().unwrap().type_str;
+                let value_str = match param_str {
+                    Some(param_str) if !param_str.is_empty() => format!(", {}", param_str),
+                    _ => "".to_string(),
+                };
+                return format!("return ({})new {}(std::in_place_index<{}>{});", impl_return_type, variant_type_str, index, value_str);
+            }
+            if let Some(index) = method_name.strip_prefix("get_") {
+                return get_str_method_impl_body(None, return_field_type, &format!("std::get<{}>", index), Some("*ptr"));
+            }
+        } else if cur_class.class_type == ClassType::StdTuple {
+            // make 以所有元素构造 pair、tuple，get_x 取第 x 个元素的值
+            if method_name == "make" {
+                let tuple_type_str = &cur_class.value_type.as_deref().unwrap().type_str;
+                return format!("return ({})new {}{};", impl_return_type, tuple_type_str, full_param_str);
+            }
+            if let Some(index) = method_name.strip_prefix("get_") {
+                return get_str_method_impl_body(None, return_field_type, &format!("std::get<{}>", index), Some("*ptr"));
+            }
         }
     }
     
-    if return_field_type.type_kind == TypeKind::String {
+    // std::array 返回首个元素的地址，dart 中按长度访问
+    if return_field_type.is_std_array() {
+        return format!("return ({})({}{}{}).data();", impl_return_type, call_prefix, method_name, full_param_str);
+    }
+
+    // std::unique_ptr 返回值的所有权转移给 dart 对象；字段、引用只是借用，所有权仍属于 c++
+    if return_field_type.type_kind == TypeKind::StdUniquePtr {
+        if param_str.is_none() || return_field_type.is_reference {
+            return format!("return ({})({}{}{}).get();", impl_return_type, call_prefix, method_name, full_param_str);
+        }
+        return format!("return ({})({}{}{}).release();", impl_return_type, call_prefix, method_name, full_param_str);
+    }
+
+    // 返回引用时直接返回已有对象的地址，不复制，dart 中对它的修改会影响到原对象
+    if return_field_type.is_borrowed_reference() && matches!(return_field_type.type_kind,
+        TypeKind::Class | TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque | TypeKind::StdMap | TypeKind::StdMultimap | TypeKind::StdUnorderedMap | TypeKind::StdSet | TypeKind::StdUnorderedSet | TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple | TypeKind::StdWeakPtr)
+    {
+        return format!("return ({})&({}{}{});", impl_return_type, call_prefix, method_name, full_param_str);
+    }
+
+    // POD 结构体按值复制到 ffi 中的 struct
+    if return_field_type.type_kind == TypeKind::PodStruct && 0 == return_field_type.ptr_level {
+        return format!("return ffi_pod_cast<{}>({}{}{});", impl_return_type, call_prefix, method_name, full_param_str);
+    }
+
+    // std::function 返回 dart 闭包的 id
+    if return_field_type.type_kind == TypeKind::StdFunction {
+        return format!("return {}_to_dart({}{}{});", get_ffi_type_name(&return_field_type.get_function_type_str()), call_prefix, method_name, full_param_str);
+    }
+
+    if return_field_type.type_kind == TypeKind::StdDuration || return_field_type.type_kind == TypeKind::StdTimePoint {
+        format!("return {};", get_str_chrono_to_int64(return_field_type, &format!("{}{}{}", call_prefix, method_name, full_param_str)))
+    }
+    else if return_field_type.type_kind == TypeKind::String && return_field_type.string_kind == StringKind::CString {
+        // const char* 可能是空指针
+        format!("static std::string retStr = \"\";
+    const char* ret = {}{}{};
+    retStr = ret ? ret : \"\";
+    return (const char*)retStr.c_str();", call_prefix, method_name, full_param_str)
+    }
+    else if return_field_type.type_kind == TypeKind::String {
+        // 有长度输出参数时同时返回长度，支持字符串中间的 0
+        let ret_len_str = match return_field_type.buffer_len_param.as_deref() {
+            Some(len_param_name) => format!("\n    *{} = retStr.size();", len_param_name),
+            None => "".to_string(),
+        };
         return format!("static std::string retStr = \"\";
-    retStr = {}{}{};
-    return (const char*)retStr.c_str();", call_prefix, method_name, full_param_str);
-    } 
+    retStr = {}{}{};{}
+    return (const char*)retStr.c_str();", call_prefix, method_name, full_param_str, ret_len_str);
+    }
+    else if return_field_type.type_kind == TypeKind::U16String {
+        let ret_len_str = match return_field_type.buffer_len_param.as_deref() {
+            Some(len_param_name) => format!("\n    *{} = retStr.size();", len_param_name),
+            None => "".to_string(),
+        };
+        format!("static std::u16string retStr = u\"\";
+    retStr = {}{}{};{}
+    return (const uint16_t*)retStr.c_str();", call_prefix, method_name, full_param_str, ret_len_str)
+    }
     else if return_field_type.type_kind == TypeKind::Class && 0 == return_field_type.ptr_level {
         return format!("return ({})new {}({}{}{});", impl_return_type, return_field_type.type_str, call_prefix, method_name, full_param_str);
     }
-    else if (return_field_type.type_kind == TypeKind::StdPtr && 0 == return_field_type.ptr_level) 
-    || (return_field_type.type_kind == TypeKind::StdVector && 0 == return_field_type.ptr_level) 
-    {
+    else if return_field_type.type_kind == TypeKind::StdPtr && 0 == return_field_type.ptr_level {
         return format!("return ({})new {}({}{}{});", impl_return_type, return_field_type.full_str, call_prefix, method_name, full_param_str);
     }
-    else if (return_field_type.type_kind == TypeKind::StdMap && 0 == return_field_type.ptr_level) 
-    || (return_field_type.type_kind == TypeKind::StdUnorderedMap && 0 == return_field_type.ptr_level)
-    || (return_field_type.type_kind == TypeKind::StdSet && 0 == return_field_type.ptr_level) 
-    || (return_field_type.type_kind == TypeKind::StdUnorderedSet && 0 == return_field_type.ptr_level) 
+    else if 0 == return_field_type.ptr_level && matches!(return_field_type.type_kind,
+        TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque | TypeKind::StdMap | TypeKind::StdMultimap |
+        TypeKind::StdUnorderedMap | TypeKind::StdSet | TypeKind::StdUnorderedSet)
     {
-        let container_type = return_field_type.full_str.clone();
+        // 容器的 type_str 不带 const、引用修饰，const 返回值也可以复制
+        let container_type = return_field_type.type_str.clone();
         return format!("return ({})new {}({}{}{});", impl_return_type, container_type, call_prefix, method_name, full_param_str);
     }
+    else if 0 == return_field_type.ptr_level && matches!(return_field_type.type_kind,
+        TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple)
+    {
+        format!("return ({})new {}({}{}{});", impl_return_type, return_field_type.type_str, call_prefix, method_name, full_param_str)
+    }
+    else if return_field_type.type_kind == TypeKind::StdWeakPtr && 0 == return_field_type.ptr_level {
+        format!("return ({})new std::weak_ptr<{}>({}{}{});", impl_return_type, return_field_type.type_str, call_prefix, method_name, full_param_str)
+    }
     else {
         return format!("return ({}){}{}{};", impl_return_type, call_prefix, method_name, full_param_str);
     }
 }
 
+/// map 的 find()，找到时和普通返回值一样转换 it->second；
+/// 找不到时 native 对象返回空指针（dart 中为 null），字符串返回空字符串，其他类型返回 0
+fn get_str_map_find_body(return_field_type: &FieldType, full_param_str: &str) -> String {
+    let ret_len_str = match return_field_type.buffer_len_param.as_deref() {
+        Some(len_param_name) => format!("*{} = 0;\n        ", len_param_name),
+        None => "".to_string(),
+    };
+    let not_found_str = match return_field_type.type_kind {
+        TypeKind::String => format!("{}return (const char*)\"\";", ret_len_str),
+        TypeKind::U16String => format!("{}return (const uint16_t*)u\"\";", ret_len_str),
+        _ => "return {};".to_string(),
+    };
+    format!("auto it = ptr->find{};
+    if (it == ptr->end()) {{
+        {}
+    }}
+    {}", full_param_str, not_found_str, get_str_method_impl_body(None, return_field_type, "it->second", None))
+}
+
 /// 函数是不是需要加第一个类的实例参数，模拟调用类实例的调用方法
 pub fn get_is_need_first_class_param(class: Option<&Class>, method: &Method) -> bool {
     if method.is_static {
@@ -1280,10 +1655,7 @@ fn get_str_decl_class_name<'a>(class: Option<&'a Class>, method: &Method) -> &'a
 
 /// 返回函数的 ffi 声明名
 fn get_str_ffi_decl_class_name(class: Option<&Class>, method: &Method) -> String {
-    let mut cur_class_name = "";
-    if let Some(cur_class) = class {
-        cur_class_name = &cur_class.type_str;
-    }
+    let cur_class_name = class.get_ffi_class_name_or_empty();
 
     return format!("ffi_{}_{}", cur_class_name, method.name);
 }
@@ -1292,7 +1664,7 @@ fn get_str_ffi_decl_class_name(class: Option<&Class>, method: &Method) -> String
 fn get_str_params_decl(class: Option<&Class>, method: &Method) -> String {
     let mut param_strs = Vec::new();
     if get_is_need_first_class_param(class, method) {
-        param_strs.push(format!("FFI_{} obj", get_str_decl_class_name(class, method)));
+        param_strs.push(format!("FFI_{} obj", get_ffi_type_name(get_str_decl_class_name(class, method))));
     }
     for param in &method.params {
         param_strs.push(format!("{} {}", get_str_ffi_type(&param.field_type), param.name));
@@ -1317,6 +1689,18 @@ fn get_str_params_impl(class: Option<&Class>, method: &Method) -> (String, Strin
                     param_prefixs.push(format!("std::vector<{}>* ptr = (std::vector<{}>*)obj;", suffix, suffix));
                 }
             }
+            else if cur_class.class_type ==  ClassType::StdList {
+                if let Some(value_type) = cur_class.value_type.as_deref() {
+                    let suffix = value_type.full_str.clone();
+                    param_prefixs.push(format!("std::list<{}>* ptr = (std::list<{}>*)obj;", suffix, suffix));
+                }
+            }
+            else if cur_class.class_type ==  ClassType::StdDeque {
+                if let Some(value_type) = cur_class.value_type.as_deref() {
+                    let suffix = value_type.full_str.clone();
+                    param_prefixs.push(format!("std::deque<{}>* ptr = (std::deque<{}>*)obj;", suffix, suffix));
+                }
+            }
             else if cur_class.class_type ==  ClassType::StdMap {
                 if let (Some(key_type), Some(value_type)) = (cur_class.key_type.as_deref(), cur_class.value_type.as_deref()) {
                     let key_suffix = key_type.full_str.clone();
@@ -1324,6 +1708,13 @@ fn get_str_params_impl(class: Option<&Class>, method: &Method) -> (String, Strin
                     param_prefixs.push(format!("std::map<{}, {}>* ptr = (std::map<{}, {}>*)obj;", key_suffix, value_suffix, key_suffix, value_suffix));
                 }
             }
+            else if cur_class.class_type ==  ClassType::StdMultimap {
+                if let (Some(key_type), Some(value_type)) = (cur_class.key_type.as_deref(), cur_class.value_type.as_deref()) {
+                    let key_suffix = key_type.full_str.clone();
+                    let value_suffix = value_type.full_str.clone();
+                    param_prefixs.push(format!("std::multimap<{}, {}>* ptr = (std::multimap<{}, {}>*)obj;", key_suffix, value_suffix, key_suffix, value_suffix));
+                }
+            }
             else if cur_class.class_type ==  ClassType::StdUnorderedMap {
                 if let (Some(key_type), Some(value_type)) = (cur_class.key_type.as_deref(), cur_class.value_type.as_deref()) {
                     let key_suffix = key_type.full_str.clone();
@@ -1343,6 +1734,23 @@ fn get_str_params_impl(class: Option<&Class>, method: &Method) -> (String, Strin
                     param_prefixs.push(format!("std::unordered_set<{}>* ptr = (std::unordered_set<{}>*)obj;", suffix, suffix));
                 }
             }
+            else if cur_class.class_type ==  ClassType::StdOptional {
+                if let Some(value_type) = cur_class.value_type.as_deref() {
+                    let suffix = value_type.full_str.clone();
+                    param_prefixs.push(format!("std::optional<{}>* ptr = (std::optional<{}>*)obj;", suffix, suffix));
+                }
+            }
+            else if cur_class.class_type ==  ClassType::StdWeakPtr {
+                if let Some(stdptr_type) = cur_class.value_type.as_deref() {
+                    let suffix = stdptr_type.type_str.clone();
+                    param_prefixs.push(format!("std::weak_ptr<{}>* ptr = (std::weak_ptr<{}>*)obj;", suffix, suffix));
+                }
+            }
+            else if cur_class.class_type ==  ClassType::StdVariant || cur_class.class_type ==  ClassType::StdTuple {
+                if let Some(value_type) = cur_class.value_type.as_deref() {
+                    param_prefixs.push(format!("{}* ptr = ({}*)obj;", value_type.type_str, value_type.type_str));
+                }
+            }
             else {
                 param_prefixs.push(format!("{}* ptr = ({}*)obj;", cur_class.type_str, cur_class.type_str));
             }
@@ -1352,6 +1760,15 @@ fn get_str_params_impl(class: Option<&Class>, method: &Method) -> (String, Strin
     }
 
     for param in &method.params {
+        // std::span、字符串的长度参数和指针参数一起构造 std::span、字符串
+        if method.get_buffer_param_for_len(&param.name).is_some_and(|buffer_param| buffer_param.field_type.is_std_span()
+            || matches!(buffer_param.field_type.type_kind, TypeKind::String | TypeKind::U16String)) {
+            continue;
+        }
+        // 字符串返回值的长度输出参数，不传给 c++
+        if method.is_return_len_param(&param.name) {
+            continue;
+        }
         param_strs.push(get_str_ffi_to_cpp_param_field(&param.field_type, &param.name));
     }
 
@@ -1361,32 +1778,65 @@ fn get_str_params_impl(class: Option<&Class>, method: &Method) -> (String, Strin
 }
 
 fn get_str_ffi_to_cpp_param_field(field_type: &FieldType, param_name: &str) -> String {
-    if field_type.type_kind == TypeKind::String {
-        return format!("std::string({})", param_name);
+    if field_type.type_kind == TypeKind::String || field_type.type_kind == TypeKind::U16String {
+        // 有长度参数时按长度构造，支持字符串中间的 0
+        let len_str = match field_type.buffer_len_param.as_deref() {
+            Some(len_param_name) => format!(", {}", len_param_name),
+            None => "".to_string(),
+        };
+        if field_type.type_kind == TypeKind::U16String {
+            return format!("std::u16string((const char16_t*){}{})", param_name, len_str);
+        }
+        match field_type.string_kind {
+            StringKind::CString => param_name.to_string(),
+            StringKind::StringView => format!("std::string_view({}{})", param_name, len_str),
+            StringKind::StdString => format!("std::string({}{})", param_name, len_str),
+        }
+    }
+    else if field_type.type_kind == TypeKind::StdDuration || field_type.type_kind == TypeKind::StdTimePoint {
+        get_str_chrono_from_int64(field_type, param_name)
     }
     else if field_type.type_kind == TypeKind::Class && 0 == field_type.ptr_level {
         return format!("({})(*({}*){})", &field_type.full_str, field_type.type_str, param_name);
     }
-    else if (field_type.type_kind == TypeKind::StdPtr && 0 == field_type.ptr_level)
-    || (field_type.type_kind == TypeKind::StdVector && 0 == field_type.ptr_level)
-    {
+    else if field_type.type_kind == TypeKind::StdPtr && 0 == field_type.ptr_level {
         return format!("({})(*({}*){})", &field_type.full_str, &field_type.full_str, param_name);
     }
-    else if field_type.type_kind == TypeKind::StdMap && 0 == field_type.ptr_level {
-        let container_type = field_type.full_str.clone();
-        return format!("({})(*({}*){})", container_type, container_type, param_name);
+    else if field_type.type_kind == TypeKind::PodStruct && 0 == field_type.ptr_level {
+        format!("ffi_pod_cast<{}>({})", field_type.type_str, param_name)
+    }
+    // 和 class 一样转换为参数的类型（可能带引用修饰），指针使用不带修饰的 type_str
+    else if 0 == field_type.ptr_level && matches!(field_type.type_kind,
+        TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque | TypeKind::StdMap | TypeKind::StdMultimap |
+        TypeKind::StdUnorderedMap | TypeKind::StdSet | TypeKind::StdUnorderedSet)
+    {
+        format!("({})(*({}*){})", &field_type.full_str, field_type.type_str, param_name)
+    }
+    else if field_type.type_kind == TypeKind::StdOptional && 0 == field_type.ptr_level && !field_type.is_mutable_reference() {
+        // dart 中为 null 时传递空指针
+        format!("({} ? *({}*){} : {}())", param_name, field_type.type_str, param_name, field_type.type_str)
+    }
+    else if 0 == field_type.ptr_level && matches!(field_type.type_kind,
+        TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple)
+    {
+        format!("(*({}*){})", field_type.type_str, param_name)
+    }
+    else if field_type.type_kind == TypeKind::StdFunction {
+        format!("{}_from_dart({})", get_ffi_type_name(&field_type.get_function_type_str()), param_name)
     }
-    else if field_type.type_kind == TypeKind::StdUnorderedMap && 0 == field_type.ptr_level {
-        let container_type = field_type.full_str.clone();
-        return format!("({})(*({}*){})", container_type, container_type, param_name);
+    else if field_type.is_borrowed_unique_ptr() {
+        format!("ffi_borrowed_unique_ptr<{}>(({}*){})", field_type.type_str, field_type.type_str, param_name)
     }
-    else if field_type.type_kind == TypeKind::StdSet && 0 == field_type.ptr_level {
-        let container_type = field_type.full_str.clone();
-        return format!("({})(*({}*){})", container_type, container_type, param_name);
+    else if field_type.type_kind == TypeKind::StdUniquePtr && 0 == field_type.ptr_level {
+        // 接管 dart 对象的 native 对象，见 dart 中的 nativeRelease
+        format!("std::unique_ptr<{}>(({}*){})", field_type.type_str, field_type.type_str, param_name)
     }
-    else if field_type.type_kind == TypeKind::StdUnorderedSet && 0 == field_type.ptr_level {
-        let container_type = field_type.full_str.clone();
-        return format!("({})(*({}*){})", container_type, container_type, param_name);
+    else if field_type.type_kind == TypeKind::StdWeakPtr && 0 == field_type.ptr_level {
+        format!("(*(std::weak_ptr<{}>*){})", field_type.type_str, param_name)
+    }
+    else if field_type.is_std_span() {
+        let len_param_name = field_type.buffer_len_param.as_deref().unwrap_or_default();
+        format!("{}(({}*){}, {})", field_type.full_str.trim_start_matches("const ").trim_end_matches("&").trim(), field_type.type_str, param_name, len_param_name)
     }
     else {
         if field_type.ptr_level > 0 {
@@ -1432,12 +1882,92 @@ fn collect_element_referenced_types(element: &HppElement, typedef_names: &mut Ve
             // 处理字段类型
             collect_field_type(&field.field_type, typedef_names);
         },
+        HppElement::Constant(_constant) => {
+            // 常量只有基础类型和字符串，不需要 typedef
+        },
+        HppElement::Variable(variable) => {
+            collect_field_type(&variable.field_type, typedef_names);
+        },
         HppElement::Enum(_enum) => {
             // Enum 不需要收集引用类型，它本身就是类型定义
         }
     }
 }
 
+/// 递归收集元素中引用的 POD 结构体的类型名
+fn collect_element_pod_struct_types(element: &HppElement, pod_struct_names: &mut Vec<String>) {
+    match element {
+        HppElement::File(file) => {
+            for child in &file.children {
+                collect_element_pod_struct_types(child, pod_struct_names);
+            }
+        },
+        HppElement::Class(class) => {
+            if class.class_type == ClassType::PodStruct && !pod_struct_names.contains(&class.type_str) {
+                pod_struct_names.push(class.type_str.clone());
+            }
+            for child in &class.children {
+                collect_element_pod_struct_types(child, pod_struct_names);
+            }
+        },
+        HppElement::Method(method) => {
+            collect_pod_struct_field_type(&method.return_type, pod_struct_names);
+            for param in &method.params {
+                collect_pod_struct_field_type(&param.field_type, pod_struct_names);
+            }
+        },
+        HppElement::Field(field) => {
+            collect_pod_struct_field_type(&field.field_type, pod_struct_names);
+        },
+        HppElement::Variable(variable) => {
+            collect_pod_struct_field_type(&variable.field_type, pod_struct_names);
+        },
+        HppElement::Constant(_) | HppElement::Enum(_) => {}
+    }
+}
+
+fn collect_pod_struct_field_type(field_type: &FieldType, pod_struct_names: &mut Vec<String>) {
+    if field_type.type_kind == TypeKind::PodStruct && !pod_struct_names.contains(&field_type.type_str) {
+        pod_struct_names.push(field_type.type_str.clone());
+    }
+    for element_type in [field_type.key_type.as_ref(), field_type.value_type.as_ref()].into_iter().flatten() {
+        collect_pod_struct_field_type(element_type, pod_struct_names);
+    }
+    for element_type in &field_type.param_types {
+        collect_pod_struct_field_type(element_type, pod_struct_names);
+    }
+}
+
+/// POD 结构体在 c 中对应的 struct，字段中的 POD 结构体先定义，
+/// 多个头文件都可能定义同一个 struct，用宏避免重复定义
+fn get_str_pod_struct_def(gen_context: &GenContext, type_str: &str, defined_names: &mut Vec<String>) -> String {
+    if defined_names.iter().any(|name| name == type_str) {
+        return "".to_string();
+    }
+    defined_names.push(type_str.to_string());
+    let Some(class) = gen_context.find_pod_struct(type_str) else {
+        return "".to_string();
+    };
+
+    let mut nested_defs = String::new();
+    let mut fields_str = String::new();
+    for child in &class.children {
+        if let HppElement::Field(field) = child {
+            if field.field_type.type_kind == TypeKind::PodStruct {
+                nested_defs.push_str(&get_str_pod_struct_def(gen_context, &field.field_type.type_str, defined_names));
+            }
+            fields_str.push_str(&format!("    {} {};\n", get_str_ffi_type(&field.field_type), field.name));
+        }
+    }
+    let ffi_name = class.get_ffi_name();
+    format!("{}#ifndef FFI_POD_STRUCT_{}
+#define FFI_POD_STRUCT_{}
+typedef struct FFI_{} {{
+{}}} FFI_{};
+#endif
+", nested_defs, ffi_name, ffi_name, ffi_name, fields_str, ffi_name)
+}
+
 /// 处理单个字段类型，收集需要的typedef
 fn collect_field_type(field_type: &FieldType, typedef_names: &mut Vec<String>) {
     // 跳过被忽略的类型
@@ -1457,10 +1987,11 @@ fn collect_field_type(field_type: &FieldType, typedef_names: &mut Vec<String>) {
             let clean_type_str = cleaned.trim().to_string();
 
             // 使用 should_ignore_type 检查是否应该忽略这个类型
+            let ffi_type_str = get_ffi_type_name(&clean_type_str);
             if !clean_type_str.is_empty()
-                && !typedef_names.contains(&clean_type_str)
+                && !typedef_names.contains(&ffi_type_str)
                 && !crate::gen_context::should_ignore_type(&clean_type_str) {
-                typedef_names.push(clean_type_str);
+                typedef_names.push(ffi_type_str);
             }
         },
         TypeKind::StdPtr => {
@@ -1474,16 +2005,30 @@ fn collect_field_type(field_type: &FieldType, typedef_names: &mut Vec<String>) {
             let clean_type_str = cleaned.trim().to_string();
 
             // 使用 should_ignore_type 检查是否应该忽略这个类型
+            let ffi_type_str = get_ffi_type_name(&clean_type_str);
             if !clean_type_str.is_empty()
-                && !typedef_names.contains(&clean_type_str)
+                && !typedef_names.contains(&ffi_type_str)
                 && !crate::gen_context::should_ignore_type(&clean_type_str) {
-                typedef_names.push(clean_type_str.clone());
-                let stdptr_typename = format!("StdPtr_{}", clean_type_str);
+                typedef_names.push(ffi_type_str.clone());
+                let stdptr_typename = format!("StdPtr_{}", ffi_type_str);
                 if !typedef_names.contains(&stdptr_typename) {
                     typedef_names.push(stdptr_typename);
                 }
             }
         },
+        TypeKind::StdUniquePtr | TypeKind::StdWeakPtr => {
+            // 被指向的类型，和 class 一样处理
+            let mut class_type = field_type.clone();
+            class_type.type_kind = TypeKind::Class;
+            collect_field_type(&class_type, typedef_names);
+
+            if field_type.type_kind == TypeKind::StdWeakPtr {
+                let weakptr_type_str = get_ffi_type_name(&format!("StdWeakPtr_{}", field_type.type_str.replace("const ", "").replace(" ", "")));
+                if !typedef_names.contains(&weakptr_type_str) {
+                    typedef_names.push(weakptr_type_str);
+                }
+            }
+        },
         TypeKind::StdVector => {
             // 处理vector内部的值类型
             if let Some(value_type) = &field_type.value_type {
@@ -1491,12 +2036,24 @@ fn collect_field_type(field_type: &FieldType, typedef_names: &mut Vec<String>) {
 
                 // 添加StdVector类型本身
                 let value_type_str = field_type.get_value_type_str();
-                let vector_type_str = format!("StdVector_{}", value_type_str);
+                let vector_type_str = get_ffi_type_name(&format!("StdVector_{}", value_type_str));
                 if !typedef_names.contains(&vector_type_str) {
                     typedef_names.push(vector_type_str);
                 }
             }
         },
+        TypeKind::StdList | TypeKind::StdDeque | TypeKind::StdMultimap => {
+            // 处理内部的键和值类型
+            for element_type in [field_type.key_type.as_ref(), field_type.value_type.as_ref()].into_iter().flatten() {
+                collect_field_type(element_type, typedef_names);
+            }
+
+            // 添加容器类型本身
+            let container_type_str = get_ffi_type_name(&field_type.get_type_name_without_ptr());
+            if !typedef_names.contains(&container_type_str) {
+                typedef_names.push(container_type_str);
+            }
+        },
         TypeKind::StdMap => {
             // 处理map内部的键和值类型
             if let Some(key_type) = &field_type.key_type {
@@ -1509,7 +2066,7 @@ fn collect_field_type(field_type: &FieldType, typedef_names: &mut Vec<String>) {
                 // 添加StdMap类型本身
                 let key_type_str = field_type.get_key_type_str();
                 let value_type_str = field_type.get_value_type_str();
-                let map_type_str = format!("StdMap_{}_{}", key_type_str, value_type_str);
+                let map_type_str = get_ffi_type_name(&format!("StdMap_{}_{}", key_type_str, value_type_str));
                 if !typedef_names.contains(&map_type_str) {
                     typedef_names.push(map_type_str);
                 }
@@ -1527,7 +2084,7 @@ fn collect_field_type(field_type: &FieldType, typedef_names: &mut Vec<String>) {
                 // 添加StdUnorderedMap类型本身
                 let key_type_str = field_type.get_key_type_str();
                 let value_type_str = field_type.get_value_type_str();
-                let map_type_str = format!("StdUnorderedMap_{}_{}", key_type_str, value_type_str);
+                let map_type_str = get_ffi_type_name(&format!("StdUnorderedMap_{}_{}", key_type_str, value_type_str));
                 if !typedef_names.contains(&map_type_str) {
                     typedef_names.push(map_type_str);
                 }
@@ -1540,7 +2097,7 @@ fn collect_field_type(field_type: &FieldType, typedef_names: &mut Vec<String>) {
 
                 // 添加StdSet类型本身
                 let value_type_str = field_type.get_value_type_str();
-                let set_type_str = format!("StdSet_{}", value_type_str);
+                let set_type_str = get_ffi_type_name(&format!("StdSet_{}", value_type_str));
                 if !typedef_names.contains(&set_type_str) {
                     typedef_names.push(set_type_str);
                 }
@@ -1553,16 +2110,259 @@ fn collect_field_type(field_type: &FieldType, typedef_names: &mut Vec<String>) {
 
                 // 添加StdUnorderedSet类型本身
                 let value_type_str = field_type.get_value_type_str();
-                let set_type_str = format!("StdUnorderedSet_{}", value_type_str);
+                let set_type_str = get_ffi_type_name(&format!("StdUnorderedSet_{}", value_type_str));
                 if !typedef_names.contains(&set_type_str) {
                     typedef_names.push(set_type_str);
                 }
             }
         },
+        TypeKind::StdOptional => {
+            // 处理optional内部的值类型
+            if let Some(value_type) = &field_type.value_type {
+                collect_field_type(value_type, typedef_names);
+
+                // 添加StdOptional类型本身
+                let value_type_str = field_type.get_value_type_str();
+                let optional_type_str = get_ffi_type_name(&format!("StdOptional_{}", value_type_str));
+                if !typedef_names.contains(&optional_type_str) {
+                    typedef_names.push(optional_type_str);
+                }
+            }
+        },
+        TypeKind::StdVariant => {
+            // 处理variant的备选类型
+            for alternative_type in &field_type.param_types {
+                collect_field_type(alternative_type, typedef_names);
+            }
+
+            // 添加StdVariant类型本身
+            let variant_type_str = get_ffi_type_name(&field_type.get_variant_type_str());
+            if !typedef_names.contains(&variant_type_str) {
+                typedef_names.push(variant_type_str);
+            }
+        },
+        TypeKind::StdPair | TypeKind::StdTuple => {
+            // 处理pair、tuple的元素类型
+            for element_type in &field_type.param_types {
+                collect_field_type(element_type, typedef_names);
+            }
+
+            // 添加StdPair、StdTuple类型本身
+            let tuple_type_str = get_ffi_type_name(&field_type.get_tuple_type_str());
+            if !typedef_names.contains(&tuple_type_str) {
+                typedef_names.push(tuple_type_str);
+            }
+        },
         _ => {} // 其他基本类型不需要特殊处理
     }
 }
 
+/// std::function 的一种签名，dart 闭包保存在 dart 中，c++ 中的 std::function 只持有闭包的 id，
+/// 调用时通过 SendPort（无返回值）或者函数指针（有返回值）调用 dart 闭包，
+/// std::function 的所有副本都释放后，通知 dart 释放闭包
+fn gen_c_stdfunction_class(c_context: &mut CFileContext, class: &Class) {
+    let Some(HppElement::Method(call_method)) = class.children.first() else {
+        return;
+    };
+    let class_name = class.get_ffi_name();
+    let param_strs: Vec<&str> = call_method.params.iter().map(|param| param.field_type.full_str.as_str()).collect();
+    let function_type_str = format!("std::function<{}({})>", call_method.return_type.full_str, param_strs.join(", "));
+    let decl_params: Vec<String> = call_method.params.iter().map(|param| format!("{} {}", param.field_type.full_str, param.name)).collect();
+    let decl_params_str = decl_params.join(", ");
+
+    // 调用 dart 闭包
+    let (regist_decl, regist_impl, call_impl) = if call_method.is_sync_callback {
+        let mut fnptr_params = vec!["int64_t closure_id".to_string()];
+        let mut call_params = vec!["closure->closure_id".to_string()];
+        let mut param_conversions = Vec::new();
+        for (i, param) in call_method.params.iter().enumerate() {
+            fnptr_params.push(format!("int64_t param{}", i));
+            call_params.push(get_str_callback_param_sync_value(&param.field_type, &param.name, i, &mut param_conversions));
+        }
+        let param_conversions_str = if param_conversions.is_empty() {
+            String::new()
+        } else {
+            format!("
+{}
+", param_conversions.join("
+"))
+        };
+
+        let regist_decl = format!("typedef int64_t (*FFI_{}_FnPtr)({});
+API_EXPORT void FFI_{}_register(FFI_{}_FnPtr fnptr, int64_t release_port);
+",
+            class_name, fnptr_params.join(", "),
+            class_name, class_name);
+        let regist_impl = format!("static FFI_{}_FnPtr {}_fnptr = nullptr;
+API_EXPORT void FFI_{}_register(FFI_{}_FnPtr fnptr, int64_t release_port) {{
+    {}_fnptr = fnptr;
+    {}_release_port = release_port;
+}}
+",
+            class_name, class_name,
+            class_name, class_name,
+            class_name,
+            class_name);
+        let call_impl = format!("        if ({}_fnptr == nullptr) {{
+            return 0;  // 没有注册函数指针，返回默认值
+        }}
+{}
+        // 直接调用 Dart 函数指针
+        int64_t result = {}_fnptr({});
+
+        // 转换返回值
+        {}",
+            class_name,
+            param_conversions_str,
+            class_name, call_params.join(", "),
+            get_str_callback_sync_return(&call_method.return_type));
+        (regist_decl, regist_impl, call_impl)
+    } else {
+        let mut gen_values = Vec::new();
+        let mut values = vec!["&value0".to_string()];
+        for (i, param) in call_method.params.iter().enumerate() {
+            gen_values.push(get_str_callback_param_cobject_value(&param.field_type, &param.name, i+1));
+            values.push(format!("&value{}", i+1));
+        }
+
+        let regist_decl = format!("API_EXPORT void FFI_{}_regist(int64_t call_port, int64_t release_port);
+", class_name);
+        let regist_impl = format!("static int64_t {}_call_port = 0;
+API_EXPORT void FFI_{}_regist(int64_t call_port, int64_t release_port) {{
+    {}_call_port = call_port;
+    {}_release_port = release_port;
+}}
+",
+            class_name,
+            class_name,
+            class_name,
+            class_name);
+        let call_impl = format!("        if ({}_call_port == 0) {{
+            return;
+        }}
+        {}
+
+        Dart_CObject value0;
+        value0.type = Dart_CObject_kInt64;
+        value0.value.as_int64 = closure->closure_id;
+
+        Dart_CObject* values[] = {{{}}};
+        Dart_CObject args;
+        args.type = Dart_CObject_kArray;
+        args.value.as_array.length = {};
+        args.value.as_array.values = values;
+
+        Dart_PostCObject_DL((Dart_Port_DL){}_call_port, &args);",
+            class_name,
+            gen_values.join(""),
+            values.join(", "),
+            values.len(),
+            class_name);
+        (regist_decl, regist_impl, call_impl)
+    };
+
+    let closure_impl = format!("
+// {}
+static int64_t {}_release_port = 0;
+{}
+// dart 闭包的 id，std::function 的所有副本都释放后，通知 dart 释放闭包
+struct {}_Closure {{
+    int64_t closure_id;
+    ~{}_Closure() {{
+        if ({}_release_port == 0) {{
+            return;
+        }}
+        Dart_CObject value;
+        value.type = Dart_CObject_kInt64;
+        value.value.as_int64 = closure_id;
+        Dart_PostCObject_DL((Dart_Port_DL){}_release_port, &value);
+    }}
+}};
+
+// 调用 dart 闭包，有名字的类型，可以从 std::function 中取出闭包的 id
+struct {}_Functor {{
+    std::shared_ptr<{}_Closure> closure;
+    {} operator()({}) const {{
+{}
+    }}
+}};
+
+// 闭包的 id 为 0 时（dart 中为 null）为空的 std::function
+static {} {}_from_dart(int64_t closure_id) {{
+    if (closure_id == 0) {{
+        return nullptr;
+    }}
+    auto closure = std::make_shared<{}_Closure>();
+    closure->closure_id = closure_id;
+    return {}_Functor{{closure}};
+}}
+
+// 由 dart 闭包创建的 std::function 返回闭包的 id，c++ 中创建的、空的 std::function 返回 0（dart 中为 null）
+static int64_t {}_to_dart(const {}& function) {{
+    auto functor = function.target<{}_Functor>();
+    return functor ? functor->closure->closure_id : 0;
+}}
+",
+        function_type_str,
+        class_name,
+        regist_impl,
+        class_name,
+        class_name,
+        class_name,
+        class_name,
+        class_name,
+        class_name,
+        call_method.return_type.full_str, decl_params_str,
+        call_impl,
+        function_type_str, class_name,
+        class_name,
+        class_name,
+        class_name, function_type_str,
+        class_name);
+
+    c_context.ch_str.push_str(&format!("
+{}", regist_decl));
+    c_context.cc_str.push_str(&closure_impl);
+}
+
+/// 全局变量、类的静态成员变量的 getter / setter，const 变量只生成 getter
+fn gen_c_variable(c_context: &mut CFileContext, class: Option<&Class>, variable: &Variable) {
+    // 命名空间中的变量、类的静态成员使用完整的名字访问
+    let cpp_name = &variable.cpp_name;
+
+    // get
+    let get_method = Method::new_get_for_variable(variable);
+    let get_decl = get_str_method_decl(class, &get_method);
+    let get_impl_body = get_str_method_impl_body(None, &variable.field_type, cpp_name, None);
+    let get_impl = format!("{} {{
+    {}
+}}", get_decl.trim_end_matches(";"), get_impl_body);
+    c_context.ch_str.push_str(&format!("{}\n", get_decl));
+    c_context.cc_str.push_str(&format!("{}\n", get_impl));
+
+    if !variable.has_setter() {
+        return;
+    }
+
+    // set
+    let set_method = Method::new_set_for_variable(variable);
+    let set_decl = get_str_method_decl(class, &set_method);
+    let (param_prefix, param_str) = get_str_params_impl(class, &set_method);
+    let mut set_impl_body = format!("{} = {};", cpp_name, param_str);
+    if variable.field_type.is_c_array {
+        set_impl_body = format!("memcpy({}, {}, sizeof({}));", cpp_name, param_str, cpp_name);
+    }
+    else if variable.field_type.is_std_array() {
+        set_impl_body = format!("memcpy({}.data(), {}, sizeof({}));", cpp_name, param_str, cpp_name);
+    }
+    let set_impl = format!("{} {{
+    {}
+    {}
+}}", set_decl.trim_end_matches(";"), param_prefix, set_impl_body);
+    c_context.ch_str.push_str(&format!("{}\n", set_decl));
+    c_context.cc_str.push_str(&format!("{}\n", set_impl));
+}
+
 /// 为 enum 生成 C FFI 代码
 fn gen_c_enum(_c_context: &mut CFileContext, enum_def: &Enum) {
     // Enum 在 C++ 层面就是整数类型，不需要生成额外的 FFI 函数
@@ -93,10 +93,344 @@ pub fn should_ignore_type(type_str: &str) -> bool {
     false
 }
 
+/// 类型在 C FFI 中使用的名字，嵌套类型的 `::` 替换为 `_`
+/// 如 Http::Request -> Http_Request
+pub fn get_ffi_type_name(type_str: &str) -> String {
+    type_str.replace("::", "_")
+}
+
+/// 为 std 容器等生成的类名的前缀，如 StdVector_int
+const GENERATED_STD_CLASS_PREFIXES: [&str; 16] = [
+    "StdPtr", "StdWeakPtr", "StdUniquePtr", "StdVector", "StdList", "StdDeque", "StdSet", "StdUnorderedSet",
+    "StdMap", "StdMultimap", "StdUnorderedMap", "StdOptional", "StdVariant", "StdPair", "StdTuple", "StdFunction",
+];
+
+/// 是否为生成的 std 容器等的类名，如 StdVector_int，用户定义的 StdLogger 不是
+fn is_generated_std_class_name(type_str: &str) -> bool {
+    type_str.split_once('_').is_some_and(|(prefix, _)| GENERATED_STD_CLASS_PREFIXES.contains(&prefix))
+}
+
+/// 类型在 Dart 中使用的名字，嵌套类型去掉 `::`
+/// 如 Http::Request -> HttpRequest
+/// 命名规则指定了 UpperCamelCase 时转换用户定义的类型名，如 HTTPClient -> HttpClient，
+/// 生成的 StdVector_T 等类名保持不变
+pub fn get_dart_type_name(naming: &DartNaming, type_str: &str) -> String {
+    if naming.upper_camel_types && !is_generated_std_class_name(type_str) {
+        let words = split_identifier_words(type_str);
+        if !words.is_empty() {
+            return words.iter().map(|word| capitalize_word(word)).collect();
+        }
+    }
+    type_str.replace("::", "")
+}
+
+/// dart 中标识符的命名规则，默认保持 c++ 中的名字
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DartNaming {
+    /// 类、枚举等类型名转换为 UpperCamelCase，如 HTTPClient -> HttpClient
+    pub upper_camel_types: bool,
+    /// 普通类的方法、方法参数、全局函数、常量、变量名转换为 lowerCamelCase，如 get_user_id -> getUserId
+    pub lower_camel_members: bool,
+    /// 方法、参数、字段、常量、变量、枚举值去掉的前缀，如 k（kMaxSize -> MaxSize）、m_（m_count -> count）
+    pub strip_prefixes: Vec<String>,
+}
+
+/// 方法、参数、字段等成员在 dart 中的名字：去掉指定的前缀，按需转换为 lowerCamelCase，和 dart 关键字相同时加上 Value 后缀
+pub fn get_dart_member_name(naming: &DartNaming, name: &str, lower_camel: bool) -> String {
+    let mut member_name = strip_dart_name_prefix(naming, name).to_string();
+    if lower_camel {
+        let words = split_identifier_words(&member_name);
+        if !words.is_empty() {
+            member_name = words.iter().enumerate()
+                .map(|(i, word)| if i == 0 { word.to_lowercase() } else { capitalize_word(word) })
+                .collect();
+        }
+    }
+    escape_dart_keyword(&member_name)
+}
+
+/// 去掉命名规则中指定的前缀，k 这样的前缀只在后面是大写字母时去掉，避免 kind -> ind
+pub fn strip_dart_name_prefix<'a>(naming: &DartNaming, name: &'a str) -> &'a str {
+    for prefix in &naming.strip_prefixes {
+        let Some(rest) = name.strip_prefix(prefix.as_str()) else {
+            continue;
+        };
+        let Some(first) = rest.chars().next() else {
+            continue;
+        };
+        if first.is_ascii_digit() {
+            continue;
+        }
+        if prefix.ends_with('_') || first.is_uppercase() {
+            return rest;
+        }
+    }
+    name
+}
+
+/// dart 的保留字和内置标识符，内置标识符如 required、late、get 在部分位置也不能作为名字
+const DART_KEYWORDS: [&str; 58] = [
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else", "enum",
+    "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null", "rethrow", "return",
+    "super", "switch", "this", "throw", "true", "try", "var", "void", "while", "with",
+    "abstract", "as", "covariant", "deferred", "dynamic", "export", "extension", "external", "factory",
+    "Function", "get", "implements", "import", "interface", "late", "library", "mixin", "operator", "part",
+    "required", "set", "static", "typedef", "await", "yield",
+];
+
+/// 和 dart 关键字相同的名字加上 Value 后缀，如 in -> inValue
+pub fn escape_dart_keyword(name: &str) -> String {
+    if DART_KEYWORDS.contains(&name) {
+        return format!("{}Value", name);
+    }
+    name.to_string()
+}
+
+#[test]
+fn test_dart_naming() {
+    let naming = DartNaming {
+        upper_camel_types: true,
+        lower_camel_members: true,
+        strip_prefixes: vec!["k".to_string(), "m_".to_string()],
+    };
+    assert_eq!(get_dart_type_name(&naming, "HTTPClient"), "HttpClient");
+    assert_eq!(get_dart_type_name(&naming, "StdLogger"), "StdLogger");
+    assert_eq!(get_dart_type_name(&naming, "STDLogger"), "StdLogger");
+    assert_eq!(get_dart_type_name(&naming, "StdVector_HTTPClient"), "StdVector_HTTPClient");
+    assert_eq!(get_dart_member_name(&naming, "kMaxSize", true), "maxSize");
+    assert_eq!(get_dart_member_name(&naming, "kind", true), "kind");
+    assert_eq!(get_dart_member_name(&naming, "m_required", true), "requiredValue");
+    assert_eq!(get_dart_member_name(&naming, "late", false), "lateValue");
+    assert_eq!(get_dart_member_name(&naming, "get", false), "getValue");
+    assert_eq!(get_dart_type_name(&DartNaming::default(), "Http::Request"), "HttpRequest");
+}
+
+/// 把标识符拆分为单词，按 _、:: 和大小写的边界拆分，连续的大写字母作为一个单词
+/// 如 get_user_id -> [get, user, id]，HTTPClient -> [HTTP, Client]，Http::Request -> [Http, Request]
+fn split_identifier_words(name: &str) -> Vec<String> {
+    let mut words = vec![];
+    for part in name.split(['_', ':']).filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            if i > 0 && c.is_uppercase() {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+                if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lower) {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            word.push(c);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+/// 单词首字母大写，其余小写，如 HTTP -> Http
+fn capitalize_word(word: &str) -> String {
+    let mut chars = word.chars();
+    let Some(first) = chars.next() else {
+        return "".to_string();
+    };
+    first.to_uppercase().chain(chars.as_str().to_lowercase().chars()).collect()
+}
+
+/// 获取嵌套类型的限定名，只包含外层类的名字，不包含 namespace
+/// 如 class Http { class Request {}; } 中的 Request 返回 Http::Request
+/// 不是嵌套类型时返回 None
+pub fn get_nested_type_name(entity: &clang::Entity) -> Option<String> {
+    let mut names = vec![entity.get_name()?];
+    let mut parent = entity.get_semantic_parent();
+    while let Some(cur_parent) = parent {
+        match cur_parent.get_kind() {
+            clang::EntityKind::ClassDecl | clang::EntityKind::StructDecl => {
+                names.push(cur_parent.get_name()?);
+                parent = cur_parent.get_semantic_parent();
+            }
+            _ => break,
+        }
+    }
+    if names.len() < 2 {
+        return None;
+    }
+    names.reverse();
+    Some(names.join("::"))
+}
+
+/// 变量等在 c++ 中的完整名字，包含外层的命名空间和类名，如 app::Config::kLimit
+/// 匿名命名空间没有名字，其中的名字不需要限定
+pub fn get_qualified_name(entity: &clang::Entity) -> String {
+    let mut names = vec![entity.get_name().unwrap_or_default()];
+    let mut parent = entity.get_semantic_parent();
+    while let Some(cur_parent) = parent {
+        match cur_parent.get_kind() {
+            clang::EntityKind::Namespace | clang::EntityKind::ClassDecl | clang::EntityKind::StructDecl => {
+                if let Some(parent_name) = cur_parent.get_name() {
+                    names.push(parent_name);
+                }
+                parent = cur_parent.get_semantic_parent();
+            }
+            _ => break,
+        }
+    }
+    names.reverse();
+    names.join("::")
+}
+
+/// 注释中是否有完整的标记，如 `@pod`，`@leafy` 不会匹配 `@leaf`
+pub fn has_comment_tag(comment: &Option<String>, tag: &str) -> bool {
+    let Some(comment_text) = comment else {
+        return false;
+    };
+    comment_text
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '@'))
+        .any(|token| token.eq_ignore_ascii_case(tag))
+}
+
+#[test]
+fn test_has_comment_tag() {
+    assert!(has_comment_tag(&Some("/// @pod".to_string()), "@pod"));
+    assert!(has_comment_tag(&Some("/** 坐标 @POD */".to_string()), "@pod"));
+    assert!(!has_comment_tag(&Some("/// @leafy".to_string()), "@leaf"));
+    assert!(!has_comment_tag(&Some("/// @no_leaf".to_string()), "@leaf"));
+    assert!(!has_comment_tag(&None, "@pod"));
+}
+
+/// 是否为可以按值传递的 POD 结构体：
+/// 注释中有 `@pod` 标记，非模板、没有基类和方法，所有字段都是 public 的基础数据类型或者 POD 结构体
+/// 没有标记的结构体仍然作为普通类，避免改变已有的 dart 接口
+pub fn is_pod_struct_type(clang_type: &clang::Type) -> bool {
+    let canonical_type = clang_type.get_canonical_type();
+    if canonical_type.get_kind() != clang::TypeKind::Record || !canonical_type.is_pod() {
+        return false;
+    }
+    if canonical_type.get_template_argument_types().is_some() {
+        return false;
+    }
+    let Some(declaration) = canonical_type.get_declaration() else {
+        return false;
+    };
+    if declaration.get_kind() != clang::EntityKind::StructDecl || declaration.is_in_system_header() {
+        return false;
+    }
+    if !has_comment_tag(&declaration.get_comment(), "@pod") {
+        return false;
+    }
+    let mut field_count = 0;
+    for child in declaration.get_children() {
+        match child.get_kind() {
+            clang::EntityKind::FieldDecl => {
+                if child.get_accessibility() != Some(clang::Accessibility::Public) || child.is_bit_field() {
+                    return false;
+                }
+                let Some(field_type) = child.get_type() else {
+                    return false;
+                };
+                let field_type = field_type.get_canonical_type();
+                let is_supported_field = match field_type.get_kind() {
+                    clang::TypeKind::Bool | clang::TypeKind::CharS | clang::TypeKind::SChar | clang::TypeKind::UChar |
+                    clang::TypeKind::Short | clang::TypeKind::UShort | clang::TypeKind::Int | clang::TypeKind::UInt |
+                    clang::TypeKind::Long | clang::TypeKind::ULong | clang::TypeKind::LongLong | clang::TypeKind::ULongLong |
+                    clang::TypeKind::Float | clang::TypeKind::Double => true,
+                    clang::TypeKind::Record => is_pod_struct_type(&field_type),
+                    _ => false,
+                };
+                if !is_supported_field {
+                    return false;
+                }
+                field_count += 1;
+            }
+            clang::EntityKind::Method | clang::EntityKind::Constructor | clang::EntityKind::Destructor |
+            clang::EntityKind::ConversionFunction | clang::EntityKind::FunctionTemplate |
+            clang::EntityKind::BaseSpecifier | clang::EntityKind::VarDecl => {
+                return false;
+            }
+            _ => {}
+        }
+    }
+    field_count > 0
+}
+
+/// 容器在生成的 c++ 代码中使用的类型名，去掉引用、指针修饰
+/// 如 std::vector<int> & -> std::vector<int>
+fn get_clean_container_type_str(display_name: &str) -> String {
+    display_name.trim_end_matches(|c: char| c == '&' || c == '*' || c.is_whitespace()).to_string()
+}
+
+/// 把类型字符串中独立出现的 from 替换为 to，避免替换到其他类型名的一部分
+fn replace_type_token(type_str: &str, from: &str, to: &str) -> String {
+    if from.is_empty() {
+        return type_str.to_string();
+    }
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == ':';
+    let mut result = String::new();
+    let mut rest = type_str;
+    while let Some(pos) = rest.find(from) {
+        let before_ok = rest[..pos].chars().next_back().is_none_or(|c| !is_ident_char(c));
+        let after_ok = rest[pos + from.len()..].chars().next().is_none_or(|c| !is_ident_char(c));
+        result.push_str(&rest[..pos]);
+        if before_ok && after_ok {
+            result.push_str(to);
+        } else {
+            result.push_str(from);
+        }
+        rest = &rest[pos + from.len()..];
+    }
+    result.push_str(rest);
+    result
+}
+
 #[derive(Debug, Default)]
 pub struct GenContext {
     pub module_name: String,
     pub hpp_elements: Vec<HppElement>,
+    /// .i 文件中的 %buffer 指令
+    pub buffer_params: Vec<BufferParam>,
+    /// dart 中 native assets 的 asset id，指定后使用 @Native 注解绑定 ffi 函数，不再需要 setDylib
+    pub dart_native_asset: Option<String>,
+    /// 是否为普通类和 POD 结构体生成 dart 的不可变数据类（XxxData）
+    pub dart_data_class: bool,
+    /// dart 中标识符的命名规则
+    pub dart_naming: DartNaming,
+    /// 模块中是否有同步回调，解析完所有头文件后计算一次，见 gen_dart::get_has_sync_callback
+    pub has_sync_callback: bool,
+}
+
+impl GenContext {
+    /// 按类型名查找类，它可能定义在其他文件中
+    pub fn find_class(&self, type_str: &str) -> Option<&Class> {
+        for element in &self.hpp_elements {
+            let HppElement::File(file) = element else {
+                continue;
+            };
+            for child in &file.children {
+                if let HppElement::Class(class) = child {
+                    if class.type_str == type_str {
+                        return Some(class);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// 按类型名查找 POD 结构体
+    pub fn find_pod_struct(&self, type_str: &str) -> Option<&Class> {
+        self.find_class(type_str).filter(|class| class.class_type == ClassType::PodStruct)
+    }
+}
+
+/// .i 文件中的 %buffer 指令，把方法的指针参数和长度参数合并为 dart 中的 TypedData
+/// 如 %buffer Image::load(data, len)
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BufferParam {
+    /// 方法名，类的方法需要带类名，如 Image::load，全局函数直接使用函数名
+    pub method_name: String,
+    pub ptr_param: String,
+    pub len_param: String,
 }
 
 #[derive(PartialEq, Eq)]
@@ -106,6 +440,8 @@ pub enum HppElement {
     Method(Method),
     Field(Field),
     Enum(Enum),
+    Constant(Constant),
+    Variable(Variable),
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -122,10 +458,24 @@ pub enum ClassType {
     Callback,
     StdPtr,
     StdVector,
+    StdList,
+    StdDeque,
     StdMap,
+    StdMultimap,
     StdUnorderedMap,
     StdSet,
     StdUnorderedSet,
+    StdOptional,
+    /// std::function 的一种签名，children 中的 call 方法描述它的参数和返回值
+    StdFunction,
+    /// std::variant，value_type 存储 variant 类型本身
+    StdVariant,
+    /// std::weak_ptr，value_type 存储对应的 std::shared_ptr 类型
+    StdWeakPtr,
+    /// std::pair、std::tuple，value_type 存储 pair、tuple 类型本身
+    StdTuple,
+    /// 只有基础数据类型字段的 POD 结构体，c 中为对应的 struct，dart 中为 Struct，按值传递
+    PodStruct,
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -143,9 +493,13 @@ pub struct Class {
     pub comment_str: Option<String>,
     /// 源文件位置
     pub souce_file_path: String,
+    /// 是否特化了 std::hash，特化时 dart 中生成 hashCode
+    pub has_std_hash: bool,
+    /// 回调类中参数、返回值无法桥接的纯虚函数，Impl 子类会是抽象类，解析结束后报错
+    pub unsupported_pure_virtual_methods: Vec<String>,
 }
 
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub enum MethodType {
     /// 实例方法
     #[default]
@@ -156,7 +510,7 @@ pub enum MethodType {
     Destructor,
 }
 
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct Method {
     pub method_type: MethodType,
     pub name: String,
@@ -170,6 +524,16 @@ pub struct Method {
     /// true = 同步调用（使用函数指针）
     /// false = 异步调用（使用 SendPort，默认）
     pub is_sync_callback: bool,
+    /// 重载操作符的符号（如 +、==、[]、()），此时 name 为 ffi 中使用的安全名字（如 operator_add）
+    pub operator_str: Option<String>,
+    /// 是否为 const 成员函数，dart 的只读视图中只包含这些方法
+    pub is_const: bool,
+    /// dart 中是否使用 isLeaf 调用，注释中的 @leaf / @no_leaf 标记，None 时按签名自动判断
+    pub is_leaf: Option<bool>,
+    /// 是否为字段的 getter / setter，回调类的字段也直接调用 ffi，不作为回调方法
+    pub is_field_accessor: bool,
+    /// 返回的 native 对象是否可能为空指针（如 map 的 find() 找不到 key），dart 中返回可空类型
+    pub is_nullable_return: bool,
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -192,7 +556,45 @@ pub struct Enum {
     pub comment_str: Option<String>,
 }
 
+/// 常量：constexpr / const 全局变量、类的 static constexpr 成员、#define 常量
+#[derive(Debug, PartialEq, Eq)]
+pub struct Constant {
+    pub name: String,
+    /// 常量的类型，#define 常量根据值推断
+    pub field_type: FieldType,
+    /// 编译期求得的值，生成 dart 的 const
+    pub value: ConstantValue,
+    /// 注释
+    pub comment_str: Option<String>,
+}
+
+/// 全局变量、类的静态成员变量，生成 getter / setter
 #[derive(Debug, Default, PartialEq, Eq)]
+pub struct Variable {
+    /// 变量名，类的静态成员不包含类名
+    pub name: String,
+    /// c++ 中的完整名字，包含外层的命名空间和类名，如 app::gCounter，生成的 c 代码中使用
+    pub cpp_name: String,
+    pub field_type: FieldType,
+    /// const 变量（编译期无法求值，如 std::string 常量、只有 extern 声明的常量）只生成 getter
+    pub is_const: bool,
+    /// 注释
+    pub comment_str: Option<String>,
+}
+
+/// 常量的值
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ConstantValue {
+    Int(i64),
+    /// 无符号整数，超出 i64 范围时 dart 中使用相同位模式的十六进制字面量
+    UInt(u64),
+    /// 浮点数保存为可以直接作为 dart 字面量的字符串
+    Float(String),
+    Bool(bool),
+    String(String),
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct MethodParam {
     pub name: String,
     pub field_type: FieldType,
@@ -203,27 +605,84 @@ pub struct MethodParam {
 pub enum TypeKind {
     #[default]
     Void,
+    Int8,
+    Int16,
+    Int32,
     Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
     Float,
     Double,
     Char,
     Bool,
 
+    /// std::string、std::string_view、const char*，见 FieldType.string_kind
     String,
+    /// std::u16string，dart 中为 String，ffi 中为 UTF-16 编码
+    U16String,
 
     Class,
+    /// 只有基础数据类型字段的 POD 结构体，按值传递，见 is_pod_struct_type
+    PodStruct,
     Enum,
     StdPtr,
     StdVector,
+    /// std::list，和 std::vector 一样只能按下标访问，dart 中的类为 StdList_T
+    StdList,
+    /// std::deque，dart 中的类为 StdDeque_T
+    StdDeque,
     StdMap,
+    /// std::multimap，find 返回第一个匹配的值，dart 中的类为 StdMultimap_K_V
+    StdMultimap,
     StdUnorderedMap,
     StdSet,
     StdUnorderedSet,
+    /// std::optional，dart 中为可空类型 T?
+    StdOptional,
+    /// std::function，dart 中为函数类型，只支持作为参数和字段
+    StdFunction,
+    /// std::variant，dart 中为 sealed class
+    StdVariant,
+    /// std::unique_ptr，dart 中直接使用被指向的类，返回时所有权转移给 dart 对象，作为参数时 dart 对象失效
+    StdUniquePtr,
+    /// std::weak_ptr，dart 中通过 lock() 得到可空的 StdPtr
+    StdWeakPtr,
+    /// std::pair，dart 中为 record (T1, T2)
+    StdPair,
+    /// std::tuple，dart 中为 record (T1, T2, ...)
+    StdTuple,
+    /// std::chrono::duration，dart 中为 Duration，ffi 中为微秒数
+    StdDuration,
+    /// std::chrono::system_clock::time_point，dart 中为 UTC 的 DateTime，ffi 中为距离 1970 年的微秒数
+    StdTimePoint,
 
     /// 应该被忽略的类型（模板参数、第三方库内部类型等）
     Ignored,
 }
 
+impl TypeKind {
+    /// 是否为整数类型（有符号、无符号 8/16/32/64 位）
+    pub fn is_integer(&self) -> bool {
+        matches!(self,
+            TypeKind::Int8 | TypeKind::Int16 | TypeKind::Int32 | TypeKind::Int64 |
+            TypeKind::UInt8 | TypeKind::UInt16 | TypeKind::UInt32 | TypeKind::UInt64)
+    }
+
+    /// 是否有对应的 dart TypedData（Uint8List、Float32List 等）
+    pub fn is_typed_data_element(&self) -> bool {
+        self.is_integer() || matches!(self, TypeKind::Float | TypeKind::Double | TypeKind::Char)
+    }
+
+    /// ffi 中直接按值传递的基础数据类型，不需要分配内存和转换对象
+    pub fn is_ffi_primitive(&self) -> bool {
+        self.is_integer() || matches!(self,
+            TypeKind::Bool | TypeKind::Float | TypeKind::Double | TypeKind::Char | TypeKind::Enum |
+            TypeKind::StdDuration | TypeKind::StdTimePoint)
+    }
+}
+
 /// 返回值、字段、参数等的类型
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct FieldType {
@@ -239,6 +698,48 @@ pub struct FieldType {
     pub value_type: Option<Box<FieldType>>,
     /// 如果是 map 类型，这里存储 key 类型
     pub key_type: Option<Box<FieldType>>,
+
+    /// 如果是 typedef / using 别名，这里存储别名（嵌套时为全限定名），类型映射仍按底层类型进行
+    pub alias_str: Option<String>,
+
+    /// 是否有 const 修饰，指针和引用时表示被指向的类型是否有 const 修饰
+    pub is_const: bool,
+    /// 是否为引用（T&、T&&），返回引用时不复制对象
+    pub is_reference: bool,
+
+    /// std::function 的参数类型，返回值类型存储在 value_type；std::variant 的备选类型；std::pair、std::tuple 的元素类型
+    pub param_types: Vec<FieldType>,
+
+    /// 定长数组（C 数组、std::array）的长度，此时 ptr_level 为 1，type_kind 为元素的类型
+    pub array_len: Option<usize>,
+
+    /// 指针和长度两个参数表示的 buffer（std::span、%buffer 指令），这里是长度参数的名字，dart 中合并为 TypedData
+    /// 字符串参数的长度参数、字符串返回值的长度输出参数也使用它，支持字符串中间的 0
+    pub buffer_len_param: Option<String>,
+
+    /// 字符串的种类，type_kind 为 String 时有效
+    pub string_kind: StringKind,
+
+    /// 是否为 std::span，按规范类型判断，别名的 full_str 中没有 std::span
+    pub is_std_span: bool,
+
+    /// 是否为 std::array，别名的 full_str 中没有 std::array
+    pub is_std_array: bool,
+
+    /// 是否为 C 数组，别名（如 typedef int Vec3[3]）的 full_str 中没有 []
+    pub is_c_array: bool,
+}
+
+/// TypeKind::String 的种类
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub enum StringKind {
+    /// std::string，拥有内存
+    #[default]
+    StdString,
+    /// std::string_view，不拥有内存
+    StringView,
+    /// const char*，以 0 结尾，不拥有内存
+    CString,
 }
 
 impl HppElement {
@@ -288,6 +789,83 @@ impl HppElement {
         }
     }
 
+    /// 容器类遍历用的迭代器方法，迭代器在 ffi 中为 int64 的句柄
+    /// iter_begin 创建指向第一个元素的迭代器，iter_valid 判断是否到达末尾，iter_next 移动到下一个元素，iter_end 释放迭代器
+    /// iter_value 为当前的元素，map 类的容器还有 iter_key，iter_value 为键对应的值
+    pub fn add_iterator_methods(&mut self, key_type: Option<&FieldType>, value_type: &FieldType) {
+        let new_iterator_param = || MethodParam {
+            name: "it".to_string(),
+            field_type: FieldType {
+                full_str: "int64_t".to_string(),
+                type_str: "int64_t".to_string(),
+                type_kind: TypeKind::Int64,
+                ptr_level: 0,
+                ..Default::default()
+            },
+        };
+        let begin_method = Method {
+            method_type: MethodType::Normal,
+            name: "iter_begin".to_string(),
+            return_type: new_iterator_param().field_type,
+            is_const: true,
+            ..Default::default()
+        };
+        self.add_child(HppElement::Method(begin_method));
+        let valid_method = Method {
+            method_type: MethodType::Normal,
+            name: "iter_valid".to_string(),
+            return_type: FieldType {
+                full_str: "bool".to_string(),
+                type_str: "bool".to_string(),
+                type_kind: TypeKind::Bool,
+                ptr_level: 0,
+                ..Default::default()
+            },
+            params: vec![new_iterator_param()],
+            is_const: true,
+            ..Default::default()
+        };
+        self.add_child(HppElement::Method(valid_method));
+        let next_method = Method {
+            method_type: MethodType::Normal,
+            name: "iter_next".to_string(),
+            return_type: FieldType::new_void(),
+            params: vec![new_iterator_param()],
+            is_const: true,
+            ..Default::default()
+        };
+        self.add_child(HppElement::Method(next_method));
+        if let Some(key_type) = key_type {
+            let key_method = Method {
+                method_type: MethodType::Normal,
+                name: "iter_key".to_string(),
+                return_type: key_type.clone(),
+                params: vec![new_iterator_param()],
+                is_const: true,
+                ..Default::default()
+            };
+            self.add_child(HppElement::Method(key_method));
+        }
+        let value_method = Method {
+            method_type: MethodType::Normal,
+            name: "iter_value".to_string(),
+            return_type: value_type.clone(),
+            params: vec![new_iterator_param()],
+            is_const: true,
+            ..Default::default()
+        };
+        self.add_child(HppElement::Method(value_method));
+        let end_method = Method {
+            method_type: MethodType::Normal,
+            name: "iter_end".to_string(),
+            return_type: FieldType::new_void(),
+            params: vec![new_iterator_param()],
+            is_const: true,
+            ..Default::default()
+        };
+        self.add_child(HppElement::Method(end_method));
+    }
+
     /// 确保 class 必须有析构函数
     pub fn ensure_destructor(&mut self) {
         match self {
@@ -362,12 +940,15 @@ impl HppElement {
         return stdptr_element;
     }
 
+    /// std::vector、std::list、std::deque 共用，只有 std::vector 有 data()、resize()
     pub fn new_stdvector_class_element(field_type: &FieldType) -> Self {
-        let class_name = field_type.get_value_type_str();
-
         let mut stdvector_class = Class::default();
-        stdvector_class.type_str = format!("StdVector_{}", class_name);
-        stdvector_class.class_type = ClassType::StdVector;
+        stdvector_class.type_str = field_type.get_type_name_without_ptr();
+        stdvector_class.class_type = match field_type.type_kind {
+            TypeKind::StdList => ClassType::StdList,
+            TypeKind::StdDeque => ClassType::StdDeque,
+            _ => ClassType::StdVector,
+        };
         stdvector_class.value_type = field_type.value_type.clone();
         let mut stdvector_element = HppElement::Class(stdvector_class);
         // StdVector class 的构造函数
@@ -387,7 +968,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -403,7 +984,7 @@ impl HppElement {
                 field_type: FieldType {
                     full_str: "int".to_string(),
                     type_str: "int".to_string(),
-                    type_kind: TypeKind::Int64,
+                    type_kind: TypeKind::Int32,
                     ptr_level: 0,
                     ..Default::default()
                 },
@@ -411,17 +992,61 @@ impl HppElement {
             ..Default::default()
         };
         stdvector_element.add_child(HppElement::Method(get_method));
+        let push_back_method = Method {
+            method_type: MethodType::Normal,
+            name: "push_back".to_string(),
+            return_type: FieldType::new_void(),
+            params: vec![MethodParam {
+                name: "value".to_string(),
+                field_type: (**field_type.value_type.as_ref().unwrap()).clone(),
+            }],
+            ..Default::default()
+        };
+        stdvector_element.add_child(HppElement::Method(push_back_method));
+
+        // 元素为基础数据类型时，dart 中可以通过 data() 直接访问 native 内存，转换为 TypedData
+        let value_type = field_type.value_type.as_deref().unwrap();
+        if field_type.type_kind == TypeKind::StdVector && value_type.ptr_level == 0 && value_type.type_kind.is_typed_data_element() {
+            let data_method = Method {
+                method_type: MethodType::Normal,
+                name: "data".to_string(),
+                return_type: FieldType {
+                    ptr_level: 1,
+                    ..value_type.clone()
+                },
+                ..Default::default()
+            };
+            stdvector_element.add_child(HppElement::Method(data_method));
+            let resize_method = Method {
+                method_type: MethodType::Normal,
+                name: "resize".to_string(),
+                return_type: FieldType::new_void(),
+                params: vec![MethodParam {
+                    name: "size".to_string(),
+                    field_type: FieldType {
+                        full_str: "size_t".to_string(),
+                        type_str: "size_t".to_string(),
+                        type_kind: TypeKind::UInt64,
+                        ptr_level: 0,
+                        ..Default::default()
+                    },
+                }],
+                ..Default::default()
+            };
+            stdvector_element.add_child(HppElement::Method(resize_method));
+        }
+
+        // 遍历容器的迭代器方法
+        stdvector_element.add_iterator_methods(None, field_type.value_type.as_deref().unwrap());
 
         return stdvector_element;
     }
 
+    /// std::map、std::multimap 共用
     pub fn new_stdmap_class_element(field_type: &FieldType) -> Self {
-        let key_type_name = field_type.get_key_type_str();
-        let value_type_name = field_type.get_value_type_str();
-
         let mut stdmap_class = Class::default();
-        stdmap_class.type_str = format!("StdMap_{}_{}", key_type_name, value_type_name);
-        stdmap_class.class_type = ClassType::StdMap;
+        stdmap_class.type_str = field_type.get_type_name_without_ptr();
+        stdmap_class.class_type = if field_type.type_kind == TypeKind::StdMultimap { ClassType::StdMultimap } else { ClassType::StdMap };
         stdmap_class.key_type = field_type.key_type.clone();
         stdmap_class.value_type = field_type.value_type.clone();
         let mut stdmap_element = HppElement::Class(stdmap_class);
@@ -459,7 +1084,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -493,16 +1118,19 @@ impl HppElement {
         stdmap_element.add_child(HppElement::Method(insert_method));
         
         // find 方法
+        // 返回值的副本，std::unique_ptr 只是借用；找不到时 native 对象返回 null
+        let value_type = field_type.value_type.as_deref().unwrap();
         let find_method = Method {
             method_type: MethodType::Normal,
             name: "find".to_string(),
-            return_type: (**field_type.value_type.as_ref().unwrap()).clone(),
+            return_type: value_type.get_type_for_getter(),
             params: vec![
                 MethodParam {
                     name: "key".to_string(),
                     field_type: (**field_type.key_type.as_ref().unwrap()).clone(),
                 },
             ],
+            is_nullable_return: value_type.is_native_object(),
             ..Default::default()
         };
         stdmap_element.add_child(HppElement::Method(find_method));
@@ -514,7 +1142,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -564,15 +1192,15 @@ impl HppElement {
         };
         stdmap_element.add_child(HppElement::Method(clear_method));
 
+        // 遍历容器的迭代器方法
+        stdmap_element.add_iterator_methods(field_type.key_type.as_deref(), field_type.value_type.as_deref().unwrap());
+
         return stdmap_element;
     }
 
     pub fn new_stdunorderedmap_class_element(field_type: &FieldType) -> Self {
-        let key_type_name = field_type.get_key_type_str();
-        let value_type_name = field_type.get_value_type_str();
-
         let mut stdunorderedmap_class = Class::default();
-        stdunorderedmap_class.type_str = format!("StdUnorderedMap_{}_{}", key_type_name, value_type_name);
+        stdunorderedmap_class.type_str = field_type.get_type_name_without_ptr();
         stdunorderedmap_class.class_type = ClassType::StdUnorderedMap;
         stdunorderedmap_class.key_type = field_type.key_type.clone();
         stdunorderedmap_class.value_type = field_type.value_type.clone();
@@ -610,7 +1238,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -644,16 +1272,19 @@ impl HppElement {
         stdunorderedmap_element.add_child(HppElement::Method(insert_method));
         
         // find 方法
+        // 返回值的副本，std::unique_ptr 只是借用；找不到时 native 对象返回 null
+        let value_type = field_type.value_type.as_deref().unwrap();
         let find_method = Method {
             method_type: MethodType::Normal,
             name: "find".to_string(),
-            return_type: (**field_type.value_type.as_ref().unwrap()).clone(),
+            return_type: value_type.get_type_for_getter(),
             params: vec![
                 MethodParam {
                     name: "key".to_string(),
                     field_type: (**field_type.key_type.as_ref().unwrap()).clone(),
                 },
             ],
+            is_nullable_return: value_type.is_native_object(),
             ..Default::default()
         };
         stdunorderedmap_element.add_child(HppElement::Method(find_method));
@@ -665,7 +1296,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -715,14 +1346,15 @@ impl HppElement {
         };
         stdunorderedmap_element.add_child(HppElement::Method(clear_method));
 
+        // 遍历容器的迭代器方法
+        stdunorderedmap_element.add_iterator_methods(field_type.key_type.as_deref(), field_type.value_type.as_deref().unwrap());
+
         return stdunorderedmap_element;
     }
 
     pub fn new_stdset_class_element(field_type: &FieldType) -> Self {
-        let value_type_name = field_type.get_value_type_str();
-
         let mut stdset_class = Class::default();
-        stdset_class.type_str = format!("StdSet_{}", value_type_name);
+        stdset_class.type_str = field_type.get_type_name_without_ptr();
         stdset_class.class_type = ClassType::StdSet;
         stdset_class.value_type = field_type.value_type.clone();
         let mut stdset_element = HppElement::Class(stdset_class);
@@ -746,7 +1378,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -782,7 +1414,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -832,14 +1464,15 @@ impl HppElement {
         };
         stdset_element.add_child(HppElement::Method(clear_method));
 
+        // 遍历容器的迭代器方法
+        stdset_element.add_iterator_methods(None, field_type.value_type.as_deref().unwrap());
+
         return stdset_element;
     }
 
     pub fn new_stdunorderedset_class_element(field_type: &FieldType) -> Self {
-        let value_type_name = field_type.get_value_type_str();
-
         let mut stdunorderedset_class = Class::default();
-        stdunorderedset_class.type_str = format!("StdUnorderedSet_{}", value_type_name);
+        stdunorderedset_class.type_str = field_type.get_type_name_without_ptr();
         stdunorderedset_class.class_type = ClassType::StdUnorderedSet;
         stdunorderedset_class.value_type = field_type.value_type.clone();
         let mut stdunorderedset_element = HppElement::Class(stdunorderedset_class);
@@ -863,7 +1496,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -899,7 +1532,7 @@ impl HppElement {
             return_type: FieldType {
                 full_str: "int".to_string(),
                 type_str: "int".to_string(),
-                type_kind: TypeKind::Int64,
+                type_kind: TypeKind::Int32,
                 ptr_level: 0,
                 ..Default::default()
             },
@@ -949,69 +1582,646 @@ impl HppElement {
         };
         stdunorderedset_element.add_child(HppElement::Method(clear_method));
 
+        // 遍历容器的迭代器方法
+        stdunorderedset_element.add_iterator_methods(None, field_type.value_type.as_deref().unwrap());
+
         return stdunorderedset_element;
     }
-}
 
-impl fmt::Debug for HppElement {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::File(arg0) => arg0.fmt(f),
-            Self::Class(arg0) => arg0.fmt(f),
-            Self::Method(arg0) => arg0.fmt(f),
-            Self::Field(arg0) => arg0.fmt(f),
-            Self::Enum(arg0) => arg0.fmt(f),
-        }
-    }
-}
+    /// std::optional 在 c ffi 中作为对象传递，dart 中通过它转换为可空类型
+    pub fn new_stdoptional_class_element(field_type: &FieldType) -> Self {
+        let value_type_name = field_type.get_value_type_str();
 
-impl Class {
-    pub fn is_callback(&self) -> bool {
-        return self.class_type == ClassType::Callback
-    }
-}
+        let stdoptional_class = Class {
+            type_str: format!("StdOptional_{}", value_type_name),
+            class_type: ClassType::StdOptional,
+            value_type: field_type.value_type.clone(),
+            ..Default::default()
+        };
+        let mut stdoptional_element = HppElement::Class(stdoptional_class);
 
-pub trait OptionClassExt {
-    fn get_class_name_or_empty(&self) -> &str;
-}
-impl OptionClassExt for Option<&Class> {
-    fn get_class_name_or_empty(&self) -> &str {
-        match self {
-            Some(class) => class.type_str.as_str(),
-            None => "",
+        // 构造函数，构造为空的 optional
+        let constructor_method = Method {
+            method_type: MethodType::Constructor,
+            name: "Constructor".to_string(),
+            return_type: field_type.clone(),
+            ..Default::default()
+        };
+        stdoptional_element.add_child(HppElement::Method(constructor_method));
+        stdoptional_element.ensure_destructor();
+        if field_type.value_type.is_none() {
+            return stdoptional_element;
         }
-    }
-}
 
-impl Method {
-    pub fn new_get_for_field(field: &Field) -> Self {
-        return Method {
+        // has_value 方法
+        let has_value_method = Method {
             method_type: MethodType::Normal,
-            name: format!("get_{}", field.name),
-            return_type: field.field_type.clone(),
-            comment_str: field.comment_str.clone(),
+            name: "has_value".to_string(),
+            return_type: FieldType {
+                full_str: "bool".to_string(),
+                type_str: "bool".to_string(),
+                type_kind: TypeKind::Bool,
+                ptr_level: 0,
+                ..Default::default()
+            },
+            is_const: true,
             ..Default::default()
         };
-    }
-    pub fn new_set_for_field(field: &Field) -> Self {
-        return Method {
+        stdoptional_element.add_child(HppElement::Method(has_value_method));
+
+        // value 方法
+        let value_method = Method {
             method_type: MethodType::Normal,
-            name: format!("set_{}", field.name),
-            return_type: FieldType::new_void(),
-            params: vec![MethodParam {
-                name: field.name.clone(),
-                field_type: field.field_type.clone(),
-            }],
-            comment_str: field.comment_str.clone(),
+            name: "value".to_string(),
+            return_type: (**field_type.value_type.as_ref().unwrap()).clone(),
+            is_const: true,
             ..Default::default()
         };
-    }
-}
+        stdoptional_element.add_child(HppElement::Method(value_method));
 
-impl FieldType {
-    pub fn from_clang_type(clang_type: &Option<clang::Type>) -> Self {
+        // emplace 方法
+        let emplace_method = Method {
+            method_type: MethodType::Normal,
+            name: "emplace".to_string(),
+            return_type: FieldType::new_void(),
+            params: vec![
+                MethodParam {
+                    name: "value".to_string(),
+                    field_type: (**field_type.value_type.as_ref().unwrap()).clone(),
+                },
+            ],
+            ..Default::default()
+        };
+        stdoptional_element.add_child(HppElement::Method(emplace_method));
+
+        // reset 方法
+        let reset_method = Method {
+            method_type: MethodType::Normal,
+            name: "reset".to_string(),
+            return_type: FieldType::new_void(),
+            ..Default::default()
+        };
+        stdoptional_element.add_child(HppElement::Method(reset_method));
+
+        stdoptional_element
+    }
+
+    /// std::function 的每种签名生成一个类，用来保存 dart 闭包，并在 c++ 中调用它
+    pub fn new_stdfunction_class_element(field_type: &FieldType) -> Self {
+        let stdfunction_class = Class {
+            type_str: field_type.get_function_type_str(),
+            class_type: ClassType::StdFunction,
+            value_type: field_type.value_type.clone(),
+            ..Default::default()
+        };
+        let mut stdfunction_element = HppElement::Class(stdfunction_class);
+
+        // call 方法，c++ 调用 std::function 时调用对应的 dart 闭包
+        let call_method = Method {
+            method_type: MethodType::Normal,
+            name: "call".to_string(),
+            return_type: field_type.value_type.as_deref().cloned().unwrap_or_else(FieldType::new_void),
+            params: field_type.param_types.iter().enumerate().map(|(index, param_type)| MethodParam {
+                name: format!("arg{}", index),
+                field_type: param_type.clone(),
+            }).collect(),
+            // 有返回值时需要同步调用
+            is_sync_callback: field_type.value_type.as_ref().is_some_and(|return_type| return_type.type_kind != TypeKind::Void),
+            ..Default::default()
+        };
+        stdfunction_element.add_child(HppElement::Method(call_method));
+
+        stdfunction_element
+    }
+
+    pub fn new_stdweakptr_class_element(field_type: &FieldType) -> Self {
+        let class_name = field_type.type_str.clone();
+        // 对应的 std::shared_ptr
+        let stdptr_type = FieldType {
+            full_str: format!("std::shared_ptr<{}>", class_name),
+            type_str: class_name.clone(),
+            type_kind: TypeKind::StdPtr,
+            ptr_level: 0,
+            ..Default::default()
+        };
+
+        let stdweakptr_class = Class {
+            type_str: format!("StdWeakPtr_{}", class_name),
+            class_type: ClassType::StdWeakPtr,
+            value_type: Some(Box::new(stdptr_type.clone())),
+            ..Default::default()
+        };
+        let mut stdweakptr_element = HppElement::Class(stdweakptr_class);
+        // 构造函数，观察一个 std::shared_ptr
+        let constructor_method = Method {
+            method_type: MethodType::Constructor,
+            name: "Constructor".to_string(),
+            return_type: FieldType {
+                full_str: format!("std::weak_ptr<{}>", class_name),
+                type_str: class_name.clone(),
+                type_kind: TypeKind::StdWeakPtr,
+                ptr_level: 0,
+                ..Default::default()
+            },
+            params: vec![MethodParam {
+                name: "ptr".to_string(),
+                field_type: stdptr_type.clone(),
+            }],
+            ..Default::default()
+        };
+        stdweakptr_element.add_child(HppElement::Method(constructor_method));
+        stdweakptr_element.ensure_destructor();
+
+        // std::weak_ptr.expired()
+        let expired_method = Method {
+            method_type: MethodType::Normal,
+            name: "expired".to_string(),
+            return_type: FieldType {
+                full_str: "bool".to_string(),
+                type_str: "bool".to_string(),
+                type_kind: TypeKind::Bool,
+                ptr_level: 0,
+                ..Default::default()
+            },
+            is_const: true,
+            ..Default::default()
+        };
+        stdweakptr_element.add_child(HppElement::Method(expired_method));
+
+        // std::weak_ptr.lock()，对象已经释放时返回空，dart 中为可空的 StdPtr
+        let lock_method = Method {
+            method_type: MethodType::Normal,
+            name: "lock".to_string(),
+            return_type: FieldType {
+                full_str: format!("std::optional<{}>", stdptr_type.full_str),
+                type_str: format!("std::optional<{}>", stdptr_type.full_str),
+                type_kind: TypeKind::StdOptional,
+                ptr_level: 0,
+                value_type: Some(Box::new(stdptr_type)),
+                ..Default::default()
+            },
+            is_const: true,
+            ..Default::default()
+        };
+        stdweakptr_element.add_child(HppElement::Method(lock_method));
+
+        stdweakptr_element
+    }
+
+    pub fn new_stdvariant_class_element(field_type: &FieldType) -> Self {
+        let stdvariant_class = Class {
+            type_str: field_type.get_variant_type_str(),
+            class_type: ClassType::StdVariant,
+            value_type: Some(Box::new(field_type.clone())),
+            ..Default::default()
+        };
+        // from_x 方法返回的 native 对象，和构造函数一样按类处理
+        let class_return_type = FieldType {
+            full_str: stdvariant_class.type_str.clone(),
+            type_str: stdvariant_class.type_str.clone(),
+            type_kind: TypeKind::Class,
+            ptr_level: 0,
+            ..Default::default()
+        };
+        let mut stdvariant_element = HppElement::Class(stdvariant_class);
+        stdvariant_element.ensure_destructor();
+
+        // index 方法，当前值的备选类型的下标
+        let index_method = Method {
+            method_type: MethodType::Normal,
+            name: "index".to_string(),
+            return_type: FieldType {
+                full_str: "int64_t".to_string(),
+                type_str: "int64_t".to_string(),
+                type_kind: TypeKind::Int64,
+                ptr_level: 0,
+                ..Default::default()
+            },
+            is_const: true,
+            ..Default::default()
+        };
+        stdvariant_element.add_child(HppElement::Method(index_method));
+
+        for (index, alternative_type) in field_type.param_types.iter().enumerate() {
+            // std::monostate 没有值
+            let is_monostate = alternative_type.type_kind == TypeKind::Void;
+
+            // from_x 方法，以第 x 个备选类型构造 variant
+            let from_method = Method {
+                method_type: MethodType::Normal,
+                name: format!("from_{}", index),
+                return_type: class_return_type.clone(),
+                params: if is_monostate {
+                    vec![]
+                } else {
+                    vec![MethodParam {
+                        name: "value".to_string(),
+                        field_type: alternative_type.clone(),
+                    }]
+                },
+                is_static: true,
+                ..Default::default()
+            };
+            stdvariant_element.add_child(HppElement::Method(from_method));
+
+            // get_x 方法，取第 x 个备选类型的值
+            if !is_monostate {
+                let get_method = Method {
+                    method_type: MethodType::Normal,
+                    name: format!("get_{}", index),
+                    return_type: alternative_type.clone(),
+                    is_const: true,
+                    ..Default::default()
+                };
+                stdvariant_element.add_child(HppElement::Method(get_method));
+            }
+        }
+
+        stdvariant_element
+    }
+
+    pub fn new_stdtuple_class_element(field_type: &FieldType) -> Self {
+        let stdtuple_class = Class {
+            type_str: field_type.get_tuple_type_str(),
+            class_type: ClassType::StdTuple,
+            value_type: Some(Box::new(field_type.clone())),
+            ..Default::default()
+        };
+        // make 方法返回的 native 对象，和构造函数一样按类处理
+        let class_return_type = FieldType {
+            full_str: stdtuple_class.type_str.clone(),
+            type_str: stdtuple_class.type_str.clone(),
+            type_kind: TypeKind::Class,
+            ptr_level: 0,
+            ..Default::default()
+        };
+        let mut stdtuple_element = HppElement::Class(stdtuple_class);
+        stdtuple_element.ensure_destructor();
+
+        // make 方法，以所有元素构造 pair、tuple
+        let make_method = Method {
+            method_type: MethodType::Normal,
+            name: "make".to_string(),
+            return_type: class_return_type,
+            params: field_type.param_types.iter().enumerate().map(|(index, element_type)| MethodParam {
+                name: format!("value_{}", index),
+                field_type: element_type.clone(),
+            }).collect(),
+            is_static: true,
+            ..Default::default()
+        };
+        stdtuple_element.add_child(HppElement::Method(make_method));
+
+        // get_x 方法，取第 x 个元素的值
+        for (index, element_type) in field_type.param_types.iter().enumerate() {
+            let get_method = Method {
+                method_type: MethodType::Normal,
+                name: format!("get_{}", index),
+                return_type: element_type.clone(),
+                is_const: true,
+                ..Default::default()
+            };
+            stdtuple_element.add_child(HppElement::Method(get_method));
+        }
+
+        stdtuple_element
+    }
+}
+
+impl fmt::Debug for HppElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(arg0) => arg0.fmt(f),
+            Self::Class(arg0) => arg0.fmt(f),
+            Self::Method(arg0) => arg0.fmt(f),
+            Self::Field(arg0) => arg0.fmt(f),
+            Self::Enum(arg0) => arg0.fmt(f),
+            Self::Constant(arg0) => arg0.fmt(f),
+            Self::Variable(arg0) => arg0.fmt(f),
+        }
+    }
+}
+
+impl Class {
+    pub fn is_callback(&self) -> bool {
+        return self.class_type == ClassType::Callback
+    }
+
+    /// 容器类批量构造时每个元素的参数名和类型，map 类的容器为键和值，元素不能批量复制时为空
+    pub fn get_bulk_element_types(&self) -> Vec<(&'static str, &FieldType)> {
+        if !self.is_container() {
+            return vec![];
+        }
+        let mut element_types = vec![];
+        if matches!(self.class_type, ClassType::StdMap | ClassType::StdMultimap | ClassType::StdUnorderedMap) {
+            let Some(key_type) = self.key_type.as_deref() else {
+                return vec![];
+            };
+            element_types.push(("keys", key_type));
+        }
+        let Some(value_type) = self.value_type.as_deref() else {
+            return vec![];
+        };
+        element_types.push(("values", value_type));
+        if element_types.iter().all(|(_, element_type)| element_type.is_bulk_element()) {
+            return element_types;
+        }
+        vec![]
+    }
+
+    /// 容器类在 c++ 中的类型，如 std::vector<int>，来自构造函数的返回值
+    pub fn get_container_type_str(&self) -> Option<String> {
+        for child in &self.children {
+            if let HppElement::Method(method) = child {
+                if method.method_type == MethodType::Constructor && method.name == "Constructor" {
+                    return Some(method.return_type.type_str.clone());
+                }
+            }
+        }
+        None
+    }
+
+    /// 是否为 stl 容器类，这些类有遍历用的迭代器方法
+    pub fn is_container(&self) -> bool {
+        matches!(self.class_type,
+            ClassType::StdVector | ClassType::StdList | ClassType::StdDeque |
+            ClassType::StdMap | ClassType::StdMultimap | ClassType::StdUnorderedMap |
+            ClassType::StdSet | ClassType::StdUnorderedSet)
+    }
+
+    /// C FFI 中使用的类名，见 get_ffi_type_name
+    pub fn get_ffi_name(&self) -> String {
+        get_ffi_type_name(&self.type_str)
+    }
+
+    /// Dart 中使用的类名，见 get_dart_type_name
+    pub fn get_dart_name(&self, naming: &DartNaming) -> String {
+        get_dart_type_name(naming, &self.type_str)
+    }
+}
+
+pub trait OptionClassExt {
+    fn get_class_name_or_empty(&self) -> &str;
+    fn get_ffi_class_name_or_empty(&self) -> String;
+}
+impl OptionClassExt for Option<&Class> {
+    fn get_class_name_or_empty(&self) -> &str {
+        match self {
+            Some(class) => class.type_str.as_str(),
+            None => "",
+        }
+    }
+    fn get_ffi_class_name_or_empty(&self) -> String {
+        match self {
+            Some(class) => class.get_ffi_name(),
+            None => "".to_string(),
+        }
+    }
+}
+
+impl Field {
+    /// const 字段、std::string_view、const char* 字段只能读取，dart 传入的字符串在设置之后就释放了
+    pub fn has_setter(&self) -> bool {
+        let is_const_field = self.field_type.is_const && (self.field_type.ptr_level == 0 || self.field_type.array_len.is_some());
+        !is_const_field && !self.field_type.is_borrowed_string()
+    }
+}
+
+impl Variable {
+    /// const 变量，以及 std::string_view、const char* 变量只能读取
+    pub fn has_setter(&self) -> bool {
+        !self.is_const && !self.field_type.is_borrowed_string()
+    }
+}
+
+impl Method {
+    /// 长度参数对应的 buffer 指针参数，见 FieldType.buffer_len_param
+    pub fn get_buffer_param_for_len(&self, len_param_name: &str) -> Option<&MethodParam> {
+        self.params.iter().find(|param| param.field_type.buffer_len_param.as_deref() == Some(len_param_name))
+    }
+
+    /// 是否为字符串返回值的长度输出参数，见 FieldType.buffer_len_param
+    pub fn is_return_len_param(&self, param_name: &str) -> bool {
+        self.return_type.buffer_len_param.as_deref() == Some(param_name)
+    }
+
+    pub fn new_get_for_field(field: &Field) -> Self {
+        return Method {
+            method_type: MethodType::Normal,
+            name: format!("get_{}", field.name),
+            return_type: field.field_type.get_type_for_getter(),
+            comment_str: field.comment_str.clone(),
+            is_const: true,
+            is_leaf: get_accessor_is_leaf(&field.field_type),
+            is_field_accessor: true,
+            ..Default::default()
+        };
+    }
+    pub fn new_set_for_field(field: &Field) -> Self {
+        return Method {
+            method_type: MethodType::Normal,
+            name: format!("set_{}", field.name),
+            return_type: FieldType::new_void(),
+            params: vec![MethodParam {
+                name: field.name.clone(),
+                field_type: field.field_type.clone(),
+            }],
+            comment_str: field.comment_str.clone(),
+            is_leaf: get_accessor_is_leaf(&field.field_type),
+            is_field_accessor: true,
+            ..Default::default()
+        };
+    }
+
+    /// 全局变量、类的静态成员变量的 getter，不需要对象参数
+    pub fn new_get_for_variable(variable: &Variable) -> Self {
+        Method {
+            method_type: MethodType::Normal,
+            name: format!("get_{}", variable.name),
+            return_type: variable.field_type.get_type_for_getter(),
+            is_static: true,
+            comment_str: variable.comment_str.clone(),
+            is_leaf: get_accessor_is_leaf(&variable.field_type),
+            ..Default::default()
+        }
+    }
+    pub fn new_set_for_variable(variable: &Variable) -> Self {
+        Method {
+            method_type: MethodType::Normal,
+            name: format!("set_{}", variable.name),
+            return_type: FieldType::new_void(),
+            params: vec![MethodParam {
+                name: variable.name.clone(),
+                field_type: variable.field_type.clone(),
+            }],
+            is_static: true,
+            comment_str: variable.comment_str.clone(),
+            is_leaf: get_accessor_is_leaf(&variable.field_type),
+            ..Default::default()
+        }
+    }
+}
+
+/// 字段、变量的读写不会回调 dart，默认使用 isLeaf 调用；
+/// 类、容器等 native 对象的复制和析构会执行任意的 c++ 代码，std::function 替换时会释放 dart 闭包，这些按签名判断
+fn get_accessor_is_leaf(field_type: &FieldType) -> Option<bool> {
+    if field_type.is_native_object() || field_type.type_kind == TypeKind::StdFunction {
+        return None;
+    }
+    Some(true)
+}
+
+/// 整数类型的种类和 c ffi 中使用的类型名，不是整数类型时返回 None
+/// int 保持原样，其余统一为 stdint 中的定宽类型，long、size_t 等平台相关类型按实际位宽确定
+/// 指针、数组的元素类型必须和 c++ 中完全相同，平台相关类型使用 clang 的规范类型（如 size_t* -> unsigned long*），
+/// macOS 上 int64_t 是 long long，和 long 的指针不能互相转换
+fn get_integer_type(type_name: &str, clang_type: &clang::Type) -> Option<(TypeKind, String)> {
+    let type_name = type_name.trim_start_matches("std::").replace("&", "");
+    let type_name = type_name.trim();
+    if type_name == "int" {
+        return Some((TypeKind::Int32, "int".to_string()));
+    }
+
+    let signed_names = [
+        "signed char", "int8_t",
+        "short", "short int", "signed short", "signed short int", "int16_t",
+        "signed", "signed int", "int32_t",
+        "long", "long int", "signed long", "signed long int",
+        "long long", "long long int", "signed long long", "signed long long int", "int64_t",
+        "ssize_t", "ptrdiff_t", "intptr_t",
+    ];
+    let unsigned_names = [
+        "unsigned char", "uint8_t",
+        "unsigned short", "unsigned short int", "uint16_t",
+        "unsigned", "unsigned int", "uint32_t",
+        "unsigned long", "unsigned long int", "unsigned long long", "unsigned long long int", "uint64_t",
+        "size_t", "uintptr_t",
+    ];
+    let is_unsigned = if signed_names.contains(&type_name) {
+        false
+    } else if unsigned_names.contains(&type_name) {
+        true
+    } else {
+        return None;
+    };
+
+    // 去掉指针、引用、数组后的类型
+    let mut base_type = *clang_type;
+    let mut is_indirect = false;
+    loop {
+        if let Some(pointee) = base_type.get_pointee_type() {
+            base_type = pointee;
+        } else if let Some(element) = base_type.get_element_type() {
+            base_type = element;
+        } else {
+            break;
+        }
+        is_indirect = true;
+    }
+
+    // 位宽：定宽类型直接从名字得到，其余从 clang 获取
+    let is_fixed_width = ["8_t", "16_t", "32_t", "64_t"].iter().any(|suffix| type_name.ends_with(suffix));
+    let bit_width = if type_name.ends_with("8_t") {
+        8
+    } else if type_name.ends_with("16_t") {
+        16
+    } else if type_name.ends_with("32_t") {
+        32
+    } else if type_name.ends_with("64_t") {
+        64
+    } else {
+        base_type.get_sizeof().map(|size| size * 8).unwrap_or(64)
+    };
+
+    let type_kind = match (is_unsigned, bit_width) {
+        (false, 8) => TypeKind::Int8,
+        (false, 16) => TypeKind::Int16,
+        (false, 32) => TypeKind::Int32,
+        (false, _) => TypeKind::Int64,
+        (true, 8) => TypeKind::UInt8,
+        (true, 16) => TypeKind::UInt16,
+        (true, 32) => TypeKind::UInt32,
+        (true, _) => TypeKind::UInt64,
+    };
+    let type_str = if is_indirect && !is_fixed_width {
+        let canonical_str = base_type.get_canonical_type().get_display_name();
+        canonical_str.trim_start_matches("const ").trim_start_matches("volatile ").to_string()
+    } else {
+        format!("{}int{}_t", if is_unsigned { "u" } else { "" }, bit_width)
+    };
+
+    Some((type_kind, type_str))
+}
+
+/// std::function 的返回值，同步调用 dart 闭包后转换为 c++ 类型
+fn is_supported_function_return_type(field_type: &FieldType) -> bool {
+    if field_type.ptr_level != 0 {
+        return false;
+    }
+    field_type.type_kind.is_integer() || matches!(field_type.type_kind,
+        TypeKind::Void | TypeKind::Bool | TypeKind::Float | TypeKind::Double | TypeKind::Char)
+}
+
+/// std::function 的参数，和回调类的参数一样传递给 dart
+fn is_supported_function_param_type(field_type: &FieldType) -> bool {
+    match field_type.type_kind {
+        TypeKind::Class => true,
+        // 不拥有内存的字符串在调用 dart 闭包时可能已经失效
+        TypeKind::String => !field_type.is_borrowed_string(),
+        TypeKind::Bool | TypeKind::Float | TypeKind::Double | TypeKind::Char | TypeKind::Enum |
+        TypeKind::StdPtr | TypeKind::StdVector | TypeKind::StdOptional |
+        TypeKind::StdDuration | TypeKind::StdTimePoint => field_type.ptr_level == 0,
+        ref kind if kind.is_integer() => field_type.ptr_level == 0,
+        _ => false,
+    }
+}
+
+/// std::pair、std::tuple 的元素类型，和 std::variant 的备选类型一样，需要能够按值返回和传递
+fn is_supported_tuple_element_type(field_type: &FieldType) -> bool {
+    field_type.type_kind != TypeKind::Void && is_supported_variant_alternative_type(field_type)
+}
+
+/// std::variant 的备选类型，需要能够按值返回和传递
+fn is_supported_variant_alternative_type(field_type: &FieldType) -> bool {
+    if field_type.ptr_level != 0 {
+        return false;
+    }
+    match field_type.type_kind {
+        // std::monostate
+        TypeKind::Void => field_type.full_str == "std::monostate",
+        // 不拥有内存的字符串不能保存
+        TypeKind::String => !field_type.is_borrowed_string(),
+        TypeKind::Bool | TypeKind::Float | TypeKind::Double | TypeKind::Char | TypeKind::Enum |
+        TypeKind::Class | TypeKind::StdPtr | TypeKind::StdVector | TypeKind::StdOptional => true,
+        ref kind if kind.is_integer() => true,
+        _ => false,
+    }
+}
+
+impl FieldType {
+    pub fn from_clang_type(clang_type: &Option<clang::Type>) -> Self {
         // println!("clang_type: {:?}, {:?}, {:?}", clang_type, clang_type.unwrap().get_kind(), clang_type.unwrap().get_template_argument_types());
 
+        // typedef / using 别名，按底层类型映射
+        if let Some(mut field_type) = FieldType::from_clang_alias_type(clang_type.unwrap()) {
+            field_type.fill_qualifiers(&clang_type.unwrap());
+            return field_type;
+        }
+        let mut field_type = FieldType::from_clang_type_without_qualifiers(clang_type);
+        field_type.fill_qualifiers(&clang_type.unwrap());
+        field_type.fill_array_len(&clang_type.unwrap());
+        // 容器中的 std::string_view、const char* 引用的内存无法确定生命周期，std::u16string、std::chrono 类型只支持直接使用
+        let is_unsupported_element = |element_type: &FieldType| element_type.is_borrowed_string()
+            || matches!(element_type.type_kind, TypeKind::U16String | TypeKind::StdDuration | TypeKind::StdTimePoint);
+        if field_type.value_type.as_deref().is_some_and(is_unsupported_element)
+        || field_type.key_type.as_deref().is_some_and(is_unsupported_element) {
+            field_type.type_kind = TypeKind::Ignored;
+        }
+        field_type
+    }
+
+    fn from_clang_type_without_qualifiers(clang_type: &Option<clang::Type>) -> Self {
+
         let mut display_name = clang_type.unwrap().get_display_name();
         let mut field_type = FieldType::default();
         field_type.full_str = display_name.clone();
@@ -1042,6 +2252,42 @@ impl FieldType {
             field_type.type_str = "String".to_string();
             return field_type;
         }
+        // std::string_view，不拥有内存
+        else if clean_string_type == "std::string_view" || clean_string_type == "string_view" {
+            field_type.type_kind = TypeKind::String;
+            field_type.full_str = "std::string_view".to_string();
+            field_type.type_str = "String".to_string();
+            field_type.string_kind = StringKind::StringView;
+            return field_type;
+        }
+        // const char*，以 0 结尾的字符串，不拥有内存；非 const 的 char* 可能是输出参数，仍然作为指针处理
+        else if clang_type.unwrap().get_kind() == clang::TypeKind::Pointer
+        && clang_type.unwrap().get_pointee_type().is_some_and(|pointee| pointee.get_display_name() == "const char") {
+            field_type.type_kind = TypeKind::String;
+            field_type.full_str = "const char*".to_string();
+            field_type.type_str = "String".to_string();
+            field_type.string_kind = StringKind::CString;
+            return field_type;
+        }
+        // std::u16string
+        else if clean_string_type == "std::u16string" || clean_string_type == "u16string" {
+            field_type.type_kind = TypeKind::U16String;
+            field_type.full_str = "std::u16string".to_string();
+            field_type.type_str = "U16String".to_string();
+            return field_type;
+        }
+        // std::chrono::duration、std::chrono::system_clock::time_point
+        // milliseconds 等都是 typedef，按规范类型判断，type_str 也使用规范类型，保证在生成的 c++ 代码中可用
+        else if let Some(chrono_kind) = FieldType::get_chrono_type_kind(&clang_type.unwrap()) {
+            let canonical_type = clang_type.unwrap().get_canonical_type();
+            let canonical_type = canonical_type.get_pointee_type().unwrap_or(canonical_type);
+            field_type.type_kind = chrono_kind;
+            field_type.type_str = canonical_type.get_display_name().replace("const ", "");
+            if clang_type.unwrap().get_kind() == clang::TypeKind::Pointer {
+                field_type.type_kind = TypeKind::Ignored;
+            }
+            return field_type;
+        }
         // std::shared_ptr
         else if lower_full_str.starts_with("std::shared_ptr") {
             field_type.type_kind = TypeKind::StdPtr;
@@ -1052,15 +2298,90 @@ impl FieldType {
             }
             return field_type;
         }
+        // std::unique_ptr
+        else if lower_full_str.starts_with("std::unique_ptr") {
+            field_type.type_kind = TypeKind::StdUniquePtr;
+            if let (Some(start), Some(end)) = (field_type.full_str.find('<'), field_type.full_str.rfind('>')) {
+                field_type.type_str = field_type.full_str[start + 1..end].trim().to_string();
+            } else {
+                field_type.type_str = "std::unique_ptr".to_string();
+            }
+            // dart 中对应被指向的类，只支持指向类、结构体的 unique_ptr，std::unique_ptr<int> 等按规范类型判断后忽略
+            let unique_ptr_type = clang_type.unwrap().get_pointee_type().unwrap_or(clang_type.unwrap());
+            let is_record_pointee = unique_ptr_type.get_canonical_type().get_template_argument_types()
+                .and_then(|template_args| template_args.first().cloned().flatten())
+                .is_some_and(|pointee_type| pointee_type.get_canonical_type().get_kind() == clang::TypeKind::Record);
+            // 自定义 deleter、数组的 unique_ptr 无法直接交给 dart 对象释放
+            if !is_record_pointee || field_type.type_str.contains(',') || field_type.type_str.ends_with("[]") {
+                field_type.type_kind = TypeKind::Ignored;
+            }
+            return field_type;
+        }
+        // std::weak_ptr
+        else if lower_full_str.starts_with("std::weak_ptr") {
+            field_type.type_kind = TypeKind::StdWeakPtr;
+            if let (Some(start), Some(end)) = (field_type.full_str.find('<'), field_type.full_str.rfind('>')) {
+                field_type.type_str = field_type.full_str[start + 1..end].trim().to_string();
+            } else {
+                field_type.type_str = "std::weak_ptr".to_string();
+            }
+            return field_type;
+        }
         // 数组
         else if clang_type.unwrap().get_kind() == clang::TypeKind::ConstantArray {
             lower_full_str = lower_full_str.split_once("[").unwrap_or((&lower_full_str, "")).0.trim().to_string();
             field_type.ptr_level = 1;
+            field_type.is_c_array = true;
         }
-        // std::vector
-        else if lower_full_str.starts_with("std::vector") {
-            field_type.type_kind = TypeKind::StdVector;
-            field_type.type_str = display_name.clone();
+        // std::array，和 C 数组一样处理
+        else if lower_full_str.starts_with("std::array") {
+            field_type.is_std_array = true;
+            let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
+            let Some(value_clang_type) = template_args.first().cloned().flatten() else {
+                field_type.type_kind = TypeKind::Ignored;
+                field_type.type_str = "std::array".to_string();
+                return field_type;
+            };
+            let value_type = FieldType::from_clang_type(&Some(value_clang_type));
+            field_type.type_kind = value_type.type_kind.clone();
+            field_type.type_str = value_type.type_str.clone();
+            field_type.ptr_level = 1;
+            // 长度是非类型模板参数，从类型名中取，如 std::array<int, 4>
+            field_type.array_len = display_name.rsplit_once(',')
+                .and_then(|(_, len_str)| len_str.trim_end_matches('>').trim().parse::<usize>().ok());
+            if field_type.array_len.is_none() || !field_type.is_supported_array_element_type() {
+                field_type.type_kind = TypeKind::Ignored;
+            }
+            return field_type;
+        }
+        // std::span，作为参数时拆分为指针和长度两个参数，见 handle_clang_ParmDecl
+        else if FieldType::is_clang_std_span(&clang_type.unwrap()) {
+            field_type.is_std_span = true;
+            let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
+            let Some(value_clang_type) = template_args.first().cloned().flatten() else {
+                field_type.type_kind = TypeKind::Ignored;
+                field_type.type_str = "std::span".to_string();
+                return field_type;
+            };
+            let value_type = FieldType::from_clang_type(&Some(value_clang_type));
+            field_type.type_kind = value_type.type_kind.clone();
+            field_type.type_str = value_type.type_str.clone();
+            field_type.ptr_level = 1;
+            if value_type.ptr_level != 0 || !value_type.type_kind.is_typed_data_element() {
+                field_type.type_kind = TypeKind::Ignored;
+            }
+            return field_type;
+        }
+        // std::vector、std::list、std::deque
+        else if lower_full_str.starts_with("std::vector") || lower_full_str.starts_with("std::list") || lower_full_str.starts_with("std::deque") {
+            field_type.type_kind = if lower_full_str.starts_with("std::list") {
+                TypeKind::StdList
+            } else if lower_full_str.starts_with("std::deque") {
+                TypeKind::StdDeque
+            } else {
+                TypeKind::StdVector
+            };
+            field_type.type_str = get_clean_container_type_str(&display_name);
 
             let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
             let value_clang_type = template_args.first().unwrap();
@@ -1069,10 +2390,10 @@ impl FieldType {
             field_type.value_type = Some(Box::new(value_type));
             return field_type;
         }
-        // std::map
-        else if lower_full_str.starts_with("std::map") {
-            field_type.type_kind = TypeKind::StdMap;
-            field_type.type_str = display_name.clone();
+        // std::map、std::multimap
+        else if lower_full_str.starts_with("std::map") || lower_full_str.starts_with("std::multimap") {
+            field_type.type_kind = if lower_full_str.starts_with("std::multimap") { TypeKind::StdMultimap } else { TypeKind::StdMap };
+            field_type.type_str = get_clean_container_type_str(&display_name);
 
             let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
             if template_args.len() >= 2 {
@@ -1090,7 +2411,7 @@ impl FieldType {
         // std::unordered_map
         else if lower_full_str.starts_with("std::unordered_map") {
             field_type.type_kind = TypeKind::StdUnorderedMap;
-            field_type.type_str = display_name.clone();
+            field_type.type_str = get_clean_container_type_str(&display_name);
 
             let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
             if template_args.len() >= 2 {
@@ -1108,7 +2429,7 @@ impl FieldType {
         // std::set
         else if lower_full_str.starts_with("std::set") {
             field_type.type_kind = TypeKind::StdSet;
-            field_type.type_str = display_name.clone();
+            field_type.type_str = get_clean_container_type_str(&display_name);
 
             let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
             let value_clang_type = template_args.first().unwrap();
@@ -1117,10 +2438,95 @@ impl FieldType {
             field_type.value_type = Some(Box::new(value_type));
             return field_type;
         }
+        // std::optional
+        else if lower_full_str.starts_with("std::optional") {
+            field_type.type_kind = TypeKind::StdOptional;
+
+            let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
+            if let Some(value_clang_type) = template_args.first() {
+                let value_type = FieldType::from_clang_type(value_clang_type);
+                // 不带修饰的类型名，生成的 c++ 代码中用来创建 optional
+                field_type.type_str = format!("std::optional<{}>", value_type.full_str);
+                // 值类型被忽略时，optional 也无法生成绑定
+                if value_type.type_kind == TypeKind::Ignored {
+                    field_type.type_kind = TypeKind::Ignored;
+                }
+                field_type.value_type = Some(Box::new(value_type));
+            } else {
+                field_type.type_str = "std::optional".to_string();
+            }
+            return field_type;
+        }
+        // std::function
+        else if lower_full_str.starts_with("std::function") {
+            field_type.type_kind = TypeKind::StdFunction;
+
+            let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
+            let Some(function_clang_type) = template_args.first().cloned().flatten() else {
+                field_type.type_kind = TypeKind::Ignored;
+                field_type.type_str = "std::function".to_string();
+                return field_type;
+            };
+            let return_type = FieldType::from_clang_type(&function_clang_type.get_result_type());
+            let param_types: Vec<FieldType> = function_clang_type.get_argument_types().unwrap_or_default()
+                .iter().map(|param_clang_type| FieldType::from_clang_type(&Some(*param_clang_type))).collect();
+            // 不带修饰的类型名，生成的 c++ 代码中用来创建 std::function
+            let param_strs: Vec<&str> = param_types.iter().map(|param_type| param_type.full_str.as_str()).collect();
+            field_type.type_str = format!("std::function<{}({})>", return_type.full_str, param_strs.join(", "));
+            // 闭包的参数和返回值只支持能够在回调中传递的类型
+            if !is_supported_function_return_type(&return_type) || !param_types.iter().all(is_supported_function_param_type) {
+                field_type.type_kind = TypeKind::Ignored;
+            }
+            field_type.value_type = Some(Box::new(return_type));
+            field_type.param_types = param_types;
+            return field_type;
+        }
+        // std::variant
+        else if lower_full_str.starts_with("std::variant") {
+            field_type.type_kind = TypeKind::StdVariant;
+
+            let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
+            let alternative_types: Vec<FieldType> = template_args.iter().map(|alternative_clang_type| {
+                let alternative_type = FieldType::from_clang_type(alternative_clang_type);
+                // std::monostate 表示没有值
+                if alternative_type.type_str == "std::monostate" {
+                    return FieldType {
+                        full_str: "std::monostate".to_string(),
+                        type_str: "Monostate".to_string(),
+                        ..Default::default()
+                    };
+                }
+                alternative_type
+            }).collect();
+            // 不带修饰的类型名，生成的 c++ 代码中用来创建 variant
+            let alternative_strs: Vec<&str> = alternative_types.iter().map(|alternative_type| alternative_type.full_str.as_str()).collect();
+            field_type.type_str = format!("std::variant<{}>", alternative_strs.join(", "));
+            if alternative_types.is_empty() || !alternative_types.iter().all(is_supported_variant_alternative_type) {
+                field_type.type_kind = TypeKind::Ignored;
+            }
+            field_type.param_types = alternative_types;
+            return field_type;
+        }
+        // std::pair、std::tuple
+        else if lower_full_str.starts_with("std::pair") || lower_full_str.starts_with("std::tuple") {
+            let is_pair = lower_full_str.starts_with("std::pair");
+            field_type.type_kind = if is_pair { TypeKind::StdPair } else { TypeKind::StdTuple };
+
+            let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
+            let element_types: Vec<FieldType> = template_args.iter().map(FieldType::from_clang_type).collect();
+            // 不带修饰的类型名，生成的 c++ 代码中用来创建 pair、tuple
+            let element_strs: Vec<&str> = element_types.iter().map(|element_type| element_type.full_str.as_str()).collect();
+            field_type.type_str = format!("std::{}<{}>", if is_pair { "pair" } else { "tuple" }, element_strs.join(", "));
+            if element_types.is_empty() || !element_types.iter().all(is_supported_tuple_element_type) {
+                field_type.type_kind = TypeKind::Ignored;
+            }
+            field_type.param_types = element_types;
+            return field_type;
+        }
         // std::unordered_set
         else if lower_full_str.starts_with("std::unordered_set") {
             field_type.type_kind = TypeKind::StdUnorderedSet;
-            field_type.type_str = display_name.clone();
+            field_type.type_str = get_clean_container_type_str(&display_name);
 
             let template_args = clang_type.unwrap().get_template_argument_types().unwrap_or_default();
             let value_clang_type = template_args.first().unwrap();
@@ -1140,29 +2546,24 @@ impl FieldType {
         if is_enum_type {
             field_type.type_kind = TypeKind::Enum;
             field_type.type_str = display_name.clone();
+            field_type.qualify_nested_type(&clang_type.unwrap());
             return field_type;
         }
 
         let lower_full_str_without_ptr = lower_full_str.trim_end_matches('*').trim();
+
+        // 整数类型，按实际位宽和符号区分
+        if let Some((type_kind, type_str)) = get_integer_type(lower_full_str_without_ptr, &clang_type.unwrap()) {
+            field_type.type_kind = type_kind;
+            field_type.type_str = type_str;
+            return field_type;
+        }
+
         match lower_full_str_without_ptr {
             "void" => {
                 field_type.type_kind = TypeKind::Void;
                 field_type.type_str = "void".to_string();
             }
-            "int" | "int64_t" | "size_t" | "uint64_t" => {
-                field_type.type_kind = TypeKind::Int64;
-                match lower_full_str_without_ptr {                
-                    "int64_t" | "size_t" | "uint64_t" => {
-                        field_type.type_str = "int64_t".to_string();
-                    }
-                    "int" => {
-                        field_type.type_str = "int".to_string();
-                    }
-                    _ => {
-                        field_type.type_str = "int".to_string();
-                    }
-                }
-            }
             "float" => {
                 field_type.type_kind = TypeKind::Float;
                 field_type.type_str = "float".to_string();
@@ -1175,21 +2576,10 @@ impl FieldType {
                 field_type.type_kind = TypeKind::Char;
                 field_type.type_str = "char".to_string();
             }
-            "unsigned char" => {
-                field_type.type_kind = TypeKind::Char;
-                field_type.type_str = "unsigned char".to_string();
-            }
             "bool" => {
                 field_type.type_kind = TypeKind::Bool;
                 field_type.type_str = "bool".to_string();
             }
-            "std::uint8_t" | "uint8_t" | "std::uint16_t" | "uint16_t" |
-            "std::uint32_t" | "uint32_t" | "std::int8_t" | "int8_t" |
-            "std::int16_t" | "int16_t" | "std::int32_t" | "int32_t" => {
-                // std 整数类型映射到 Int64
-                field_type.type_kind = TypeKind::Int64;
-                field_type.type_str = "int64_t".to_string();
-            }
             _ => {
                 // 获取类型名称用于检查
                 let type_display_name = if let Some(_pointee) = clang_type.unwrap().get_pointee_type() {
@@ -1204,14 +2594,26 @@ impl FieldType {
                     field_type.type_str = type_display_name;
                 } else {
                     // 指针类型
-                    if let Some(_pointee) = clang_type.unwrap().get_pointee_type() {
+                    if let Some(pointee) = clang_type.unwrap().get_pointee_type() {
                         field_type.type_kind = TypeKind::Class;
-                        field_type.type_str = clang_type.unwrap().get_pointee_type().unwrap().get_display_name();
+                        field_type.type_str = pointee.get_display_name();
+                        field_type.qualify_nested_type(&pointee);
+                        if is_pod_struct_type(&pointee) {
+                            field_type.type_kind = TypeKind::PodStruct;
+                        }
                     }
                     // 非指针类型
                     else {
                         field_type.type_kind = TypeKind::Class;
                         field_type.type_str = clang_type.unwrap().get_display_name();
+                        field_type.qualify_nested_type(&clang_type.unwrap());
+                        if is_pod_struct_type(&clang_type.unwrap()) {
+                            field_type.type_kind = TypeKind::PodStruct;
+                        }
+                    }
+                    // POD 结构体只支持按值和一级指针传递
+                    if field_type.type_kind == TypeKind::PodStruct && field_type.ptr_level > 1 {
+                        field_type.type_kind = TypeKind::Ignored;
                     }
                 }
             }
@@ -1221,6 +2623,201 @@ impl FieldType {
         return field_type;
     }
 
+    /// typedef / using 别名（及其指针、引用）按底层类型映射，full_str 保留别名的写法，
+    /// 生成的 C++ 代码中可以继续使用别名。系统头文件中的别名（int64_t、std::string 等）仍按名字识别
+    fn from_clang_alias_type(clang_type: clang::Type) -> Option<Self> {
+        let is_pointer = clang_type.get_kind() == clang::TypeKind::Pointer;
+        let is_reference = matches!(clang_type.get_kind(), clang::TypeKind::LValueReference | clang::TypeKind::RValueReference);
+        let alias_type = if is_pointer || is_reference {
+            clang_type.get_pointee_type()?
+        } else {
+            clang_type
+        };
+
+        let declaration = alias_type.get_declaration()?;
+        match declaration.get_kind() {
+            clang::EntityKind::TypedefDecl | clang::EntityKind::TypeAliasDecl => {},
+            _ => return None,
+        }
+        if declaration.is_in_system_header() {
+            return None;
+        }
+        let underlying_type = declaration.get_typedef_underlying_type()?;
+
+        let mut field_type = FieldType::from_clang_type(&Some(underlying_type));
+        field_type.full_str = clang_type.get_display_name();
+        let alias_name = declaration.get_name().unwrap_or_default();
+        let qualified_alias_name = get_nested_type_name(&declaration).unwrap_or(alias_name.clone());
+        if qualified_alias_name != alias_name && !field_type.full_str.contains(&qualified_alias_name) {
+            field_type.full_str = replace_type_token(&field_type.full_str, &alias_name, &qualified_alias_name);
+        }
+        if is_pointer {
+            // 指向别名的指针，别名只描述被指向的类型
+            field_type.ptr_level += 1;
+        } else {
+            field_type.alias_str = Some(qualified_alias_name);
+        }
+
+        Some(field_type)
+    }
+
+    /// std::chrono 的 duration 返回 StdDuration，system_clock 的 time_point 返回 StdTimePoint，
+    /// 其他时钟的 time_point 没有固定的纪元，返回 Ignored
+    fn get_chrono_type_kind(clang_type: &clang::Type) -> Option<TypeKind> {
+        let canonical_type = clang_type.get_canonical_type();
+        let canonical_type = canonical_type.get_pointee_type().unwrap_or(canonical_type);
+        // 去掉 libc++ 的 __1、libstdc++ 的 _V2 内联命名空间
+        let canonical_str = canonical_type.get_display_name()
+            .replace("const ", "")
+            .replace("__1::", "")
+            .replace("_V2::", "");
+        if canonical_str.starts_with("std::chrono::duration<") {
+            return Some(TypeKind::StdDuration);
+        }
+        if canonical_str.starts_with("std::chrono::time_point<") {
+            if canonical_str.starts_with("std::chrono::time_point<std::chrono::system_clock,") {
+                return Some(TypeKind::StdTimePoint);
+            }
+            return Some(TypeKind::Ignored);
+        }
+        None
+    }
+
+    /// 是否为 std::span，按规范类型判断，支持别名和 using namespace std 后的 span<T>
+    fn is_clang_std_span(clang_type: &clang::Type) -> bool {
+        let canonical_type = clang_type.get_canonical_type();
+        let canonical_type = canonical_type.get_pointee_type().unwrap_or(canonical_type);
+        // 去掉 libc++ 的 __1 内联命名空间
+        let canonical_str = canonical_type.get_display_name()
+            .replace("const ", "")
+            .replace("__1::", "");
+        canonical_str.starts_with("std::span<")
+    }
+
+    /// 记录 const 和引用修饰，解析类型时会去掉这些修饰
+    fn fill_qualifiers(&mut self, clang_type: &clang::Type) {
+        let kind = clang_type.get_kind();
+        self.is_reference = matches!(kind, clang::TypeKind::LValueReference | clang::TypeKind::RValueReference);
+        let qualified_type = if self.is_reference || kind == clang::TypeKind::Pointer {
+            clang_type.get_pointee_type().unwrap_or(*clang_type)
+        } else {
+            *clang_type
+        };
+        self.is_const = qualified_type.is_const_qualified();
+    }
+
+    /// C 数组的长度，只支持元素为基础数据类型的一维数组，其它数组仍然作为指针处理
+    fn fill_array_len(&mut self, clang_type: &clang::Type) {
+        if clang_type.get_kind() != clang::TypeKind::ConstantArray {
+            return;
+        }
+        let is_nested_array = clang_type.get_element_type()
+            .is_some_and(|element_type| element_type.get_kind() == clang::TypeKind::ConstantArray);
+        if !is_nested_array && self.is_supported_array_element_type() {
+            self.array_len = clang_type.get_size();
+        }
+    }
+
+    /// 定长数组的元素是否为基础数据类型，dart 中可以转换为 List
+    fn is_supported_array_element_type(&self) -> bool {
+        self.ptr_level == 1 && (self.type_kind.is_integer() || matches!(self.type_kind,
+            TypeKind::Float | TypeKind::Double | TypeKind::Bool | TypeKind::Char))
+    }
+
+    /// 是否为不拥有内存的字符串（std::string_view、const char*）
+    pub fn is_borrowed_string(&self) -> bool {
+        self.type_kind == TypeKind::String && self.string_kind != StringKind::StdString
+    }
+
+    /// 容器元素是否可以批量复制，基础数据类型使用连续的内存，std::string 使用连续的 utf8 和每个字符串的长度
+    pub fn is_bulk_element(&self) -> bool {
+        if self.ptr_level != 0 {
+            return false;
+        }
+        self.type_kind.is_typed_data_element() || (self.type_kind == TypeKind::String && !self.is_borrowed_string())
+    }
+
+    /// 方法参数中的容器是否可以直接传递 dart 的 List、Set、Map，调用时批量复制为 native 容器
+    /// 非 const 的左值引用可能用于返回结果，仍然使用容器类
+    pub fn is_dart_collection_param(&self) -> bool {
+        if self.ptr_level != 0 {
+            return false;
+        }
+        if !matches!(self.type_kind,
+            TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque |
+            TypeKind::StdSet | TypeKind::StdUnorderedSet |
+            TypeKind::StdMap | TypeKind::StdUnorderedMap) {
+            return false;
+        }
+        if self.full_str.contains('&') && !self.full_str.contains("&&") && !self.full_str.starts_with("const ") {
+            return false;
+        }
+        let is_key_bulk = self.key_type.as_deref().is_none_or(FieldType::is_bulk_element);
+        let is_value_bulk = self.value_type.as_deref().is_some_and(FieldType::is_bulk_element);
+        is_key_bulk && is_value_bulk
+    }
+
+    /// 是否为 std::span，c++ 中需要用指针和长度构造
+    pub fn is_std_span(&self) -> bool {
+        self.is_std_span && self.ptr_level == 1 && self.type_kind != TypeKind::Ignored
+    }
+
+    /// 是否为 std::array，c++ 中需要通过 data() 访问元素
+    pub fn is_std_array(&self) -> bool {
+        self.is_std_array && self.array_len.is_some()
+    }
+
+    /// 返回值是否引用已有的对象（T&），这时生成的 dart 对象不拥有 native 对象
+    pub fn is_borrowed_reference(&self) -> bool {
+        self.is_reference && 0 == self.ptr_level
+    }
+
+    /// 是否为 const 引用（const T&），dart 中使用只读视图
+    pub fn is_const_reference(&self) -> bool {
+        self.is_const && self.is_borrowed_reference()
+    }
+
+    /// 嵌套类型在外层类内部被引用时，clang 给出的类型名可能不带外层类名（如 Request），
+    /// 生成的代码在类外部使用，需要补全为 Http::Request
+    fn qualify_nested_type(&mut self, clang_type: &clang::Type) {
+        let Some(declaration) = clang_type.get_declaration() else { return; };
+        let Some(qualified_name) = get_nested_type_name(&declaration) else { return; };
+        if !self.full_str.contains(&qualified_name) {
+            let short_name = declaration.get_name().unwrap_or_default();
+            self.full_str = replace_type_token(&self.full_str, &short_name, &qualified_name);
+        }
+        self.type_str = qualified_name;
+    }
+
+    /// 是否为 dart 中使用 native 对象包装的值类型（类、智能指针、容器等），c 中以指针传递
+    pub fn is_native_object(&self) -> bool {
+        0 == self.ptr_level && matches!(self.type_kind,
+            TypeKind::Class | TypeKind::StdPtr | TypeKind::StdUniquePtr | TypeKind::StdWeakPtr |
+            TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque |
+            TypeKind::StdMap | TypeKind::StdMultimap | TypeKind::StdUnorderedMap |
+            TypeKind::StdSet | TypeKind::StdUnorderedSet |
+            TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple)
+    }
+
+    /// 是否为非 const 的左值引用（T&），可能用于返回结果
+    pub fn is_mutable_reference(&self) -> bool {
+        self.is_reference && !self.full_str.starts_with("const ") && !self.full_str.contains("&&")
+    }
+
+    /// 是否为 const std::unique_ptr<T>& 参数，只是借用，所有权仍属于 dart 对象
+    pub fn is_borrowed_unique_ptr(&self) -> bool {
+        self.type_kind == TypeKind::StdUniquePtr && self.is_reference && 0 == self.ptr_level && self.full_str.starts_with("const ")
+    }
+
+    /// 字段、变量的 getter 的返回值类型，std::unique_ptr 的 getter 只是借用，不转移所有权
+    pub fn get_type_for_getter(&self) -> FieldType {
+        let mut getter_type = self.clone();
+        if getter_type.type_kind == TypeKind::StdUniquePtr {
+            getter_type.is_reference = true;
+        }
+        getter_type
+    }
+
     pub fn new_void() -> Self {
         return FieldType {
             full_str: "void".to_string(),
@@ -1229,15 +2826,71 @@ impl FieldType {
         };
     }
 
+    /// size_t，buffer、字符串的长度参数
+    pub fn new_size_t() -> Self {
+        FieldType {
+            full_str: "size_t".to_string(),
+            type_str: "uint64_t".to_string(),
+            type_kind: TypeKind::UInt64,
+            ptr_level: 0,
+            ..Default::default()
+        }
+    }
+
     pub fn get_value_type_str(&self) -> String {
         if self.value_type.is_none() {
             return "".to_string();
         }
-        let value_type = self.value_type.as_ref().unwrap();
-        if value_type.type_kind == TypeKind::StdPtr {
-            return format!("StdPtr_{}", value_type.type_str);
-        } else {
-            return value_type.type_str.clone();
+        self.value_type.as_ref().unwrap().get_type_name_without_ptr()
+    }
+
+    /// std::function 对应的类名，由返回值和参数的类型名组成
+    /// 如 std::function<void(int, std::string)> -> StdFunction_void_int_String
+    pub fn get_function_type_str(&self) -> String {
+        let return_type = self.value_type.as_deref().cloned().unwrap_or_else(FieldType::new_void);
+        let type_names: Vec<String> = std::iter::once(&return_type).chain(self.param_types.iter())
+            .map(FieldType::get_type_str_for_naming).collect();
+        format!("StdFunction_{}", type_names.join("_"))
+    }
+
+    /// std::variant 对应的类名，由备选类型的类型名组成
+    /// 如 std::variant<int, std::string> -> StdVariant_int_String
+    pub fn get_variant_type_str(&self) -> String {
+        let type_names: Vec<String> = self.param_types.iter().map(FieldType::get_type_str_for_naming).collect();
+        format!("StdVariant_{}", type_names.join("_"))
+    }
+
+    /// std::pair、std::tuple 对应的类名，由元素的类型名组成
+    /// 如 std::pair<int, std::string> -> StdPair_int_String
+    pub fn get_tuple_type_str(&self) -> String {
+        let type_names: Vec<String> = self.param_types.iter().map(FieldType::get_type_str_for_naming).collect();
+        let prefix = if self.type_kind == TypeKind::StdPair { "StdPair" } else { "StdTuple" };
+        format!("{}_{}", prefix, type_names.join("_"))
+    }
+
+    /// 组成 std::function、std::variant、std::pair、std::tuple 类名时使用的类型名
+    fn get_type_str_for_naming(&self) -> String {
+        format!("{}{}", self.get_type_name_without_ptr(), "Ptr".repeat(self.ptr_level as usize))
+    }
+
+    /// 组成容器类名时使用的类型名，容器嵌套时递归组成，
+    /// 如 std::vector<std::vector<float>> -> StdVector_StdVector_float，
+    /// std::map<std::string, std::vector<Item>> -> StdMap_String_StdVector_Item
+    pub fn get_type_name_without_ptr(&self) -> String {
+        match self.type_kind {
+            TypeKind::StdPtr => format!("StdPtr_{}", self.type_str),
+            TypeKind::StdVector => format!("StdVector_{}", self.get_value_type_str()),
+            TypeKind::StdList => format!("StdList_{}", self.get_value_type_str()),
+            TypeKind::StdDeque => format!("StdDeque_{}", self.get_value_type_str()),
+            TypeKind::StdSet => format!("StdSet_{}", self.get_value_type_str()),
+            TypeKind::StdUnorderedSet => format!("StdUnorderedSet_{}", self.get_value_type_str()),
+            TypeKind::StdOptional => format!("StdOptional_{}", self.get_value_type_str()),
+            TypeKind::StdMap => format!("StdMap_{}_{}", self.get_key_type_str(), self.get_value_type_str()),
+            TypeKind::StdMultimap => format!("StdMultimap_{}_{}", self.get_key_type_str(), self.get_value_type_str()),
+            TypeKind::StdUnorderedMap => format!("StdUnorderedMap_{}_{}", self.get_key_type_str(), self.get_value_type_str()),
+            TypeKind::StdVariant => self.get_variant_type_str(),
+            TypeKind::StdPair | TypeKind::StdTuple => self.get_tuple_type_str(),
+            _ => self.type_str.clone(),
         }
     }
 
@@ -1245,11 +2898,6 @@ impl FieldType {
         if self.key_type.is_none() {
             return "".to_string();
         }
-        let key_type = self.key_type.as_ref().unwrap();
-        if key_type.type_kind == TypeKind::StdPtr {
-            return format!("StdPtr_{}", key_type.type_str);
-        } else {
-            return key_type.type_str.clone();
-        }
+        self.key_type.as_ref().unwrap().get_type_name_without_ptr()
     }
 }
@@ -20,27 +20,95 @@ struct DartGenContext<'a> {
 fn gen_dart_public<'a>(gen_context: &GenContext, gen_out_dir: &str) {
     let public_file_name = format!("{}_public.dart", gen_context.module_name);
     let public_file_path = PathBuf::new().join(gen_out_dir).join(public_file_name.clone()).into_os_string().into_string().unwrap();
-    let public_file_str = format!("
-import 'dart:ffi';
-import 'dart:io';
-import 'package:ffi/ffi.dart';
-
-late final DynamicLibrary {}_dylib;
+    let dylib_str = match gen_context.dart_native_asset {
+        // @Native 注解绑定时不需要 dylib
+        Some(_) => "".to_string(),
+        None => format!("late final DynamicLibrary {}_dylib;
 void {}_setDylib(DynamicLibrary dylib) {{
     {}_dylib = dylib;
     return;
 }}
+",
+            gen_context.module_name,
+            gen_context.module_name,
+            gen_context.module_name,
+        ),
+    };
+    let public_file_str = format!("{}
+import 'dart:ffi';
+import 'dart:io';
+import 'package:ffi/ffi.dart';
 
-late final ptr_ffi_Dart_InitializeApiDL = {}_dylib.lookup<NativeFunction<Int64 Function(Pointer<Void>)>>('Dart_InitializeApiDL');
-late final ffi_Dart_InitializeApiDL = ptr_ffi_Dart_InitializeApiDL.asFunction<int Function(Pointer<Void>)>();
-    ", gen_context.module_name,
-    gen_context.module_name,
-    gen_context.module_name,
-    gen_context.module_name,
+{}
+{}    ", get_str_dart_default_asset(gen_context),
+    dylib_str,
+    get_str_dart_ffi_binding(gen_context, "ffi_Dart_InitializeApiDL", "Dart_InitializeApiDL", "Int64 Function(Pointer<Void>)", "int", "Pointer<Void>", false),
     );
 
     let mut public_file = fs::File::create(public_file_path).unwrap();
-    public_file.write_all(public_file_str.as_bytes());
+    public_file.write_all(public_file_str.as_bytes()).unwrap();
+    public_file.write_all(get_str_dart_native_iterable().as_bytes()).unwrap();
+}
+
+/// 容器类遍历使用的 Iterable，通过容器的 ffi 迭代器方法遍历
+/// 遍历到末尾时释放迭代器，提前结束遍历时由 Finalizer 释放
+fn get_str_dart_native_iterable() -> String {
+    r#"
+/// c++ 容器的 Iterable，每次遍历创建一个 native 迭代器，遍历过程中不能修改容器
+class NativeIterable<E> extends Iterable<E> {
+    final int Function() _begin;
+    final bool Function(int) _valid;
+    final void Function(int) _next;
+    final E Function(int) _current;
+    final void Function(int) _end;
+
+    NativeIterable(this._begin, this._valid, this._next, this._current, this._end);
+
+    @override
+    Iterator<E> get iterator => _NativeIterator<E>(this);
+}
+
+class _NativeIterator<E> implements Iterator<E> {
+    static final _finalizer = Finalizer<void Function()>((release) => release());
+
+    final NativeIterable<E> _iterable;
+    int? _it;
+    bool _isStarted = false;
+    E? _current;
+
+    _NativeIterator(this._iterable);
+
+    @override
+    E get current => _current as E;
+
+    @override
+    bool moveNext() {
+        if (!_isStarted) {
+            _isStarted = true;
+            final it = _iterable._begin();
+            _it = it;
+            final end = _iterable._end;
+            _finalizer.attach(this, () => end(it), detach: this);
+        } else if (_it != null) {
+            _iterable._next(_it!);
+        }
+
+        final it = _it;
+        if (it == null) {
+            return false;
+        }
+        if (!_iterable._valid(it)) {
+            _finalizer.detach(this);
+            _iterable._end(it);
+            _it = null;
+            _current = null;
+            return false;
+        }
+        _current = _iterable._current(it);
+        return true;
+    }
+}
+"#.to_string()
 }
 
 fn gen_dart_fun<'a>(gen_context: &GenContext, hpp_element: &'a HppElement, gen_out_dir: &str, dart_gen_context: Option<&mut DartGenContext<'a>>) {
@@ -60,7 +128,7 @@ fn gen_dart_fun<'a>(gen_context: &GenContext, hpp_element: &'a HppElement, gen_o
 
             // 收集当前文件中所有引用的外部类型
             let mut referenced_types = Vec::new();
-            collect_referenced_types_from_file(file, &mut referenced_types);
+            collect_referenced_types_from_file(gen_context, file, &mut referenced_types);
             
             // 生成导入语句，使用 HashSet 去重
             let mut import_set = std::collections::HashSet::new();
@@ -83,11 +151,16 @@ fn gen_dart_fun<'a>(gen_context: &GenContext, hpp_element: &'a HppElement, gen_o
             // 公共头
             let file_header = format!("
 import '{}';
+import '{}_public.dart';
 import 'dart:ffi';
+import 'dart:convert';
+import 'dart:typed_data';
 import 'package:ffi/ffi.dart';
 import 'dart:isolate';
-{}            \n", dart_ffiapi_filename, import_statements);
-            dart_file.write(file_header.as_bytes());
+{}            \n", dart_ffiapi_filename, gen_context.module_name, import_statements);
+            // typedef / using 别名，生成对应的 dart typedef
+            let file_header = format!("{}{}", file_header, get_str_dart_typedefs(gen_context, file));
+            dart_file.write_all(file_header.as_bytes()).unwrap();
 
             dart_gen_context.cur_file = Some(dart_file);
             for hpp_element in &file.children {
@@ -97,41 +170,92 @@ import 'dart:isolate';
         HppElement::Class(class) => {
             let local_dart_gen_context = dart_gen_context.unwrap();
 
+            // std::function 不是 native 对象，只保存 dart 闭包
+            if class.class_type == ClassType::StdFunction {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                dart_file.write_all(get_str_dart_stdfunction_class(gen_context, class).as_bytes()).unwrap();
+                return;
+            }
+            // POD 结构体按值传递，不是 native 对象
+            if class.class_type == ClassType::PodStruct {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                dart_file.write_all(get_str_dart_pod_struct(gen_context, class).as_bytes()).unwrap();
+                if gen_context.dart_data_class {
+                    dart_file.write_all(get_str_dart_data_class(gen_context, class).as_bytes()).unwrap();
+                }
+                return;
+            }
+
+            // 普通类和回调类生成只读视图，const 引用使用
+            let (class_view, class_view_implements) = match class.class_type {
+                ClassType::Normal | ClassType::Callback => {
+                    (get_str_dart_class_view(gen_context, class), format!(", {}View", class.get_dart_name(&gen_context.dart_naming)))
+                }
+                _ => ("".to_string(), "".to_string()),
+            };
+
             // 公共头
             let dart_file_header = local_dart_gen_context.cur_file.as_mut().unwrap();
-            let mut class_header = format!("
+            let mut class_header = format!("{}
 {}
-class {} implements Finalizable {{
-    late Pointer<Void> _nativePtr;
+class {} implements Finalizable{} {{
+    Pointer<Void>? _nativePtrOrNull;
+    Pointer<Void> get _nativePtr {{
+        final nativePtr = _nativePtrOrNull;
+        if (nativePtr == null) {{
+            throw StateError('native对象的所有权已经转移给c++，dart对象不再可用');
+        }}
+        return nativePtr;
+    }}
+    set _nativePtr(Pointer<Void> nativePtr) {{
+        _nativePtrOrNull = nativePtr;
+    }}
     Pointer<Void> getNativePtr() {{
         return _nativePtr;
     }}
     static final _finalizer = NativeFinalizer(ptr_ffi_{}_Destructor);
+    bool _isNativeOwner = false;
 
     /**
      * dart对象释放时，释放native对象，默认行为
      */
     void nativeLifecycleLink() {{
         _finalizer.attach(this, _nativePtr, detach: this);
+        _isNativeOwner = true;
     }}
     /**
      * dart对象释放时，不释放native对象
      */
     void nativeLifecycleUnlink() {{
         _finalizer.detach(this);
+        _isNativeOwner = false;
+    }}
+    /**
+     * native对象的所有权转移给c++（如 std::unique_ptr 参数），之后dart对象不再可用
+     * 只有拥有native对象的dart对象可以转移，借用的对象（如引用返回值）转移后会被释放两次
+     */
+    Pointer<Void> nativeRelease() {{
+        if (!_isNativeOwner) {{
+            throw StateError('dart对象不拥有native对象，不能转移所有权');
+        }}
+        final nativePtr = _nativePtr;
+        nativeLifecycleUnlink();
+        _nativePtrOrNull = null;
+        return nativePtr;
     }}
 ", 
+            class_view,
             class.comment_str.as_ref().unwrap_or(&"".to_string()),
-            class.type_str, class.type_str);
+            class.get_dart_name(&gen_context.dart_naming), class_view_implements, class.get_ffi_name());
             class_header.push_str(&format!("
-    {}.FromNative(Pointer<Void> nativePtr) : _nativePtr = nativePtr {{}}
-            \n", class.type_str));
-            dart_file_header.write(class_header.as_bytes());
+    {}.FromNative(Pointer<Void> nativePtr) : _nativePtrOrNull = nativePtr {{}}
+            \n", class.get_dart_name(&gen_context.dart_naming)));
+            dart_file_header.write_all(class_header.as_bytes()).unwrap();
 
             // 回调类的特殊内容
             if class.is_callback() {
-                let callback_header = format!("    static Map<Pointer<Void>, WeakReference<{}>> nativeToObjMap = {{}};\n\n", class.type_str);
-                dart_file_header.write(callback_header.as_bytes());
+                let callback_header = format!("    static Map<Pointer<Void>, WeakReference<{}>> nativeToObjMap = {{}};\n\n", class.get_dart_name(&gen_context.dart_naming));
+                dart_file_header.write_all(callback_header.as_bytes()).unwrap();
             }
             
             local_dart_gen_context.cur_class = Some(class);
@@ -140,23 +264,74 @@ class {} implements Finalizable {{
             }
             local_dart_gen_context.cur_class = None;
 
-            // 为StdMap、StdUnorderedMap和StdSet类添加便利方法
-            if class.class_type == ClassType::StdMap {
+            // 特化了 std::hash 的类，使用 c++ 的 hash 作为 hashCode
+            if class.has_std_hash {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let hash_code = format!("    @override
+    int get hashCode => ffi_{}_hashCode(_nativePtr);
+
+", class.get_ffi_name());
+                dart_file.write_all(hash_code.as_bytes()).unwrap();
+            } else if class.children.iter().any(|element| matches!(element, HppElement::Method(method) if method.operator_str.as_deref() == Some("=="))) {
+                // 重写了 == 但没有特化 std::hash，无法按值计算 hash，所有对象使用相同的 hashCode，保证相等的对象 hashCode 相同
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                dart_file.write_all("    @override
+    int get hashCode => 0;
+
+".as_bytes()).unwrap();
+            }
+
+            // 容器类通过迭代器遍历，转换为 dart 的集合
+            if class.is_container() {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let iteration_methods = generate_container_iteration_methods(gen_context, class);
+                dart_file.write_all(iteration_methods.as_bytes()).unwrap();
+            }
+
+            // vector、list、deque 从 dart 的 List 创建
+            if matches!(class.class_type, ClassType::StdVector | ClassType::StdList | ClassType::StdDeque) {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let from_list_method = generate_stdsequence_from_list_method(gen_context, class);
+                dart_file.write_all(from_list_method.as_bytes()).unwrap();
+            }
+
+            // 为StdVector、StdMap、StdUnorderedMap和StdSet类添加便利方法
+            if class.class_type == ClassType::StdVector {
                 let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
-                let convenience_methods = generate_stdmap_convenience_methods(class);
-                dart_file.write(convenience_methods.as_bytes());
+                let convenience_methods = generate_stdvector_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
+            } else if class.class_type == ClassType::StdMap {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let convenience_methods = generate_stdmap_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
+            } else if class.class_type == ClassType::StdMultimap {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let convenience_methods = generate_stdmultimap_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
             } else if class.class_type == ClassType::StdUnorderedMap {
                 let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
-                let convenience_methods = generate_stdunorderedmap_convenience_methods(class);
-                dart_file.write(convenience_methods.as_bytes());
+                let convenience_methods = generate_stdunorderedmap_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
             } else if class.class_type == ClassType::StdSet {
                 let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
-                let convenience_methods = generate_stdset_convenience_methods(class);
-                dart_file.write(convenience_methods.as_bytes());
+                let convenience_methods = generate_stdset_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
             } else if class.class_type == ClassType::StdUnorderedSet {
                 let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
-                let convenience_methods = generate_stdunorderedset_convenience_methods(class);
-                dart_file.write(convenience_methods.as_bytes());
+                let convenience_methods = generate_stdunorderedset_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
+            } else if class.class_type == ClassType::StdOptional {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let convenience_methods = generate_stdoptional_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
+            } else if class.class_type == ClassType::StdVariant {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let convenience_methods = generate_stdvariant_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
+            } else if class.class_type == ClassType::StdTuple {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let convenience_methods = generate_stdtuple_convenience_methods(gen_context, class);
+                dart_file.write_all(convenience_methods.as_bytes()).unwrap();
             }
 
             // 回调类的静态包装函数（必须在类结束之前生成）
@@ -173,15 +348,28 @@ class {} implements Finalizable {{
             // 公共尾
             let dart_file_footer = local_dart_gen_context.cur_file.as_mut().unwrap();
             let class_footer = format!("}}\n\n");
-            dart_file_footer.write(class_footer.as_bytes());
+            dart_file_footer.write_all(class_footer.as_bytes()).unwrap();
             }
 
             // 回调类的 init 函数（在类结束之后生成）
             if class.is_callback() {
                 // 用于注册dart函数实现的函数
-                let callback_footer = format!("\nvoid _{}_init() {{\n{}\n}}\n", class.type_str, init_str);
+                let callback_footer = format!("\nvoid _{}_init() {{\n{}\n}}\n", class.get_ffi_name(), init_str);
                 let dart_file_footer = local_dart_gen_context.cur_file.as_mut().unwrap();
-                dart_file_footer.write(callback_footer.as_bytes());
+                dart_file_footer.write_all(callback_footer.as_bytes()).unwrap();
+            }
+
+            // std::variant 在 dart 中使用的 sealed class
+            if class.class_type == ClassType::StdVariant {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                let sealed_classes = get_str_dart_stdvariant_sealed_classes(gen_context, class);
+                dart_file.write_all(sealed_classes.as_bytes()).unwrap();
+            }
+
+            // 不可变的数据类
+            if gen_context.dart_data_class {
+                let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+                dart_file.write_all(get_str_dart_data_class(gen_context, class).as_bytes()).unwrap();
             }
         }
         HppElement::Method(method) => {
@@ -190,46 +378,51 @@ class {} implements Finalizable {{
 
             // 跳过无类名的方法（通常来自第三方库的模板实例化）
             // 这些方法的 FFI 名称会是 ffi__method_name（注意双下划线）
-            let mut cur_class_name = "";
-            if let Some(cur_class) = local_dart_gen_context.cur_class {
-                cur_class_name = &cur_class.type_str;
-            }
+            let cur_class_name = local_dart_gen_context.cur_class.get_ffi_class_name_or_empty();
             let ffi_name = format!("ffi_{}_{}", cur_class_name, method.name);
             if ffi_name.starts_with("ffi__") {
                 return;
             }
 
-            let method_impl = get_str_dart_fun(local_dart_gen_context.cur_class, method);
-            dart_file.write(method_impl.as_bytes());
+            let method_impl = get_str_dart_fun(gen_context, local_dart_gen_context.cur_class, method);
+            dart_file.write_all(method_impl.as_bytes()).unwrap();
         }
         HppElement::Field(field) => {
             let local_dart_gen_context = dart_gen_context.unwrap();
             let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
 
             // 跳过无类名的字段 getter/setter（通常来自第三方库）
-            let mut cur_class_name = "";
-            if let Some(cur_class) = local_dart_gen_context.cur_class {
-                cur_class_name = &cur_class.type_str;
-            }
+            let cur_class_name = local_dart_gen_context.cur_class.get_ffi_class_name_or_empty();
             let ffi_get_name = format!("ffi_{}_get_{}", cur_class_name, field.name);
             if ffi_get_name.starts_with("ffi__") {
                 return;
             }
 
-            // get
-            let get_method = Method::new_get_for_field(field);
-            let get_method_str = get_str_dart_fun(local_dart_gen_context.cur_class, &get_method);
-            // set
-            let set_method = Method::new_set_for_field(field);
-            let set_method_str = get_str_dart_fun(local_dart_gen_context.cur_class, &set_method);
-            dart_file.write(format!("{}\n{}\n", get_method_str, set_method_str).as_bytes());
+            let field_str = get_str_dart_field(gen_context, local_dart_gen_context.cur_class, field);
+            dart_file.write_all(field_str.as_bytes()).unwrap();
         }
         HppElement::Enum(enum_def) => {
             let local_dart_gen_context = dart_gen_context.unwrap();
             let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
 
-            let enum_code = gen_dart_enum(enum_def);
-            dart_file.write(enum_code.as_bytes());
+            let enum_code = gen_dart_enum(gen_context, enum_def);
+            dart_file.write_all(enum_code.as_bytes()).unwrap();
+        }
+        HppElement::Constant(constant) => {
+            let local_dart_gen_context = dart_gen_context.unwrap();
+            let cur_class = local_dart_gen_context.cur_class;
+            let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+
+            let constant_code = get_str_dart_constant(gen_context, cur_class, constant);
+            dart_file.write_all(constant_code.as_bytes()).unwrap();
+        }
+        HppElement::Variable(variable) => {
+            let local_dart_gen_context = dart_gen_context.unwrap();
+            let cur_class = local_dart_gen_context.cur_class;
+            let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
+
+            let variable_code = get_str_dart_variable(gen_context, cur_class, variable);
+            dart_file.write_all(variable_code.as_bytes()).unwrap();
         }
         _ => {
             unimplemented!("gen_dart_api: unknown child");
@@ -253,13 +446,13 @@ fn gen_dart_api<'a>(gen_context: &GenContext, hpp_element: &'a HppElement, gen_o
 
             let public_file_name = format!("{}_public.dart", gen_context.module_name);
             // 公共头
-            let file_header = format!("
+            let file_header = format!("{}
 import 'dart:ffi';
 import 'dart:io';
 import 'package:ffi/ffi.dart';
 import '{}';
-            \n", public_file_name);
-            ffiapi_file.write(file_header.as_bytes());
+{}            \n", get_str_dart_default_asset(gen_context), public_file_name, get_str_dart_pod_struct_imports(gen_context, file));
+            ffiapi_file.write_all(file_header.as_bytes()).unwrap();
 
             ffiapi_gen_context.cur_file = Some(ffiapi_file);
             for hpp_element in &file.children {
@@ -269,6 +462,17 @@ import '{}';
         HppElement::Class(class) => {
             let local_ffiapi_gen_context = ffiapi_gen_context.unwrap();
 
+            // std::function 只需要注册 dart 闭包调用、释放的接口
+            if class.class_type == ClassType::StdFunction {
+                let ffiapi_file = local_ffiapi_gen_context.cur_file.as_mut().unwrap();
+                ffiapi_file.write_all(get_str_dart_api_for_stdfunction(gen_context, class).as_bytes()).unwrap();
+                return;
+            }
+            // POD 结构体没有 ffi 函数
+            if class.class_type == ClassType::PodStruct {
+                return;
+            }
+
             local_ffiapi_gen_context.cur_class = Some(class);
 
             // 如果是回调类且有同步回调（有返回值的方法），生成 setCallbackResult FFI 绑定
@@ -283,12 +487,9 @@ import '{}';
 
                 if has_sync_callback {
                     let ffiapi_file = local_ffiapi_gen_context.cur_file.as_mut().unwrap();
-                    let set_result_api = format!("late final ptr_ffi_FFI_{}_setCallbackResult = {}_dylib.lookup<NativeFunction<Void Function(Int64, Int64)>>('FFI_{}_setCallbackResult');
-late final ffi_FFI_{}_setCallbackResult = ptr_ffi_FFI_{}_setCallbackResult.asFunction<void Function(int, int)>();
-",
-                        class.type_str, gen_context.module_name, class.type_str,
-                        class.type_str, class.type_str);
-                    ffiapi_file.write(set_result_api.as_bytes());
+                    let set_result_fun_name = format!("FFI_{}_setCallbackResult", class.get_ffi_name());
+                    let set_result_api = get_str_dart_ffi_binding(gen_context, &format!("ffi_{}", set_result_fun_name), &set_result_fun_name, "Void Function(Int64, Int64)", "void", "int, int", false);
+                    ffiapi_file.write_all(set_result_api.as_bytes()).unwrap();
                 }
             }
 
@@ -296,6 +497,35 @@ late final ffi_FFI_{}_setCallbackResult = ptr_ffi_FFI_{}_setCallbackResult.asFun
                 gen_dart_api(gen_context, hpp_element, gen_out_dir, Some(local_ffiapi_gen_context));
             }
             local_ffiapi_gen_context.cur_class = None;
+
+            if class.has_std_hash {
+                let ffiapi_file = local_ffiapi_gen_context.cur_file.as_mut().unwrap();
+                let hash_fun_name = format!("ffi_{}_hashCode", class.get_ffi_name());
+                let hash_api = format!("{}\n", get_str_dart_ffi_binding(gen_context, &hash_fun_name, &hash_fun_name, "Int64 Function(Pointer<Void>)", "int", "Pointer<Void>", false));
+                ffiapi_file.write_all(hash_api.as_bytes()).unwrap();
+            }
+
+            // 容器类从连续的内存批量构造
+            let bulk_element_types = class.get_bulk_element_types();
+            if !bulk_element_types.is_empty() {
+                let ffiapi_file = local_ffiapi_gen_context.cur_file.as_mut().unwrap();
+                let mut native_param_strs = vec![];
+                let mut dart_param_strs = vec![];
+                for (_, element_type) in &bulk_element_types {
+                    if element_type.type_kind == TypeKind::String {
+                        native_param_strs.push("Pointer<Utf8>, Pointer<Uint64>".to_string());
+                        dart_param_strs.push("Pointer<Utf8>, Pointer<Uint64>".to_string());
+                    } else {
+                        native_param_strs.push(format!("Pointer<{}>", get_str_native_api_type(gen_context, element_type)));
+                        dart_param_strs.push(format!("Pointer<{}>", get_str_native_api_type(gen_context, element_type)));
+                    }
+                }
+                let bulk_fun_name = format!("ffi_{}_Constructor_from_buffer", class.get_ffi_name());
+                let bulk_api = format!("{}\n", get_str_dart_ffi_binding(gen_context, &bulk_fun_name, &bulk_fun_name,
+                    &format!("Pointer<Void> Function({}, Uint64)", native_param_strs.join(", ")),
+                    "Pointer<Void>", &format!("{}, int", dart_param_strs.join(", ")), false));
+                ffiapi_file.write_all(bulk_api.as_bytes()).unwrap();
+            }
         }
         HppElement::Method(method) => {
             let local_ffiapi_gen_context = ffiapi_gen_context.unwrap();
@@ -303,10 +533,7 @@ late final ffi_FFI_{}_setCallbackResult = ptr_ffi_FFI_{}_setCallbackResult.asFun
 
             // 跳过无类名的方法（通常来自第三方库的模板实例化）
             // 这些方法的 FFI 名称会是 ffi__method_name（注意双下划线）
-            let mut cur_class_name = "";
-            if let Some(cur_class) = local_ffiapi_gen_context.cur_class {
-                cur_class_name = &cur_class.type_str;
-            }
+            let cur_class_name = local_ffiapi_gen_context.cur_class.get_ffi_class_name_or_empty();
             let ffi_name = format!("ffi_{}_{}", cur_class_name, method.name);
             if ffi_name.starts_with("ffi__") {
                 return;
@@ -315,21 +542,18 @@ late final ffi_FFI_{}_setCallbackResult = ptr_ffi_FFI_{}_setCallbackResult.asFun
             if local_ffiapi_gen_context.cur_class.is_some() && local_ffiapi_gen_context.cur_class.unwrap().is_callback() {
                 // 对于回调类，需要特殊生成注册函数
                 let dart_api_str = get_str_dart_api_for_regist_callback(gen_context, local_ffiapi_gen_context.cur_class, method);
-                ffiapi_file.write(format!("{}", dart_api_str).as_bytes());
+                ffiapi_file.write_all(dart_api_str.as_bytes()).unwrap();
             }
 
             let dart_api_str = get_str_dart_api(gen_context, local_ffiapi_gen_context.cur_class, method);
-            ffiapi_file.write(format!("{}\n", dart_api_str).as_bytes());
+            ffiapi_file.write_all(format!("{}\n", dart_api_str).as_bytes()).unwrap();
         }
         HppElement::Field(field) => {
             let local_ffiapi_gen_context = ffiapi_gen_context.unwrap();
             let ffiapi_file = local_ffiapi_gen_context.cur_file.as_mut().unwrap();
 
             // 跳过无类名的字段 getter/setter（通常来自第三方库）
-            let mut cur_class_name = "";
-            if let Some(cur_class) = local_ffiapi_gen_context.cur_class {
-                cur_class_name = &cur_class.type_str;
-            }
+            let cur_class_name = local_ffiapi_gen_context.cur_class.get_ffi_class_name_or_empty();
             let ffi_get_name = format!("ffi_{}_get_{}", cur_class_name, field.name);
             if ffi_get_name.starts_with("ffi__") {
                 return;
@@ -338,15 +562,36 @@ late final ffi_FFI_{}_setCallbackResult = ptr_ffi_FFI_{}_setCallbackResult.asFun
             // get
             let get_method = Method::new_get_for_field(field);
             let get_method_str = get_str_dart_api(gen_context, local_ffiapi_gen_context.cur_class, &get_method);
-            // set
-            let set_method = Method::new_set_for_field(field);
-            let set_method_str = get_str_dart_api(gen_context, local_ffiapi_gen_context.cur_class, &set_method);
-            ffiapi_file.write(format!("{}\n{}\n", get_method_str, set_method_str).as_bytes());
+            ffiapi_file.write_all(format!("{}\n", get_method_str).as_bytes()).unwrap();
+            // set，string_view、const char* 字段只读
+            if field.has_setter() {
+                let set_method = Method::new_set_for_field(field);
+                let set_method_str = get_str_dart_api(gen_context, local_ffiapi_gen_context.cur_class, &set_method);
+                ffiapi_file.write_all(format!("{}\n", set_method_str).as_bytes()).unwrap();
+            }
         }
         HppElement::Enum(_enum_def) => {
             // Enum 不需要生成 FFI API，因为它们就是整数类型
             // 在 Dart 层已经通过 enum 类定义处理了类型转换
         }
+        HppElement::Constant(_constant) => {
+            // 常量直接生成 dart 的 const，不需要 FFI API
+        }
+        HppElement::Variable(variable) => {
+            let local_ffiapi_gen_context = ffiapi_gen_context.unwrap();
+            let ffiapi_file = local_ffiapi_gen_context.cur_file.as_mut().unwrap();
+
+            // get
+            let get_method = Method::new_get_for_variable(variable);
+            let get_method_str = get_str_dart_api(gen_context, local_ffiapi_gen_context.cur_class, &get_method);
+            ffiapi_file.write_all(format!("{}\n", get_method_str).as_bytes()).unwrap();
+            // set，const 变量、string_view、const char* 变量没有 setter
+            if variable.has_setter() {
+                let set_method = Method::new_set_for_variable(variable);
+                let set_method_str = get_str_dart_api(gen_context, local_ffiapi_gen_context.cur_class, &set_method);
+                ffiapi_file.write_all(format!("{}\n", set_method_str).as_bytes()).unwrap();
+            }
+        }
         _ => {
             unimplemented!("gen_dart_ffiapi: unknown child");
         }
@@ -360,9 +605,12 @@ fn gen_dart_fun_for_regist_callback<'a>(gen_context: &GenContext, hpp_element: &
             let local_dart_gen_context = dart_gen_context.unwrap();
             let dart_file = local_dart_gen_context.cur_file.as_mut().unwrap();
 
-            let (local_init_str, dart_fun_impl) = get_dart_fun_for_regist_callback(local_dart_gen_context.cur_class, method);
+            let (local_init_str, dart_fun_impl) = get_dart_fun_for_regist_callback(gen_context, local_dart_gen_context.cur_class, method);
             init_str.push_str(&local_init_str);
-            dart_file.write(dart_fun_impl.as_bytes());
+            dart_file.write_all(dart_fun_impl.as_bytes()).unwrap();
+        }
+        HppElement::Constant(_) | HppElement::Variable(_) => {
+            // 常量和静态变量已经在类中生成，回调注册不需要处理
         }
         _ => {
             unimplemented!("gen_dart_api_for_callback_fun: unknown child");
@@ -370,27 +618,36 @@ fn gen_dart_fun_for_regist_callback<'a>(gen_context: &GenContext, hpp_element: &
     }
 }
 
-fn get_str_dart_fun(class: Option<&Class>, method: &Method) -> String {
+fn get_str_dart_fun(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
     let (cur_class_name, class_is_callback) = if let Some(cur_class) = class {
-        (cur_class.type_str.as_str(), cur_class.is_callback())
+        (cur_class.get_dart_name(&gen_context.dart_naming), cur_class.is_callback())
     } else {
-        ("", false)
+        ("".to_string(), false)
     };
 
-    let callbck_block = get_str_dart_fun_callback_block(class, method);
-    let params_decl_str = get_str_dart_fun_params_decl(class, method);
+    let dart_method = get_dart_named_method(gen_context, class, method);
+    let method = &dart_method;
+    let class_is_callback = class_is_callback && !method.is_field_accessor;
+
+    // operator== 在 dart 中的参数必须是 Object
+    if method.operator_str.as_deref() == Some("==") {
+        return get_str_dart_fun_for_equal_operator(gen_context, class, method);
+    }
+
+    let callbck_block = get_str_dart_fun_callback_block(gen_context, class, method);
+    let params_decl_str = get_str_dart_fun_params_decl(gen_context, class, method);
     let fun_body = if class_is_callback {
-        get_str_dart_fun_body_for_callback(class, method)
+        get_str_dart_fun_body_for_callback(gen_context, class, method)
     } else {
-        get_str_dart_fun_body(class, method)
+        get_str_dart_fun_body(gen_context, class, method)
     };
 
     let mut fun_name = "".to_string();
     let static_modifier = if method.is_static { "static " } else { "" };
     match method.method_type {
         MethodType::Normal | MethodType::Destructor => {
-            fun_name.push_str(&format!("{}{} {}", static_modifier, get_str_dart_fun_type(&method.return_type), method.name));
+            fun_name.push_str(&format!("{}{} {}", static_modifier, get_str_dart_fun_return_type(gen_context, method), get_str_dart_fun_name(gen_context, class, method)));
         }
         MethodType::Constructor => {
             fun_name.push_str(&format!("{}.{}", cur_class_name, method.name));
@@ -414,44 +671,282 @@ fn get_str_dart_fun(class: Option<&Class>, method: &Method) -> String {
     return dart_fun_impl;
 }
 
-fn get_str_dart_fun_body(class: Option<&Class>, method: &Method) -> String {
+/// 是否为回调类中由 c++ 调用 dart 的方法，回调类的字段 getter / setter 和普通类一样直接调用 ffi
+fn is_dart_callback_method(class: Option<&Class>, method: &Method) -> bool {
+    class.is_some_and(|cur_class| cur_class.is_callback()) && !method.is_field_accessor
+}
+
+/// dart 中的函数名，重载操作符使用 dart 的 operator 声明，operator() 对应 call 方法
+fn get_str_dart_fun_name(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
+    match method.operator_str.as_deref() {
+        Some("()") => "call".to_string(),
+        Some(operator_str) => format!("operator {}", operator_str),
+        None if is_dart_naming_method(class, method) => get_dart_member_name(&gen_context.dart_naming, &method.name, gen_context.dart_naming.lower_camel_members),
+        None => method.name.clone(),
+    }
+}
+
+/// 命名规则只用于普通类的方法和全局函数，
+/// 回调类、容器等生成的类在生成的 dart 代码中按 c++ 中的名字互相调用，构造函数为命名构造函数，保持不变
+fn is_dart_naming_method(class: Option<&Class>, method: &Method) -> bool {
+    if method.method_type != MethodType::Normal {
+        return false;
+    }
+    class.is_none_or(|cur_class| cur_class.class_type == ClassType::Normal)
+}
+
+/// 按命名规则重命名参数后的方法，dart 中的参数名只在函数内部使用，ffi 函数名不受影响
+fn get_dart_named_method(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> Method {
+    let mut dart_method = method.clone();
+    if !is_dart_naming_method(class, method) {
+        return dart_method;
+    }
+    let lower_camel = gen_context.dart_naming.lower_camel_members;
+    for param in &mut dart_method.params {
+        param.name = get_dart_member_name(&gen_context.dart_naming, &param.name, lower_camel);
+        // buffer 指针参数通过名字关联长度参数
+        if let Some(len_param_name) = param.field_type.buffer_len_param.as_mut() {
+            *len_param_name = get_dart_member_name(&gen_context.dart_naming, len_param_name, lower_camel);
+        }
+    }
+    if let Some(ret_len_name) = dart_method.return_type.buffer_len_param.as_mut() {
+        *ret_len_name = get_dart_member_name(&gen_context.dart_naming, ret_len_name, lower_camel);
+    }
+    dart_method
+}
+
+/// 类的只读视图，const 引用（const T&）返回时使用，只包含 const 方法和字段的 getter
+fn get_str_dart_class_view(gen_context: &GenContext, class: &Class) -> String {
+    let mut view_str = format!("
+/// {} 的只读视图
+abstract interface class {}View {{
+    Pointer<Void> getNativePtr();
+", class.get_dart_name(&gen_context.dart_naming), class.get_dart_name(&gen_context.dart_naming));
+    for child in &class.children {
+        let method = match child {
+            HppElement::Method(method) => method,
+            HppElement::Field(field) => {
+                // 字段的 getter 属性，名字冲突时为 getter 方法
+                let get_method = Method::new_get_for_field(field);
+                match get_dart_field_property_name(gen_context, class, field) {
+                    Some(property_name) => view_str.push_str(&format!("    {} get {};
+", get_str_dart_fun_decl_type(gen_context, &get_method.return_type), property_name)),
+                    None => view_str.push_str(&format!("    {} {}();
+", get_str_dart_fun_decl_type(gen_context, &get_method.return_type), get_str_dart_fun_name(gen_context, Some(class), &get_method))),
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        // operator== 由 Object 声明，不需要放到视图中
+        if method.method_type != MethodType::Normal || method.is_static || !method.is_const
+            || method.operator_str.as_deref() == Some("==") {
+            continue;
+        }
+        view_str.push_str(&format!("    {} {}({});
+",
+            get_str_dart_fun_return_type(gen_context, method), get_str_dart_fun_name(gen_context, Some(class), method),
+            get_str_dart_fun_params_decl(gen_context, Some(class), &get_dart_named_method(gen_context, Some(class), method))));
+    }
+    view_str.push_str("}
+");
+
+    view_str
+}
+
+/// operator==，参数类型不匹配时返回 false
+fn get_str_dart_fun_for_equal_operator(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
+    let param = method.params.first().unwrap();
+    let fun_body = get_str_dart_fun_body(gen_context, class, method);
+
+    format!("    {}
+    @override
+    bool operator ==(Object {}) {{
+        if ({} is! {}) {{
+            return false;
+        }}
+        {}
+    }}
+",
+        method.comment_str.as_ref().unwrap_or(&"".to_string()),
+        param.name,
+        param.name, get_str_dart_fun_type(gen_context, &param.field_type),
+        fun_body,
+    )
+}
+
+fn get_str_dart_fun_body(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
-    let (cur_class_name, class_is_callback) = if let Some(cur_class) = class {
-        (cur_class.type_str.as_str(), cur_class.is_callback())
-    } else {
-        ("", false)
-    };
+    let cur_class_name = class.map_or("".to_string(), |cur_class| cur_class.get_ffi_name());
+    let class_is_callback = is_dart_callback_method(class, method);
     let ffiapi_c_method_name = format!("ffi_{}_{}", cur_class_name, method.name);
-    let params_str = get_str_dart_fun_params_impl(class, method);
+    let params_str = get_str_dart_fun_params_impl(gen_context, class, method);
 
-    // 仅对非回调类的方法（包括普通/构造/析构）处理字符串参数内存释放
-    let mut string_params: Vec<String> = Vec::new();
+    // 仅对非回调类的方法（包括普通/构造/析构）处理字符串、定长数组参数内存释放
+    let mut alloc_lines: Vec<String> = Vec::new();
+    let mut free_lines: Vec<String> = Vec::new();
     if !class_is_callback {
         for param in &method.params {
-            if param.field_type.type_kind == TypeKind::String {
-                string_params.push(param.name.clone());
+            if param.field_type.is_dart_collection_param() {
+                // dart 的 List、Set、Map 批量复制为 native 容器，由 dart 对象释放
+                let (_, factory_name) = get_str_dart_collection_type(gen_context, &param.field_type);
+                alloc_lines.push(format!("final _c_{} = {}.{}({});",
+                    param.name, get_str_dart_fun_type(gen_context, &param.field_type), factory_name, param.name));
+            }
+            else if param.field_type.type_kind == TypeKind::StdOptional && !param.field_type.is_mutable_reference() {
+                // null 时不创建 native 对象，c 中为空的 optional
+                alloc_lines.push(format!("final _c_{} = {} == null ? null : {}.fromDart({});",
+                    param.name, param.name, get_str_dart_fun_type(gen_context, &param.field_type), param.name));
+            }
+            else if matches!(param.field_type.type_kind, TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple) {
+                // 从 dart 的可空类型、sealed class、record 创建 native 的 optional、variant、pair、tuple，
+                // 局部变量保证调用结束前 dart 对象不会被回收（Finalizable），native 对象不会被提前释放
+                alloc_lines.push(format!("final _c_{} = {}.fromDart({});",
+                    param.name, get_str_dart_fun_type(gen_context, &param.field_type), param.name));
+            }
+            else if param.field_type.type_kind == TypeKind::String && param.field_type.buffer_len_param.is_some() {
+                // 带长度传递，字符串中间可以有 0
+                alloc_lines.push(format!("final _c_{}_units = utf8.encode({});
+        final _c_{} = malloc<Uint8>(_c_{}_units.length + 1);
+        _c_{}.asTypedList(_c_{}_units.length).setAll(0, _c_{}_units);
+        _c_{}[_c_{}_units.length] = 0;",
+                    param.name, param.name,
+                    param.name, param.name,
+                    param.name, param.name, param.name,
+                    param.name, param.name));
+                free_lines.push(format!("malloc.free(_c_{});", param.name));
+            }
+            else if param.field_type.type_kind == TypeKind::String {
+                alloc_lines.push(format!("final _c_{} = {}.toNativeUtf8();", param.name, param.name));
+                free_lines.push(format!("malloc.free(_c_{});", param.name));
+            }
+            else if param.field_type.type_kind == TypeKind::U16String {
+                // dart 的 String 就是 utf16 编码
+                alloc_lines.push(format!("final _c_{} = malloc<Uint16>({}.length + 1);
+        _c_{}.asTypedList({}.length).setAll(0, {}.codeUnits);
+        _c_{}[{}.length] = 0;",
+                    param.name, param.name,
+                    param.name, param.name, param.name,
+                    param.name, param.name));
+                free_lines.push(format!("malloc.free(_c_{});", param.name));
+            }
+            else if let Some(array_len) = param.field_type.array_len {
+                // 定长数组：检查长度，复制到 native 内存中
+                let element_native_type = get_str_native_api_type(gen_context, &FieldType { ptr_level: 0, ..param.field_type.clone() });
+                alloc_lines.push(format!("if ({}.length != {}) {{
+            throw ArgumentError.value({}.length, '{}', 'length must be {}');
+        }}
+        final _c_{} = malloc<{}>({});
+        for (var i = 0; i < {}; i++) {{
+            _c_{}[i] = {}[i];
+        }}", param.name, array_len, param.name, param.name, array_len,
+                    param.name, element_native_type, array_len,
+                    array_len, param.name, param.name));
+                free_lines.push(format!("malloc.free(_c_{});", param.name));
+            }
+            else if param.field_type.buffer_len_param.is_some() {
+                // buffer：一次复制到 native 内存中
+                let element_native_type = get_str_native_api_type(gen_context, &FieldType { ptr_level: 0, ..param.field_type.clone() });
+                alloc_lines.push(format!("final _c_{} = malloc<{}>({}.length);
+        _c_{}.asTypedList({}.length).setAll(0, {});",
+                    param.name, element_native_type, param.name,
+                    param.name, param.name, param.name));
+                free_lines.push(format!("malloc.free(_c_{});", param.name));
             }
         }
+        // 字符串返回值的长度输出参数
+        if let Some(ret_len_name) = method.return_type.buffer_len_param.as_deref() {
+            alloc_lines.push(format!("final _c_{} = malloc<Uint64>();", ret_len_name));
+            free_lines.push(format!("malloc.free(_c_{});", ret_len_name));
+        }
     }
 
     let mut body_prefix = "".to_string();
     let mut body_suffix = "".to_string();
+    // 转移所有权的、临时的 native 对象由 dart 对象释放，见 is_dart_owned_return
+    let return_lifecycle_link = if is_dart_owned_return(&method.return_type) { "..nativeLifecycleLink()" } else { "" };
     match method.method_type {
         MethodType::Normal => {
-            if method.return_type.type_kind == TypeKind::Class {
-                body_prefix.push_str(&format!("return {}.FromNative({}(", get_str_dart_fun_type(&method.return_type), ffiapi_c_method_name));
-                body_suffix.push_str("));");
+            if method.is_nullable_return {
+                // c 中返回空指针时为 null
+                let native_obj_str = format!("({}.FromNative(_ret){})", get_str_dart_fun_type(gen_context, &method.return_type), return_lifecycle_link);
+                let ret_str = match method.return_type.type_kind {
+                    TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple => format!("{}.toDart()", native_obj_str),
+                    _ => native_obj_str,
+                };
+                body_prefix.push_str(&format!("final _ret = {}(", ffiapi_c_method_name));
+                body_suffix.push_str(&format!(");
+        if (_ret == nullptr) {{
+            return null;
+        }}
+        return {};", ret_str));
             }
-            else if (method.return_type.type_kind == TypeKind::StdPtr)
+            else if method.return_type.type_kind == TypeKind::Class
+            || method.return_type.type_kind == TypeKind::StdUniquePtr
+            || method.return_type.type_kind == TypeKind::StdPtr
+            || method.return_type.type_kind == TypeKind::StdWeakPtr
             || method.return_type.type_kind == TypeKind::StdVector
+            || method.return_type.type_kind == TypeKind::StdList
+            || method.return_type.type_kind == TypeKind::StdDeque
             || method.return_type.type_kind == TypeKind::StdMap
+            || method.return_type.type_kind == TypeKind::StdMultimap
             || method.return_type.type_kind == TypeKind::StdUnorderedMap
             || method.return_type.type_kind == TypeKind::StdSet
             || method.return_type.type_kind == TypeKind::StdUnorderedSet
             {
-                body_prefix.push_str(&format!("return {}.FromNative({}(", get_str_dart_fun_type(&method.return_type), ffiapi_c_method_name));
+                body_prefix.push_str(&format!("return {}.FromNative({}(", get_str_dart_fun_type(gen_context, &method.return_type), ffiapi_c_method_name));
+                body_suffix.push_str(&format!(")){};", return_lifecycle_link));
+            }
+            else if let Some(array_len) = method.return_type.array_len {
+                // 定长数组：复制为 TypedData，native 内存属于所在的对象，对象释放后不能再访问；bool 没有 TypedData，复制为 List<bool>
+                if let Some(typed_list_type) = get_str_dart_typed_list_type(&method.return_type) {
+                    body_prefix.push_str(&format!("return {}.fromList({}(", typed_list_type, ffiapi_c_method_name));
+                    body_suffix.push_str(&format!(").asTypedList({}));", array_len));
+                } else {
+                    body_prefix.push_str(&format!("final _ret = {}(", ffiapi_c_method_name));
+                    body_suffix.push_str(&format!(");
+        return List<bool>.generate({}, (i) => _ret[i]);", array_len));
+                }
+            }
+            else if method.return_type.type_kind == TypeKind::StdFunction {
+                // 根据闭包 id 取回 dart 闭包
+                body_prefix.push_str(&format!("return {}.fromNative({}(", get_dart_type_name(&gen_context.dart_naming, &method.return_type.get_function_type_str()), ffiapi_c_method_name));
                 body_suffix.push_str("));");
             }
+            else if method.return_type.type_kind == TypeKind::StdDuration || method.return_type.type_kind == TypeKind::StdTimePoint {
+                body_prefix.push_str(&format!("return {}", get_str_dart_chrono_from_native(&method.return_type, &format!("{}(", ffiapi_c_method_name))));
+                body_suffix.push_str(");");
+            }
+            else if let Some(ret_len_name) = method.return_type.buffer_len_param.as_deref() {
+                // 字符串和长度一起返回，支持字符串中间的 0
+                let decode_str = if method.return_type.type_kind == TypeKind::U16String {
+                    format!("String.fromCharCodes(_ret.asTypedList(_c_{}.value))", ret_len_name)
+                } else {
+                    format!("utf8.decode(_ret.cast<Uint8>().asTypedList(_c_{}.value))", ret_len_name)
+                };
+                body_prefix.push_str(&format!("final _ret = {}(", ffiapi_c_method_name));
+                body_suffix.push_str(&format!(");
+        return {};", decode_str));
+            }
+            else if method.return_type.type_kind == TypeKind::U16String {
+                // 以 0 结尾的 utf16 字符串
+                body_prefix.push_str(&format!("final _ret = {}(", ffiapi_c_method_name));
+                body_suffix.push_str(");
+        var _ret_len = 0;
+        while (_ret[_ret_len] != 0) {
+            _ret_len++;
+        }
+        return String.fromCharCodes(_ret.asTypedList(_ret_len));");
+            }
+            else if method.return_type.type_kind == TypeKind::StdOptional
+            || method.return_type.type_kind == TypeKind::StdVariant
+            || method.return_type.type_kind == TypeKind::StdPair
+            || method.return_type.type_kind == TypeKind::StdTuple
+            {
+                // 转换为 dart 的可空类型、sealed class、record
+                body_prefix.push_str(&format!("return ({}.FromNative({}(", get_str_dart_fun_type(gen_context, &method.return_type), ffiapi_c_method_name));
+                body_suffix.push_str(&format!(")){}).toDart();", return_lifecycle_link));
+            }
             else {
                 body_prefix.push_str(&format!("return {}(", ffiapi_c_method_name));
                 if method.return_type.type_kind == TypeKind::String {
@@ -460,7 +955,7 @@ fn get_str_dart_fun_body(class: Option<&Class>, method: &Method) -> String {
                     // 枚举类型：从 int 转换为枚举，使用 fromValue() 方法
                     body_suffix.push_str(&format!("))!;"));
                     // 修改 prefix 以包含 fromValue 调用
-                    body_prefix = format!("return {}.fromValue({}(", get_str_dart_fun_type(&method.return_type), ffiapi_c_method_name);
+                    body_prefix = format!("return {}.fromValue({}(", get_str_dart_fun_type(gen_context, &method.return_type), ffiapi_c_method_name);
                 } else {
                     body_suffix.push_str(");");
                 }
@@ -484,15 +979,19 @@ fn get_str_dart_fun_body(class: Option<&Class>, method: &Method) -> String {
 
     let core_body = format!("{}{}{}", body_prefix, params_str, body_suffix);
 
-    // 如果没有字符串参数，保持原样
-    if string_params.is_empty() {
+    // 如果没有字符串、定长数组参数，保持原样
+    if alloc_lines.is_empty() {
         return core_body;
     }
 
-    // 有字符串参数：生成 _c_param 变量、try/finally 释放
-    // params_str 中针对字符串参数会使用占位符 _c_<name>
-    let alloc_lines: Vec<String> = string_params.iter().map(|n| format!("final _c_{} = {}.toNativeUtf8();", n, n)).collect();
-    let free_lines: Vec<String> = string_params.iter().map(|n| format!("malloc.free(_c_{});", n)).collect();
+    // 只有由 dart 对象释放的 native 对象（容器、optional 等）时，不需要 try/finally
+    if free_lines.is_empty() {
+        return format!("{}
+        {}", alloc_lines.join("\n\t\t"), core_body);
+    }
+
+    // 有字符串、定长数组参数：生成 _c_param 变量、try/finally 释放
+    // params_str 中针对这些参数会使用占位符 _c_<name>
     // 保持最小侵入：不改变 core_body 内容，仅包裹
     let wrapped = format!("{}
         try {{
@@ -508,17 +1007,23 @@ fn get_str_dart_fun_body(class: Option<&Class>, method: &Method) -> String {
     return wrapped;
 }
 
-fn get_str_dart_fun_callback_block(class: Option<&Class>, method: &Method) -> String {
+/// 返回的 native 对象是否由 dart 对象负责释放：值返回时 c 中创建的类对象副本，转移所有权的 unique_ptr，
+/// 以及转换为 dart 的可空类型、sealed class、record 后不再使用的 optional 等临时对象
+fn is_dart_owned_return(field_type: &FieldType) -> bool {
+    if 0 != field_type.ptr_level || field_type.is_reference {
+        return false;
+    }
+    matches!(field_type.type_kind,
+        TypeKind::Class | TypeKind::StdUniquePtr | TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple)
+}
+
+fn get_str_dart_fun_callback_block(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     if method.method_type != MethodType::Normal {
         return "".to_string();
     }
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
-    let (cur_class_name, class_is_callback) = if let Some(cur_class) = class {
-        (cur_class.type_str.as_str(), cur_class.is_callback())
-    } else {
-        ("", false)
-    };
-    if cur_class_name.is_empty() || !class_is_callback {
+    let cur_class_name = class.map_or("".to_string(), |cur_class| cur_class.get_dart_name(&gen_context.dart_naming));
+    if cur_class_name.is_empty() || !is_dart_callback_method(class, method) {
         return "".to_string();
     }
 
@@ -527,18 +1032,18 @@ fn get_str_dart_fun_callback_block(class: Option<&Class>, method: &Method) -> St
 
     if needs_sync_call {
         // 同步 callback（使用函数指针）：生成静态回调函数和 block
-        let params_str = get_str_dart_fun_params_decl(class, method);
+        let params_str = get_str_dart_fun_params_decl(gen_context, class, method);
 
         // 为同步回调只生成 block 定义，不生成 ReceivePort
         // 静态函数会在 _MyCallback_init 中使用 Pointer.fromFunction 生成
         let block_str = format!("    {} Function({})? {}_block = null;",
-            get_str_dart_fun_type(&method.return_type), params_str, method.name,
+            get_str_dart_fun_decl_type(gen_context, &method.return_type), params_str, method.name,
         );
         return block_str;
     } else {
         // 异步 callback（void 返回值）：生成 ReceivePort 和 block
-        let port_args_str = get_str_port_fun_params_impl(class, method);
-        let params_str = get_str_dart_fun_params_decl(class, method);
+        let port_args_str = get_str_port_fun_params_impl(gen_context, class, method);
+        let params_str = get_str_dart_fun_params_decl(gen_context, class, method);
         let block_str = format!("    static final {}_port = ReceivePort()..listen((data) {{
         final args = data as List;
         final nativePtr = Pointer<Void>.fromAddress(args[0]);
@@ -549,13 +1054,13 @@ fn get_str_dart_fun_callback_block(class: Option<&Class>, method: &Method) -> St
             method.name,
             cur_class_name,
             method.name, port_args_str,
-            get_str_dart_fun_type(&method.return_type), params_str, method.name,
+            get_str_dart_fun_type(gen_context, &method.return_type), params_str, method.name,
         );
         return block_str;
     }
 }
 
-fn get_str_port_fun_params_impl(class: Option<&Class>, method: &Method) -> String {
+fn get_str_port_fun_params_impl(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
     let class_is_callback = if let Some(cur_class) = class {
         cur_class.is_callback()
@@ -571,18 +1076,35 @@ fn get_str_port_fun_params_impl(class: Option<&Class>, method: &Method) -> Strin
     for i in 0..method.params.len() {
         let index = i+1;
         let param = &method.params[i];
-        if param.field_type.type_kind == TypeKind::Class {
-            param_strs.push(format!("{}.FromNative(Pointer<Void>.fromAddress(args[{}]))", get_str_dart_fun_type(&param.field_type), index));
-        }
-        else if param.field_type.type_kind == TypeKind::StdPtr
+        if param.field_type.type_kind == TypeKind::Class
+        || param.field_type.type_kind == TypeKind::StdPtr
         || param.field_type.type_kind == TypeKind::StdVector
         {
-            param_strs.push(format!("{}.FromNative(Pointer<Void>.fromAddress(args[{}]))", get_str_dart_fun_type(&param.field_type), index));
+            // 异步回调中 c++ 复制了一份参数，指针参数只是借用
+            let is_borrowed = param.field_type.ptr_level > 0;
+            param_strs.push(get_str_dart_callback_native_param(gen_context, &param.field_type, &format!("args[{}]", index), is_borrowed));
+        }
+        else if param.field_type.type_kind == TypeKind::StdOptional
+        || param.field_type.type_kind == TypeKind::StdVariant
+        || param.field_type.type_kind == TypeKind::StdPair
+        || param.field_type.type_kind == TypeKind::StdTuple
+        {
+            param_strs.push(get_str_dart_optional_from_native(gen_context, &param.field_type, &format!("Pointer<Void>.fromAddress(args[{}])", index)));
         }
         else if param.field_type.type_kind == TypeKind::Char
         {
             param_strs.push(format!{"(args[{}] as String).toNativeUtf8().cast()", index});
         }
+        else if param.field_type.type_kind == TypeKind::Enum
+        {
+            // 未知的枚举值对应 null
+            param_strs.push(format!("{}.fromValue(args[{}])", get_str_dart_fun_type(gen_context, &param.field_type), index));
+        }
+        else if param.field_type.type_kind == TypeKind::StdDuration
+        || param.field_type.type_kind == TypeKind::StdTimePoint
+        {
+            param_strs.push(get_str_dart_chrono_from_native(&param.field_type, &format!("args[{}]", index)));
+        }
         else {
             param_strs.push(format!("args[{}]", index));
         }
@@ -592,23 +1114,40 @@ fn get_str_port_fun_params_impl(class: Option<&Class>, method: &Method) -> Strin
 }
 
 /// 为同步回调生成参数解析代码（从 args[3] 开始，因为前3个是 request_id, method_id, this）
-fn get_str_port_fun_params_impl_for_sync_callback(class: Option<&Class>, method: &Method) -> String {
+fn get_str_port_fun_params_impl_for_sync_callback(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     let mut param_strs = Vec::new();
     for i in 0..method.params.len() {
         let index = i+3;  // 前3个是 request_id, method_id, this
         let param = &method.params[i];
-        if param.field_type.type_kind == TypeKind::Class {
-            param_strs.push(format!("{}.FromNative(Pointer<Void>.fromAddress(args[{}]))", get_str_dart_fun_type(&param.field_type), index));
-        }
-        else if param.field_type.type_kind == TypeKind::StdPtr
+        if param.field_type.type_kind == TypeKind::Class
+        || param.field_type.type_kind == TypeKind::StdPtr
         || param.field_type.type_kind == TypeKind::StdVector
         {
-            param_strs.push(format!("{}.FromNative(Pointer<Void>.fromAddress(args[{}]))", get_str_dart_fun_type(&param.field_type), index));
+            // 异步回调中 c++ 复制了一份参数，指针参数只是借用
+            let is_borrowed = param.field_type.ptr_level > 0;
+            param_strs.push(get_str_dart_callback_native_param(gen_context, &param.field_type, &format!("args[{}]", index), is_borrowed));
+        }
+        else if param.field_type.type_kind == TypeKind::StdOptional
+        || param.field_type.type_kind == TypeKind::StdVariant
+        || param.field_type.type_kind == TypeKind::StdPair
+        || param.field_type.type_kind == TypeKind::StdTuple
+        {
+            param_strs.push(get_str_dart_optional_from_native(gen_context, &param.field_type, &format!("Pointer<Void>.fromAddress(args[{}])", index)));
         }
         else if param.field_type.type_kind == TypeKind::Char
         {
             param_strs.push(format!{"(args[{}] as String).toNativeUtf8().cast()", index});
         }
+        else if param.field_type.type_kind == TypeKind::Enum
+        {
+            // 未知的枚举值对应 null
+            param_strs.push(format!("{}.fromValue(args[{}])", get_str_dart_fun_type(gen_context, &param.field_type), index));
+        }
+        else if param.field_type.type_kind == TypeKind::StdDuration
+        || param.field_type.type_kind == TypeKind::StdTimePoint
+        {
+            param_strs.push(get_str_dart_chrono_from_native(&param.field_type, &format!("args[{}]", index)));
+        }
         else {
             param_strs.push(format!("args[{}]", index));
         }
@@ -617,15 +1156,16 @@ fn get_str_port_fun_params_impl_for_sync_callback(class: Option<&Class>, method:
     return param_strs.join(", ");
 }
 
-fn get_str_dart_fun_body_for_callback(class: Option<&Class>, method: &Method) -> String {
+fn get_str_dart_fun_body_for_callback(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
-    let (cur_class_name, class_is_callback) = if let Some(cur_class) = class {
-        (cur_class.type_str.as_str(), cur_class.is_callback())
+    // cur_class_name 用于 ffi 符号名，dart_class_name 用于 dart 类名
+    let (cur_class_name, dart_class_name) = if let Some(cur_class) = class {
+        (cur_class.get_ffi_name(), cur_class.get_dart_name(&gen_context.dart_naming))
     } else {
-        ("", false)
+        ("".to_string(), "".to_string())
     };
     let ffiapi_c_method_name = format!("ffi_{}_{}", cur_class_name, method.name);
-    let params_str = get_str_dart_fun_params_impl(class, method);
+    let params_str = get_str_dart_fun_params_impl(gen_context, class, method);
 
     let exception_default_value_str = get_str_dart_api_exception_default_value(&method.return_type);
     let exception_value_str = if exception_default_value_str.is_empty() {
@@ -662,7 +1202,7 @@ fn get_str_dart_fun_body_for_callback(class: Option<&Class>, method: &Method) ->
                                 let default_value = "0".to_string();
 
                                 sync_callback_registrations.push_str(&format!("\n        final {}_ptr = Pointer.fromFunction<{}>({}._{}_{}_static, {});",
-                                    m.name, ffi_signature, cur_class_name, cur_class_name, m.name, default_value));
+                                    m.name, ffi_signature, dart_class_name, cur_class_name, m.name, default_value));
                                 sync_callback_registrations.push_str(&format!("\n        FFI_{}_{}_FnPtr_register(_nativePtr, {}_ptr);",
                                     cur_class_name, m.name, m.name));
                             }
@@ -674,7 +1214,7 @@ fn get_str_dart_fun_body_for_callback(class: Option<&Class>, method: &Method) ->
             body_suffix.push_str(&format!(");
         nativeLifecycleLink();
         nativeToObjMap[_nativePtr] = WeakReference<{}>(this);{}
-        _{}_init();", cur_class_name, sync_callback_registrations, cur_class_name));
+        _{}_init();", dart_class_name, sync_callback_registrations, cur_class_name));
         }
         MethodType::Destructor => {
             body_prefix.push_str(&format!("nativeLifecycleUnlink();\n\t\treturn {}(", ffiapi_c_method_name));
@@ -694,22 +1234,51 @@ fn get_str_dart_fun_body_for_callback(class: Option<&Class>, method: &Method) ->
 // 已删除 get_str_dart_ffi_params_decl_for_sync_callback 和 get_str_dart_sync_callback_wrapper
 // 同步回调现在通过 ReceivePort 和 setCallbackResult 实现，不再需要 Pointer.fromFunction
 
-fn get_str_dart_fun_params_decl(class: Option<&Class>, method: &Method) -> String {
+fn get_str_dart_fun_params_decl(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
+    let class_is_callback = is_dart_callback_method(class, method);
     let mut param_strs = Vec::new();
     for param in &method.params {
-        param_strs.push(format!("{} {}", get_str_dart_fun_type(&param.field_type), param.name));
+        // buffer 的长度参数使用 TypedData 的长度，字符串返回值的长度输出参数在函数中分配，不需要传
+        if method.get_buffer_param_for_len(&param.name).is_some() || method.is_return_len_param(&param.name) {
+            continue;
+        }
+        // 基础数据类型、字符串的容器参数直接使用 dart 的 List、Set、Map
+        if !class_is_callback && param.field_type.is_dart_collection_param() {
+            let (collection_type, _) = get_str_dart_collection_type(gen_context, &param.field_type);
+            param_strs.push(format!("{} {}", collection_type, param.name));
+            continue;
+        }
+        let decl_type = if class_is_callback {
+            get_str_dart_callback_param_decl_type(gen_context, &param.field_type)
+        } else {
+            get_str_dart_fun_decl_type(gen_context, &param.field_type)
+        };
+        param_strs.push(format!("{} {}", decl_type, param.name));
     }
 
     return param_strs.join(", ");
 }
 
-fn get_str_dart_fun_params_impl(class: Option<&Class>, method: &Method) -> String {
+/// 容器参数对应的 dart 集合类型，以及容器类中从 dart 集合创建的构造函数名
+fn get_str_dart_collection_type(gen_context: &GenContext, field_type: &FieldType) -> (String, &'static str) {
+    let value_dart_type = field_type.value_type.as_deref().map_or("dynamic".to_string(), |value_type| get_str_dart_fun_type(gen_context, value_type));
+    match field_type.type_kind {
+        TypeKind::StdSet | TypeKind::StdUnorderedSet => {
+            (format!("Set<{}>", value_dart_type), "fromSet")
+        }
+        TypeKind::StdMap | TypeKind::StdUnorderedMap => {
+            let key_dart_type = field_type.key_type.as_deref().map_or("dynamic".to_string(), |key_type| get_str_dart_fun_type(gen_context, key_type));
+            (format!("Map<{}, {}>", key_dart_type, value_dart_type), "fromMap")
+        }
+        _ => {
+            (format!("List<{}>", value_dart_type), "fromList")
+        }
+    }
+}
+
+fn get_str_dart_fun_params_impl(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
-    let class_is_callback = if let Some(cur_class) = class {
-        cur_class.is_callback()
-    } else {
-        false
-    };
+    let class_is_callback = is_dart_callback_method(class, method);
     let is_destructor = method.method_type == MethodType::Destructor;
 
     let mut param_strs = Vec::new();
@@ -721,20 +1290,72 @@ fn get_str_dart_fun_params_impl(class: Option<&Class>, method: &Method) -> Strin
             param_strs.push(format!("{}", param.name));
             continue;
         }
+        if method.is_return_len_param(&param.name) {
+            param_strs.push(format!("_c_{}", param.name));
+            continue;
+        }
+        if let Some(buffer_param) = method.get_buffer_param_for_len(&param.name) {
+            // utf8 字符串的长度是编码后的字节数
+            if buffer_param.field_type.type_kind == TypeKind::String {
+                param_strs.push(format!("_c_{}_units.length", buffer_param.name));
+            } else {
+                param_strs.push(format!("{}.length", buffer_param.name));
+            }
+            continue;
+        }
 
         if !class_is_callback && param.field_type.type_kind == TypeKind::Class {
             param_strs.push(format!("{}.getNativePtr()", param.name));
         }
+        else if param.field_type.is_dart_collection_param() {
+            // 在 get_str_dart_fun_body 中从 dart 集合创建的 native 容器
+            param_strs.push(format!("_c_{}.getNativePtr()", param.name));
+        }
         else if param.field_type.type_kind == TypeKind::StdPtr
+        || param.field_type.type_kind == TypeKind::StdWeakPtr
         || param.field_type.type_kind == TypeKind::StdVector
+        || param.field_type.type_kind == TypeKind::StdList
+        || param.field_type.type_kind == TypeKind::StdDeque
         || param.field_type.type_kind == TypeKind::StdMap
+        || param.field_type.type_kind == TypeKind::StdMultimap
         || param.field_type.type_kind == TypeKind::StdUnorderedMap
         || param.field_type.type_kind == TypeKind::StdSet
         || param.field_type.type_kind == TypeKind::StdUnorderedSet
         {
             param_strs.push(format!("{}.getNativePtr()", param.name));
         }
-        else if param.field_type.type_kind == TypeKind::String {
+        else if param.field_type.is_borrowed_unique_ptr() {
+            param_strs.push(format!("{}.getNativePtr()", param.name));
+        }
+        else if param.field_type.type_kind == TypeKind::StdUniquePtr {
+            // 所有权转移给 c++，dart 对象不再可用
+            param_strs.push(format!("{}.nativeRelease()", param.name));
+        }
+        else if param.field_type.type_kind == TypeKind::StdOptional && !param.field_type.is_mutable_reference() {
+            // 在 get_str_dart_fun_body 中创建，null 时传递空指针
+            param_strs.push(format!("_c_{}?.getNativePtr() ?? nullptr", param.name));
+        }
+        else if param.field_type.type_kind == TypeKind::StdOptional
+        || param.field_type.type_kind == TypeKind::StdVariant
+        || param.field_type.type_kind == TypeKind::StdPair
+        || param.field_type.type_kind == TypeKind::StdTuple
+        {
+            // 在 get_str_dart_fun_body 中创建的 native 对象
+            param_strs.push(format!("_c_{}.getNativePtr()", param.name));
+        }
+        else if param.field_type.type_kind == TypeKind::StdFunction {
+            // 保存 dart 闭包，传递闭包的 id
+            param_strs.push(format!("{}.toNative({})", get_dart_type_name(&gen_context.dart_naming, &param.field_type.get_function_type_str()), param.name));
+        }
+        else if param.field_type.type_kind == TypeKind::String && param.field_type.buffer_len_param.is_some() {
+            // 按 utf8 编码分配的内存，带长度传递
+            param_strs.push(format!("_c_{}.cast<Utf8>()", param.name));
+        }
+        else if param.field_type.type_kind == TypeKind::String
+        || param.field_type.type_kind == TypeKind::U16String
+        || param.field_type.array_len.is_some()
+        || param.field_type.buffer_len_param.is_some()
+        {
             // 使用占位符变量，实际分配在 get_str_dart_fun_body 中完成
             param_strs.push(format!("_c_{}", param.name));
         }
@@ -742,6 +1363,9 @@ fn get_str_dart_fun_params_impl(class: Option<&Class>, method: &Method) -> Strin
             // 枚举类型需要访问 .value 属性来获取整数值
             param_strs.push(format!("{}.value", param.name));
         }
+        else if param.field_type.type_kind == TypeKind::StdDuration || param.field_type.type_kind == TypeKind::StdTimePoint {
+            param_strs.push(get_str_dart_chrono_to_native(&param.field_type, &param.name));
+        }
         else {
             param_strs.push(format!("{}", param.name));
         }
@@ -751,16 +1375,17 @@ fn get_str_dart_fun_params_impl(class: Option<&Class>, method: &Method) -> Strin
 }
 
 /// (初始化内容，回调函数的实现内容)
-fn get_dart_fun_for_regist_callback(class: Option<&Class>, method: &Method) -> (String, String) {
+fn get_dart_fun_for_regist_callback(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> (String, String) {
     if method.method_type != MethodType::Normal {
         return ("".to_string(), "".to_string());
     }
 
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
-    let cur_class_name = if let Some(cur_class) = class {
-        &cur_class.type_str
+    // cur_class_name 用于 ffi 符号名，dart_class_name 用于 dart 类名
+    let (cur_class_name, dart_class_name) = if let Some(cur_class) = class {
+        (cur_class.get_ffi_name(), cur_class.get_dart_name(&gen_context.dart_naming))
     } else {
-        ""
+        ("".to_string(), "".to_string())
     };
 
     // 判断是否需要同步调用（使用 is_sync_callback 标志）
@@ -780,24 +1405,7 @@ fn get_dart_fun_for_regist_callback(class: Option<&Class>, method: &Method) -> (
             ffi_param_strs.push(format!("int {}_raw", param.name));
 
             // 根据类型转换参数
-            let converted_param = match param.field_type.type_kind {
-                TypeKind::Int64 | TypeKind::Char | TypeKind::Bool => {
-                    format!("{}_raw", param.name)
-                }
-                TypeKind::Float => {
-                    // 从 int64 位模式重新解释为 float
-                    format!("(() {{ final p = malloc<Int64>(); p.value = {}_raw; final f = p.cast<Float>().value; malloc.free(p); return f; }})()", param.name)
-                }
-                TypeKind::Double => {
-                    // 从 int64 位模式重新解释为 double
-                    format!("(() {{ final p = malloc<Int64>(); p.value = {}_raw; final d = p.cast<Double>().value; malloc.free(p); return d; }})()", param.name)
-                }
-                TypeKind::String => {
-                    // String 通过指针传递，需要转换
-                    format!("Pointer<Utf8>.fromAddress({}_raw).toDartString()", param.name)
-                }
-                _ => format!("{}_raw", param.name),
-            };
+            let converted_param = get_str_dart_sync_callback_param(gen_context, &param.field_type, &format!("{}_raw", param.name));
             call_param_strs.push(converted_param);
         }
 
@@ -806,7 +1414,7 @@ fn get_dart_fun_for_regist_callback(class: Option<&Class>, method: &Method) -> (
 
         // 生成默认返回值
         let default_return = match method.return_type.type_kind {
-            TypeKind::Int64 => "0",
+            ref kind if kind.is_integer() => "0",
             TypeKind::Float | TypeKind::Double => "0.0",
             TypeKind::Bool => "false",
             _ => "0",
@@ -820,29 +1428,9 @@ fn get_dart_fun_for_regist_callback(class: Option<&Class>, method: &Method) -> (
                 let ret = "return 0;".to_string(); // FFI 函数必须返回 int64
                 (body, ret)
             }
-            TypeKind::Int64 | TypeKind::Char => {
-                let body = format!("      final result = obj.{}_block!({});", method.name, call_params_str);
-                let ret = "return result;".to_string();
-                (body, ret)
-            }
-            TypeKind::Bool => {
-                let body = format!("      final result = obj.{}_block!({});", method.name, call_params_str);
-                let ret = "return (result ? 1 : 0);".to_string();
-                (body, ret)
-            }
-            TypeKind::Float => {
-                let body = format!("      final result = obj.{}_block!({});", method.name, call_params_str);
-                let ret = "return (() { final p = malloc<Float>(); p.value = result; final i = p.cast<Int64>().value; malloc.free(p); return i; })();".to_string();
-                (body, ret)
-            }
-            TypeKind::Double => {
-                let body = format!("      final result = obj.{}_block!({});", method.name, call_params_str);
-                let ret = "return (() { final p = malloc<Double>(); p.value = result; final i = p.cast<Int64>().value; malloc.free(p); return i; })();".to_string();
-                (body, ret)
-            }
             _ => {
                 let body = format!("      final result = obj.{}_block!({});", method.name, call_params_str);
-                let ret = "return result;".to_string();
+                let ret = get_str_dart_sync_callback_return(&method.return_type);
                 (body, ret)
             }
         };
@@ -862,7 +1450,7 @@ fn get_dart_fun_for_regist_callback(class: Option<&Class>, method: &Method) -> (
   }}
 ",
             dart_callback_fun_name, ffi_params_str,
-            cur_class_name,
+            dart_class_name,
             method.name,
             callback_body,
             return_stmt
@@ -884,8 +1472,8 @@ fn get_dart_fun_for_regist_callback(class: Option<&Class>, method: &Method) -> (
         let native_regist_fun_name = format!("{}_regist", native_fun_type_name);
         // 实现函数的名字
         let dart_callback_fun_name = format!("_{}_{}", cur_class_name, method.name);
-        let params_decl_str = get_str_dart_fun_params_decl_for_regist_callback(class, method);
-        let params_impl_str = get_str_dart_fun_params_impl_for_regist_callback(class, method);
+        let params_decl_str = get_str_dart_fun_params_decl_for_regist_callback(gen_context, class, method);
+        let params_impl_str = get_str_dart_fun_params_impl_for_regist_callback(gen_context, class, method);
 
         // 生成用于初始化的内容
         let exception_default_value_str = get_str_dart_api_exception_default_value(&method.return_type);
@@ -899,7 +1487,7 @@ fn get_dart_fun_for_regist_callback(class: Option<&Class>, method: &Method) -> (
     {}({}.{}_port.sendPort.nativePort);
     }}
 ",
-            native_regist_fun_name, cur_class_name, method.name,
+            native_regist_fun_name, dart_class_name, method.name,
         );
 
         // 生成dart回调函数内容
@@ -907,31 +1495,102 @@ fn get_dart_fun_for_regist_callback(class: Option<&Class>, method: &Method) -> (
     return {}.nativeToObjMap[native]!.target!.{}({});
 }}
 ",
-            get_str_dart_api_type(&method.return_type), dart_callback_fun_name, params_decl_str,
-            cur_class_name, method.name, params_impl_str,
+            get_str_dart_api_type(gen_context, &method.return_type), dart_callback_fun_name, params_decl_str,
+            dart_class_name, method.name, params_impl_str,
         );
 
         return (init_str, dart_fun_impl);
     }
 }
 
-fn get_str_dart_fun_params_decl_for_regist_callback(class: Option<&Class>, method: &Method) -> String {
-    let mut param_strs = Vec::new();
-    if gen_c::get_is_need_first_class_param(class, method) {
-        param_strs.push("Pointer<Void> native".to_string());
-    }
-    for param in &method.params {
-        param_strs.push(format!("{} {}", get_str_dart_api_type(&param.field_type), param.name));
+/// 同步回调的参数，c 中都转换成了 int64 传递，这里转换回 dart 类型
+fn get_str_dart_sync_callback_param(gen_context: &GenContext, field_type: &FieldType, raw_str: &str) -> String {
+    match field_type.type_kind {
+        TypeKind::Float => {
+            // 从 int64 位模式重新解释为 float
+            format!("(() {{ final p = malloc<Int64>(); p.value = {}; final f = p.cast<Float>().value; malloc.free(p); return f; }})()", raw_str)
+        }
+        TypeKind::Double => {
+            // 从 int64 位模式重新解释为 double
+            format!("(() {{ final p = malloc<Int64>(); p.value = {}; final d = p.cast<Double>().value; malloc.free(p); return d; }})()", raw_str)
+        }
+        TypeKind::String => {
+            // String 通过指针传递，需要转换
+            format!("Pointer<Utf8>.fromAddress({}).toDartString()", raw_str)
+        }
+        TypeKind::Enum => {
+            // 未知的枚举值对应 null
+            format!("{}.fromValue({})", get_str_dart_fun_type(gen_context, field_type), raw_str)
+        }
+        TypeKind::StdDuration | TypeKind::StdTimePoint => {
+            get_str_dart_chrono_from_native(field_type, raw_str)
+        }
+        TypeKind::Class | TypeKind::StdPtr | TypeKind::StdVector => {
+            // 同步回调中 c++ 复制了一份参数，指针、非 const 引用参数只是借用
+            let is_borrowed = field_type.ptr_level > 0 || field_type.is_mutable_reference();
+            get_str_dart_callback_native_param(gen_context, field_type, raw_str, is_borrowed)
+        }
+        TypeKind::StdOptional | TypeKind::StdVariant | TypeKind::StdPair | TypeKind::StdTuple => {
+            get_str_dart_optional_from_native(gen_context, field_type, &format!("Pointer<Void>.fromAddress({})", raw_str))
+        }
+        _ => {
+            raw_str.to_string()
+        }
+    }
+}
+
+/// 回调的 native 对象参数，c++ 复制的参数由 dart 对象负责释放，借用的参数不绑定生命周期
+fn get_str_dart_callback_native_param(gen_context: &GenContext, field_type: &FieldType, raw_str: &str, is_borrowed: bool) -> String {
+    let lifecycle_link = if is_borrowed { "" } else { "..nativeLifecycleLink()" };
+    format!("{}.FromNative(Pointer<Void>.fromAddress({})){}", get_str_dart_fun_type(gen_context, field_type), raw_str, lifecycle_link)
+}
+
+/// 回调参数的 dart 类型，未知的枚举值对应 null
+fn get_str_dart_callback_param_decl_type(gen_context: &GenContext, field_type: &FieldType) -> String {
+    if field_type.type_kind == TypeKind::Enum && field_type.ptr_level == 0 {
+        return format!("{}?", get_str_dart_fun_decl_type(gen_context, field_type));
+    }
+    get_str_dart_fun_decl_type(gen_context, field_type)
+}
+
+/// 同步回调的返回值 result，转换成 int64 返回给 c
+fn get_str_dart_sync_callback_return(return_type: &FieldType) -> String {
+    match return_type.type_kind {
+        TypeKind::Bool => {
+            "return (result ? 1 : 0);".to_string()
+        }
+        TypeKind::Float => {
+            "return (() { final p = malloc<Float>(); p.value = result; final i = p.cast<Int64>().value; malloc.free(p); return i; })();".to_string()
+        }
+        TypeKind::Double => {
+            "return (() { final p = malloc<Double>(); p.value = result; final i = p.cast<Int64>().value; malloc.free(p); return i; })();".to_string()
+        }
+        TypeKind::StdDuration | TypeKind::StdTimePoint => {
+            format!("return {};", get_str_dart_chrono_to_native(return_type, "result"))
+        }
+        _ => {
+            "return result;".to_string()
+        }
+    }
+}
+
+fn get_str_dart_fun_params_decl_for_regist_callback(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
+    let mut param_strs = Vec::new();
+    if gen_c::get_is_need_first_class_param(class, method) {
+        param_strs.push("Pointer<Void> native".to_string());
+    }
+    for param in &method.params {
+        param_strs.push(format!("{} {}", get_str_dart_api_type(gen_context, &param.field_type), param.name));
     }
 
     return param_strs.join(", ");
 }
 
-fn get_str_dart_fun_params_impl_for_regist_callback(class: Option<&Class>, method: &Method) -> String {
+fn get_str_dart_fun_params_impl_for_regist_callback(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     let mut param_strs = Vec::new();
     for param in &method.params {
         if param.field_type.type_kind == TypeKind::Class {
-            param_strs.push(format!("{}.FromNative({})", param.field_type.type_str, param.name));
+            param_strs.push(format!("{}.FromNative({})", get_str_dart_fun_type(gen_context, &param.field_type), param.name));
         } else if param.field_type.type_kind == TypeKind::String {
             // String parameters come as Pointer<Utf8>, need to convert to Dart String
             param_strs.push(format!("{}.toDartString()", param.name));
@@ -941,7 +1600,15 @@ fn get_str_dart_fun_params_impl_for_regist_callback(class: Option<&Class>, metho
             || param.field_type.type_kind == TypeKind::StdSet
             || param.field_type.type_kind == TypeKind::StdUnorderedSet {
             // STL containers need to be wrapped with FromNative
-            param_strs.push(format!("{}.FromNative({})", get_str_dart_fun_type(&param.field_type), param.name));
+            param_strs.push(format!("{}.FromNative({})", get_str_dart_fun_type(gen_context, &param.field_type), param.name));
+        } else if param.field_type.type_kind == TypeKind::StdOptional
+            || param.field_type.type_kind == TypeKind::StdVariant
+            || param.field_type.type_kind == TypeKind::StdPair
+            || param.field_type.type_kind == TypeKind::StdTuple {
+            param_strs.push(get_str_dart_optional_from_native(gen_context, &param.field_type, &param.name));
+        } else if param.field_type.type_kind == TypeKind::StdDuration
+            || param.field_type.type_kind == TypeKind::StdTimePoint {
+            param_strs.push(get_str_dart_chrono_from_native(&param.field_type, &param.name));
         } else {
             param_strs.push(format!("{}", param.name));
         }
@@ -950,10 +1617,170 @@ fn get_str_dart_fun_params_impl_for_regist_callback(class: Option<&Class>, metho
     return param_strs.join(", ");
 }
 
-fn get_str_dart_fun_type(field_type: &FieldType) -> String {
+/// 函数声明中使用的类型，typedef / using 别名使用别名，保持签名可读
+fn get_str_dart_fun_return_type(gen_context: &GenContext, method: &Method) -> String {
+    // 返回 const 引用时使用只读视图，只能调用 const 方法
+    let decl_type = if method.return_type.type_kind == TypeKind::Class && method.return_type.is_const_reference() {
+        format!("{}View", get_str_dart_fun_type(gen_context, &method.return_type))
+    } else {
+        get_str_dart_fun_decl_type(gen_context, &method.return_type)
+    };
+    // 可能返回空指针时使用可空类型，std::optional 本身已经是可空类型
+    if method.is_nullable_return && !decl_type.ends_with('?') {
+        return format!("{}?", decl_type);
+    }
+    decl_type
+}
+
+fn get_str_dart_fun_decl_type(gen_context: &GenContext, field_type: &FieldType) -> String {
+    // 定长数组、buffer 使用 TypedData，bool 没有对应的 TypedData，使用 List<bool>
+    if field_type.array_len.is_some() || (field_type.buffer_len_param.is_some() && field_type.type_kind.is_typed_data_element()) {
+        return match get_str_dart_typed_list_type(field_type) {
+            Some(typed_list_type) => typed_list_type,
+            None => "List<bool>".to_string(),
+        };
+    }
+    if let Some(alias_str) = field_type.alias_str.as_ref() {
+        return get_dart_type_name(&gen_context.dart_naming, alias_str);
+    }
+    // std::optional 使用可空类型
+    if field_type.type_kind == TypeKind::StdOptional {
+        if let Some(value_type) = field_type.value_type.as_ref() {
+            return format!("{}?", get_str_dart_fun_decl_type(gen_context, value_type));
+        }
+    }
+    // 空的 std::function 对应 null
+    if field_type.type_kind == TypeKind::StdFunction {
+        return format!("{}?", get_str_dart_fun_type(gen_context, field_type));
+    }
+    // std::variant 使用 sealed class，见 get_str_dart_stdvariant_sealed_classes
+    if field_type.type_kind == TypeKind::StdVariant {
+        return format!("{}Value", get_str_dart_fun_type(gen_context, field_type));
+    }
+    // std::pair、std::tuple 使用 record，只有一个元素的 record 需要加逗号
+    if field_type.type_kind == TypeKind::StdPair || field_type.type_kind == TypeKind::StdTuple {
+        let element_strs: Vec<String> = field_type.param_types.iter().map(|element_type| get_str_dart_fun_decl_type(gen_context, element_type)).collect();
+        let trailing_comma = if element_strs.len() == 1 { "," } else { "" };
+        return format!("({}{})", element_strs.join(", "), trailing_comma);
+    }
+
+    get_str_dart_fun_type(gen_context, field_type)
+}
+
+/// 定长数组元素对应的 dart TypedData 类型
+fn get_str_dart_typed_list_type(field_type: &FieldType) -> Option<String> {
+    let typed_list_type = match field_type.type_kind {
+        TypeKind::Int8 | TypeKind::Char => "Int8List",
+        TypeKind::Int16 => "Int16List",
+        TypeKind::Int32 => "Int32List",
+        TypeKind::Int64 => "Int64List",
+        TypeKind::UInt8 => "Uint8List",
+        TypeKind::UInt16 => "Uint16List",
+        TypeKind::UInt32 => "Uint32List",
+        TypeKind::UInt64 => "Uint64List",
+        TypeKind::Float => "Float32List",
+        TypeKind::Double => "Float64List",
+        _ => return None,
+    };
+    Some(typed_list_type.to_string())
+}
+
+/// 回调参数中的 std::optional、std::variant、std::pair、std::tuple（c 中复制出来的 native 对象）转换为 dart 的可空类型、sealed class、record，并释放 native 对象
+fn get_str_dart_optional_from_native(gen_context: &GenContext, field_type: &FieldType, native_ptr_str: &str) -> String {
+    format!("({}.FromNative({})..nativeLifecycleLink()).toDart()", get_str_dart_fun_type(gen_context, field_type), native_ptr_str)
+}
+
+/// ffi 中的微秒数转换为 dart 的 Duration、DateTime
+fn get_str_dart_chrono_from_native(field_type: &FieldType, value_str: &str) -> String {
+    if field_type.type_kind == TypeKind::StdTimePoint {
+        return format!("DateTime.fromMicrosecondsSinceEpoch({}, isUtc: true)", value_str);
+    }
+    format!("Duration(microseconds: {})", value_str)
+}
+
+/// dart 的 Duration、DateTime 转换为 ffi 中的微秒数
+fn get_str_dart_chrono_to_native(field_type: &FieldType, value_str: &str) -> String {
+    if field_type.type_kind == TypeKind::StdTimePoint {
+        return format!("{}.microsecondsSinceEpoch", value_str);
+    }
+    format!("{}.inMicroseconds", value_str)
+}
+
+/// 文件中用到的 typedef / using 别名，生成 dart typedef
+fn get_str_dart_typedefs(gen_context: &GenContext, file: &File) -> String {
+    let mut alias_types = Vec::new();
+    for child in &file.children {
+        collect_alias_types_from_element(child, &mut alias_types);
+    }
+
+    let mut typedefs_str = "".to_string();
+    let mut alias_names = Vec::new();
+    for alias_type in &alias_types {
+        // 定长数组、buffer 在 dart 中直接使用 TypedData，不使用别名，别名不能覆盖 dart:typed_data 中的类型
+        if alias_type.array_len.is_some() || alias_type.buffer_len_param.is_some() {
+            continue;
+        }
+        let alias_name = get_str_dart_fun_decl_type(gen_context, alias_type);
+        if alias_names.contains(&alias_name) {
+            continue;
+        }
+        // std::optional 的别名对应可空类型，std::variant 的别名对应 sealed class，std::pair、std::tuple 的别名对应 record
+        let dart_type = if alias_type.type_kind == TypeKind::StdOptional || alias_type.type_kind == TypeKind::StdVariant
+        || alias_type.type_kind == TypeKind::StdPair || alias_type.type_kind == TypeKind::StdTuple {
+            let mut optional_type = alias_type.clone();
+            optional_type.alias_str = None;
+            get_str_dart_fun_decl_type(gen_context, &optional_type)
+        } else {
+            get_str_dart_fun_type(gen_context, alias_type)
+        };
+        typedefs_str.push_str(&format!("typedef {} = {};\n", alias_name, dart_type));
+        alias_names.push(alias_name);
+    }
+    if !typedefs_str.is_empty() {
+        typedefs_str.push('\n');
+    }
+
+    typedefs_str
+}
+
+fn collect_alias_types_from_element(element: &HppElement, alias_types: &mut Vec<FieldType>) {
+    match element {
+        HppElement::Class(class) => {
+            for child in &class.children {
+                collect_alias_types_from_element(child, alias_types);
+            }
+        },
+        HppElement::Method(method) => {
+            if method.return_type.alias_str.is_some() {
+                alias_types.push(method.return_type.clone());
+            }
+            for param in &method.params {
+                if param.field_type.alias_str.is_some() {
+                    alias_types.push(param.field_type.clone());
+                }
+            }
+        },
+        HppElement::Field(field) if field.field_type.alias_str.is_some() => {
+            alias_types.push(field.field_type.clone());
+        },
+        HppElement::Variable(variable) if variable.field_type.alias_str.is_some() => {
+            alias_types.push(variable.field_type.clone());
+        },
+        _ => {}
+    }
+}
+
+fn get_str_dart_fun_type(gen_context: &GenContext, field_type: &FieldType) -> String {
     // 枚举类型，返回枚举类型名称
     if field_type.type_kind == TypeKind::Enum {
-        return field_type.type_str.clone();
+        return get_dart_type_name(&gen_context.dart_naming, &field_type.type_str);
+    }
+    // std::chrono 类型
+    else if field_type.type_kind == TypeKind::StdDuration {
+        return "Duration".to_string();
+    }
+    else if field_type.type_kind == TypeKind::StdTimePoint {
+        return "DateTime".to_string();
     }
     // class类型，需要对应 dart class
     else if field_type.type_kind == TypeKind::Class {
@@ -963,86 +1790,245 @@ fn get_str_dart_fun_type(field_type: &FieldType) -> String {
             .replace("const&", "")
             .replace("&", "")
             .replace("*", "")
-            .replace(" ", "")
-            .replace("::", "");
+            .replace(" ", "");
 
         // Special handling for string types that might be misclassified as Class
-        if clean_type == "stdstring" || clean_type == "string" {
+        if clean_type == "std::string" || clean_type == "string" {
             return "String".to_string();
         }
 
-        return clean_type;
+        return get_dart_type_name(&gen_context.dart_naming, &clean_type);
     }
     // 智能指针类型，需要对应 dart class
     else if field_type.type_kind == TypeKind::StdPtr {
-        return format!("StdPtr_{}", field_type.type_str);
+        return get_dart_type_name(&gen_context.dart_naming, &format!("StdPtr_{}", field_type.type_str));
     }
-    else if field_type.type_kind == TypeKind::StdVector {
-        if let Some(value_type) = field_type.value_type.as_ref() {
-            return format!("StdVector_{}", value_type.type_str);
-        } else {
-            return "StdVector_Unknown".to_string();
-        }
+    // std::unique_ptr 直接使用被指向的类
+    else if field_type.type_kind == TypeKind::StdUniquePtr {
+        let mut class_type = field_type.clone();
+        class_type.type_kind = TypeKind::Class;
+        return get_str_dart_fun_type(gen_context, &class_type);
     }
-    else if field_type.type_kind == TypeKind::StdMap {
-        if let (Some(key_type), Some(value_type)) = (field_type.key_type.as_ref(), field_type.value_type.as_ref()) {
-            return format!("StdMap_{}_{}", key_type.type_str, value_type.type_str);
-        } else {
-            return "StdMap_Unknown".to_string();
-        }
+    else if field_type.type_kind == TypeKind::StdWeakPtr {
+        return get_dart_type_name(&gen_context.dart_naming, &format!("StdWeakPtr_{}", field_type.type_str.replace("const ", "").replace(" ", "")));
     }
-    else if field_type.type_kind == TypeKind::StdUnorderedMap {
-        if let (Some(key_type), Some(value_type)) = (field_type.key_type.as_ref(), field_type.value_type.as_ref()) {
-            return format!("StdUnorderedMap_{}_{}", key_type.type_str, value_type.type_str);
+    // 容器类型，容器嵌套时类名也嵌套，如 StdVector_StdVector_float，和 c 中的类名保持一致
+    else if matches!(field_type.type_kind, TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque
+        | TypeKind::StdSet | TypeKind::StdUnorderedSet | TypeKind::StdOptional)
+    {
+        if field_type.value_type.is_some() {
+            return get_dart_type_name(&gen_context.dart_naming, &field_type.get_type_name_without_ptr());
         } else {
-            return "StdUnorderedMap_Unknown".to_string();
+            return format!("{:?}_Unknown", field_type.type_kind);
         }
     }
-    else if field_type.type_kind == TypeKind::StdSet {
-        if let Some(value_type) = field_type.value_type.as_ref() {
-            return format!("StdSet_{}", value_type.type_str);
+    else if matches!(field_type.type_kind, TypeKind::StdMap | TypeKind::StdMultimap | TypeKind::StdUnorderedMap) {
+        if field_type.key_type.is_some() && field_type.value_type.is_some() {
+            return get_dart_type_name(&gen_context.dart_naming, &field_type.get_type_name_without_ptr());
         } else {
-            return "StdSet_Unknown".to_string();
+            return format!("{:?}_Unknown", field_type.type_kind);
         }
     }
-    else if field_type.type_kind == TypeKind::StdUnorderedSet {
-        if let Some(value_type) = field_type.value_type.as_ref() {
-            return format!("StdUnorderedSet_{}", value_type.type_str);
-        } else {
-            return "StdUnorderedSet_Unknown".to_string();
-        }
+    else if field_type.type_kind == TypeKind::StdVariant {
+        return get_dart_type_name(&gen_context.dart_naming, &field_type.get_variant_type_str());
+    }
+    else if field_type.type_kind == TypeKind::StdPair || field_type.type_kind == TypeKind::StdTuple {
+        return get_dart_type_name(&gen_context.dart_naming, &field_type.get_tuple_type_str());
+    }
+    // std::function 对应 dart 的函数类型
+    else if field_type.type_kind == TypeKind::StdFunction {
+        let return_str = match field_type.value_type.as_ref() {
+            Some(return_type) => get_str_dart_fun_type(gen_context, return_type),
+            None => "void".to_string(),
+        };
+        let param_strs: Vec<String> = field_type.param_types.iter().map(|param_type| get_str_dart_callback_param_decl_type(gen_context, param_type)).collect();
+        return format!("{} Function({})", return_str, param_strs.join(", "));
     }
 
     // 基础数据类型
     if field_type.ptr_level == 0 {
-        if field_type.type_kind == TypeKind::String {
+        if field_type.type_kind == TypeKind::String || field_type.type_kind == TypeKind::U16String {
             return "String".to_string();
         } else {
-            return get_str_dart_api_type(field_type);
+            return get_str_dart_api_type(gen_context, field_type);
         }
     }
 
     // 基础类型的指针
-    return get_str_native_api_type(field_type);
+    get_str_native_api_type(gen_context, field_type)
 }
 
 fn get_str_dart_api(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
-    let mut cur_class_name = "";
-    if let Some(cur_class) = class {
-        cur_class_name = &cur_class.type_str;
-    }
+    let cur_class_name = class.get_ffi_class_name_or_empty();
     let ffiapi_c_method_name = format!("ffi_{}_{}", cur_class_name, method.name);
-    let native_api_params_str = get_str_native_api_params_decl(class, method);
-    let dart_api_params_str = get_str_dart_api_params_decl(class, method);
+    let native_api_params_str = get_str_native_api_params_decl(gen_context, class, method);
+    let dart_api_params_str = get_str_dart_api_params_decl(gen_context, class, method);
+
+    let native_signature = format!("{} Function({})", get_str_native_api_type(gen_context, &method.return_type), native_api_params_str);
+    let is_leaf = get_is_leaf_call(gen_context, class, method);
+    let mut dar_api_str = get_str_dart_ffi_binding(gen_context, &ffiapi_c_method_name, &ffiapi_c_method_name,
+        &native_signature, &get_str_dart_api_type(gen_context, &method.return_type), &dart_api_params_str, is_leaf);
+    // NativeFinalizer 需要析构函数的地址
+    if gen_context.dart_native_asset.is_some() && method.method_type == MethodType::Destructor {
+        dar_api_str += &format!("final ptr_{} = Native.addressOf<NativeFunction<{}>>({});
+",
+            ffiapi_c_method_name, native_signature, ffiapi_c_method_name,
+        );
+    }
+    dar_api_str
+}
+
+/// @Native 注解绑定时，每个 ffiapi 文件都需要指定默认的 asset
+fn get_str_dart_default_asset(gen_context: &GenContext) -> String {
+    match &gen_context.dart_native_asset {
+        Some(asset_id) => format!("@DefaultAsset('{}')
+library;
+", asset_id),
+        None => "".to_string(),
+    }
+}
 
-    let dar_api_str = format!("late final ptr_{} = {}_dylib.lookup<NativeFunction<{} Function({})>>('{}');
-late final {} = ptr_{}.asFunction<{} Function({})>();
+/// 生成一个 ffi 函数的 dart 绑定
+/// 默认使用 dylib.lookup 查找符号，指定了 dart_native_asset 时使用 @Native 注解的 external 函数
+/// dart_params_str 为逗号分隔的 dart 参数类型，如 "Pointer<Void>, int"
+fn get_str_dart_ffi_binding(gen_context: &GenContext, dart_fun_name: &str, symbol: &str, native_signature: &str, dart_return_str: &str, dart_params_str: &str, is_leaf: bool) -> String {
+    if gen_context.dart_native_asset.is_none() {
+        return format!("late final ptr_{} = {}_dylib.lookup<NativeFunction<{}>>('{}');
+late final {} = ptr_{}.asFunction<{} Function({})>({});
 ",
-        ffiapi_c_method_name, gen_context.module_name, get_str_native_api_type(&method.return_type), native_api_params_str, ffiapi_c_method_name,
-        ffiapi_c_method_name, ffiapi_c_method_name, get_str_dart_api_type(&method.return_type), dart_api_params_str,
-    );
-    return dar_api_str;
+            dart_fun_name, gen_context.module_name, native_signature, symbol,
+            dart_fun_name, dart_fun_name, dart_return_str, dart_params_str, if is_leaf { "isLeaf: true" } else { "" },
+        );
+    }
+    let leaf_str = if is_leaf { ", isLeaf: true" } else { "" };
+
+    // external 函数的参数需要名字，按顶层的逗号拆分参数类型
+    let mut param_types = vec![];
+    let mut depth = 0;
+    let mut cur_type = String::new();
+    for c in dart_params_str.chars() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            _ => {}
+        }
+        if c == ',' && depth == 0 {
+            param_types.push(cur_type.trim().to_string());
+            cur_type.clear();
+        } else {
+            cur_type.push(c);
+        }
+    }
+    if !cur_type.trim().is_empty() {
+        param_types.push(cur_type.trim().to_string());
+    }
+    let params_str = param_types.iter().enumerate()
+        .map(|(i, param_type)| format!("{} p{}", param_type, i))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    format!("@Native<{}>(symbol: '{}'{})
+external {} {}({});
+",
+        native_signature, symbol, leaf_str,
+        dart_return_str, dart_fun_name, params_str,
+    )
+}
+
+/// 是否使用 isLeaf 调用，isLeaf 调用不能回调 dart，但是省去了线程状态切换的开销
+/// 注释中有 @leaf / @no_leaf 标记时按标记，基础数据类型、字符串等字段和变量的 getter / setter 默认使用
+/// 其它方法的参数和返回值都是基础数据类型时使用，但是模块中有同步回调时，c++ 可能在调用中回调 dart，只使用标记的方法
+fn get_is_leaf_call(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> bool {
+    if method.method_type != MethodType::Normal {
+        return false;
+    }
+    // 回调类、std::function 类的方法会调用 dart
+    if let Some(class) = class {
+        if class.is_callback() || class.class_type == ClassType::StdFunction {
+            return false;
+        }
+    }
+    if let Some(is_leaf) = method.is_leaf {
+        return is_leaf;
+    }
+
+    let is_primitive_return = method.return_type.ptr_level == 0
+        && (method.return_type.type_kind == TypeKind::Void || method.return_type.type_kind.is_ffi_primitive());
+    let is_primitive_params = method.params.iter()
+        .all(|param| param.field_type.ptr_level == 0 && param.field_type.type_kind.is_ffi_primitive());
+    if !is_primitive_return || !is_primitive_params {
+        return false;
+    }
+
+    !gen_context.has_sync_callback
+}
+
+/// 是否有同步回调（回调类中 @callback_sync 的方法，有返回值的 std::function）
+pub fn get_has_sync_callback(hpp_elements: &Vec<HppElement>) -> bool {
+    for hpp_element in hpp_elements {
+        match hpp_element {
+            HppElement::File(file) if get_has_sync_callback(&file.children) => {
+                return true;
+            }
+            HppElement::Class(class) if (class.is_callback() || class.class_type == ClassType::StdFunction) && get_has_sync_callback(&class.children) => {
+                return true;
+            }
+            HppElement::Method(method) if method.is_sync_callback => {
+                return true;
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// 使用 isLeaf 调用的 ffi 函数，用于生成完成后的提示
+pub fn get_leaf_function_names(gen_context: &GenContext) -> Vec<String> {
+    let mut names = vec![];
+    for hpp_element in &gen_context.hpp_elements {
+        let HppElement::File(file) = hpp_element else {
+            continue;
+        };
+        for child in &file.children {
+            match child {
+                HppElement::Class(class) => {
+                    for class_child in &class.children {
+                        let methods = match class_child {
+                            HppElement::Method(method) => vec![(method.name.clone(), get_is_leaf_call(gen_context, Some(class), method))],
+                            HppElement::Field(field) => vec![
+                                (format!("get_{}", field.name), get_is_leaf_call(gen_context, Some(class), &Method::new_get_for_field(field))),
+                                (format!("set_{}", field.name), field.has_setter() && get_is_leaf_call(gen_context, Some(class), &Method::new_set_for_field(field))),
+                            ],
+                            HppElement::Variable(variable) => vec![
+                                (format!("get_{}", variable.name), get_is_leaf_call(gen_context, Some(class), &Method::new_get_for_variable(variable))),
+                                (format!("set_{}", variable.name), variable.has_setter() && get_is_leaf_call(gen_context, Some(class), &Method::new_set_for_variable(variable))),
+                            ],
+                            _ => vec![],
+                        };
+                        for (method_name, is_leaf) in methods {
+                            if is_leaf {
+                                names.push(format!("{}::{}", class.type_str, method_name));
+                            }
+                        }
+                    }
+                }
+                HppElement::Method(method) if get_is_leaf_call(gen_context, None, method) => {
+                    names.push(method.name.clone());
+                }
+                HppElement::Variable(variable) => {
+                    if get_is_leaf_call(gen_context, None, &Method::new_get_for_variable(variable)) {
+                        names.push(format!("get_{}", variable.name));
+                    }
+                    if variable.has_setter() && get_is_leaf_call(gen_context, None, &Method::new_set_for_variable(variable)) {
+                        names.push(format!("set_{}", variable.name));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    names
 }
 
 fn get_str_dart_api_for_regist_callback(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
@@ -1051,10 +2037,7 @@ fn get_str_dart_api_for_regist_callback(gen_context: &GenContext, class: Option<
     }
 
     // 独立函数和类的函数，都走下边逻辑，需要注意区分
-    let mut cur_class_name = "";
-    if let Some(cur_class) = class {
-        cur_class_name = &cur_class.type_str;
-    }
+    let cur_class_name = class.get_ffi_class_name_or_empty();
 
     // 判断是否需要同步调用（使用 is_sync_callback 标志）
     let needs_sync_call = method.is_sync_callback;
@@ -1073,21 +2056,14 @@ fn get_str_dart_api_for_regist_callback(gen_context: &GenContext, class: Option<
 
         // 生成 _register 函数的 FFI API 绑定（接受函数指针）
         let register_fun_name = format!("{}_register", native_fun_type_name);
-        let register_api = format!("late final ptr_{} = {}_dylib.lookup<NativeFunction<Void Function(Pointer<Void>, Pointer<NativeFunction<{}>>)>>('{}');
-late final {} = ptr_{}.asFunction<void Function(Pointer<Void>, Pointer<NativeFunction<{}>>)>();
-",
-            register_fun_name, gen_context.module_name, ffi_signature, register_fun_name,
-            register_fun_name, register_fun_name, ffi_signature,
+        let register_api = get_str_dart_ffi_binding(gen_context, &register_fun_name, &register_fun_name,
+            &format!("Void Function(Pointer<Void>, Pointer<NativeFunction<{}>>)", ffi_signature),
+            "void", &format!("Pointer<Void>, Pointer<NativeFunction<{}>>", ffi_signature), false,
         );
 
         // 生成 _regist 函数的 FFI API 绑定（注册 SendPort，用于异步发送请求）
         let regist_fun_name = format!("{}_regist", native_fun_type_name);
-        let regist_api = format!("late final ptr_{} = {}_dylib.lookup<NativeFunction<Void Function(Int64)>>('{}');
-late final {} = ptr_{}.asFunction<void Function(int)>();
-",
-            regist_fun_name, gen_context.module_name, regist_fun_name,
-            regist_fun_name, regist_fun_name,
-        );
+        let regist_api = get_str_dart_ffi_binding(gen_context, &regist_fun_name, &regist_fun_name, "Void Function(Int64)", "void", "int", false);
 
         return format!("{}{}", register_api, regist_api);
     } else {
@@ -1097,58 +2073,177 @@ late final {} = ptr_{}.asFunction<void Function(int)>();
         // 注册函数的名字
         let native_regist_fun_name = format!("{}_regist", native_fun_type_name);
         // 参数列表
-        let params_str = get_str_native_api_params_decl(class, method);
+        let params_str = get_str_native_api_params_decl(gen_context, class, method);
 
         let dart_api_str = format!("typedef {} = {} Function({});
-late final ptr_{} = {}_dylib.lookup<NativeFunction<Void Function(Int64)>>('{}');
-late final {} = ptr_{}.asFunction<void Function(int)>();
-",
-            native_fun_type_name, get_str_native_api_type(&method.return_type), params_str,
-            native_regist_fun_name, gen_context.module_name, native_regist_fun_name,
-            native_regist_fun_name, native_regist_fun_name,
+{}",
+            native_fun_type_name, get_str_native_api_type(gen_context, &method.return_type), params_str,
+            get_str_dart_ffi_binding(gen_context, &native_regist_fun_name, &native_regist_fun_name, "Void Function(Int64)", "void", "int", false),
         );
 
         return dart_api_str;
     }
 }
 
+/// std::function 的 dart 类，保存 dart 闭包，c++ 中通过闭包的 id 调用
+fn get_str_dart_stdfunction_class(gen_context: &GenContext, class: &Class) -> String {
+    let Some(HppElement::Method(call_method)) = class.children.first() else {
+        return "".to_string();
+    };
+    let dart_class_name = class.get_dart_name(&gen_context.dart_naming);
+    let ffi_class_name = class.get_ffi_name();
+    let mut function_type = call_method.return_type.clone();
+    function_type.type_kind = TypeKind::StdFunction;
+    function_type.value_type = Some(Box::new(call_method.return_type.clone()));
+    function_type.param_types = call_method.params.iter().map(|param| param.field_type.clone()).collect();
+    let dart_fun_type = get_str_dart_fun_type(gen_context, &function_type);
+    let param_strs: Vec<&str> = call_method.params.iter().map(|param| param.field_type.full_str.as_str()).collect();
+    let cpp_function_type = format!("std::function<{}({})>", call_method.return_type.full_str, param_strs.join(", "));
+
+    // 调用 dart 闭包
+    let (call_impl, regist_str) = if call_method.is_sync_callback {
+        let mut ffi_param_strs = vec!["int closureId".to_string()];
+        let mut ffi_type_params = vec!["Int64".to_string()];
+        let mut call_param_strs = Vec::new();
+        for param in &call_method.params {
+            ffi_param_strs.push(format!("int {}_raw", param.name));
+            ffi_type_params.push("Int64".to_string());
+            call_param_strs.push(get_str_dart_sync_callback_param(gen_context, &param.field_type, &format!("{}_raw", param.name)));
+        }
+        let call_impl = format!("
+    // 供 c++ 通过函数指针同步调用
+    static int _call({}) {{
+        final closure = _closures[closureId];
+        if (closure == null) {{
+            return 0;  // 默认返回值
+        }}
+        final result = closure({});
+        {}
+    }}
+",
+            ffi_param_strs.join(", "),
+            call_param_strs.join(", "),
+            get_str_dart_sync_callback_return(&call_method.return_type));
+        let regist_str = format!("FFI_{}_register(Pointer.fromFunction<Int64 Function({})>(_call, 0), _releasePort.sendPort.nativePort);",
+            ffi_class_name, ffi_type_params.join(", "));
+        (call_impl, regist_str)
+    } else {
+        let call_impl = format!("
+    // 异步调用，args 为 [closureId, 参数...]
+    static final _callPort = ReceivePort()..listen((args) {{
+        _closures[args[0]]?.call({});
+    }});
+",
+            get_str_port_fun_params_impl(gen_context, None, call_method));
+        let regist_str = format!("FFI_{}_regist(_callPort.sendPort.nativePort, _releasePort.sendPort.nativePort);", ffi_class_name);
+        (call_impl, regist_str)
+    };
+
+    format!("
+/// {}
+class {} {{
+    static final Map<int, {}> _closures = {{}};
+    static int _nextClosureId = 1;
+    static bool _isRegisted = false;
+{}
+    // c++ 中的 std::function 都释放后，释放 dart 闭包
+    static final _releasePort = ReceivePort()..listen((closureId) {{
+        _closures.remove(closureId);
+    }});
+
+    /**
+     * 保存 dart 闭包，返回传递给 native 的闭包 id，null 对应 0
+     */
+    static int toNative({}? closure) {{
+        if (closure == null) {{
+            return 0;
+        }}
+        if (!_isRegisted) {{
+            _isRegisted = true;
+            {}
+        }}
+        final closureId = _nextClosureId++;
+        _closures[closureId] = closure;
+        return closureId;
+    }}
+
+    /**
+     * 根据闭包 id 取回 dart 闭包，空的 std::function 或者不是 dart 闭包时返回 null
+     */
+    static {}? fromNative(int closureId) => _closures[closureId];
+}}
+
+",
+        cpp_function_type,
+        dart_class_name,
+        dart_fun_type,
+        call_impl,
+        dart_fun_type,
+        regist_str,
+        dart_fun_type)
+}
+
+/// std::function 注册 dart 闭包调用、释放的接口
+fn get_str_dart_api_for_stdfunction(gen_context: &GenContext, class: &Class) -> String {
+    let Some(HppElement::Method(call_method)) = class.children.first() else {
+        return "".to_string();
+    };
+    let ffi_class_name = class.get_ffi_name();
+
+    if call_method.is_sync_callback {
+        let mut ffi_type_params = vec!["Int64".to_string()];
+        for _param in &call_method.params {
+            ffi_type_params.push("Int64".to_string());
+        }
+        let ffi_signature = format!("Int64 Function({})", ffi_type_params.join(", "));
+        let register_fun_name = format!("FFI_{}_register", ffi_class_name);
+        format!("{}\n", get_str_dart_ffi_binding(gen_context, &register_fun_name, &register_fun_name,
+            &format!("Void Function(Pointer<NativeFunction<{}>>, Int64)", ffi_signature),
+            "void", &format!("Pointer<NativeFunction<{}>>, int", ffi_signature), false,
+        ))
+    } else {
+        let regist_fun_name = format!("FFI_{}_regist", ffi_class_name);
+        format!("{}\n", get_str_dart_ffi_binding(gen_context, &regist_fun_name, &regist_fun_name, "Void Function(Int64, Int64)", "void", "int, int", false))
+    }
+}
+
 /// 生成 native callback 函数签名（用于 FFI API 中的 NativeFunction 类型）
 /// 例如：Int64 Function(Pointer<Void>, Int64, Int64)
-fn get_str_native_callback_function_signature(class: Option<&Class>, method: &Method) -> String {
+fn get_str_native_callback_function_signature(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     let mut param_strs = vec!["Pointer<Void>".to_string()];
 
     // 添加方法参数
     for param in &method.params {
-        param_strs.push(get_str_native_api_type(&param.field_type));
+        param_strs.push(get_str_native_api_type(gen_context, &param.field_type));
     }
 
     format!("{} Function({})",
-        get_str_native_api_type(&method.return_type),
+        get_str_native_api_type(gen_context, &method.return_type),
         param_strs.join(", ")
     )
 }
 
 /// 返回dart api中的参数列表
-fn get_str_dart_api_params_decl(class: Option<&Class>, method: &Method) -> String {
+fn get_str_dart_api_params_decl(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     let mut param_strs = Vec::new();
     if gen_c::get_is_need_first_class_param(class, method) {
         param_strs.push("Pointer<Void>".to_string());
     }
     for param in &method.params {
-        param_strs.push(format!("{}", get_str_dart_api_type(&param.field_type)));
+        param_strs.push(get_str_dart_api_type(gen_context, &param.field_type));
     }
 
     return param_strs.join(", ");
 }
 
-fn get_str_dart_api_type(field_type: &FieldType) -> String {
+fn get_str_dart_api_type(gen_context: &GenContext, field_type: &FieldType) -> String {
     // 基础数据类型
     if field_type.ptr_level == 0 {
         match field_type.type_kind {
             TypeKind::Void => {
                 return "void".to_string();
             }
-            TypeKind::Int64 => {
+            ref kind if kind.is_integer() => {
                 return "int".to_string();
             }
             TypeKind::Float => {
@@ -1170,16 +2265,26 @@ fn get_str_dart_api_type(field_type: &FieldType) -> String {
             TypeKind::String => {
                 return "Pointer<Utf8>".to_string();
             }
+            TypeKind::U16String => {
+                return "Pointer<Uint16>".to_string();
+            }
+            TypeKind::StdDuration | TypeKind::StdTimePoint => {
+                return "int".to_string();
+            }
             TypeKind::Class => {
                 return "Pointer<Void>".to_string();
             }
+            TypeKind::PodStruct => {
+                // 按值传递的 Struct
+                return get_dart_type_name(&gen_context.dart_naming, &field_type.type_str);
+            }
             TypeKind::StdPtr => {
                 return "Pointer<Void>".to_string();
             }
-            TypeKind::StdVector => {
+            TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque => {
                 return "Pointer<Void>".to_string();
             }
-            TypeKind::StdMap => {
+            TypeKind::StdMap | TypeKind::StdMultimap => {
                 return "Pointer<Void>".to_string();
             }
             TypeKind::StdSet => {
@@ -1191,6 +2296,22 @@ fn get_str_dart_api_type(field_type: &FieldType) -> String {
             TypeKind::StdUnorderedMap => {
                 return "Pointer<Void>".to_string();
             }
+            TypeKind::StdOptional => {
+                return "Pointer<Void>".to_string();
+            }
+            TypeKind::StdVariant => {
+                return "Pointer<Void>".to_string();
+            }
+            TypeKind::StdPair | TypeKind::StdTuple => {
+                return "Pointer<Void>".to_string();
+            }
+            TypeKind::StdUniquePtr | TypeKind::StdWeakPtr => {
+                return "Pointer<Void>".to_string();
+            }
+            TypeKind::StdFunction => {
+                // dart 闭包的 id
+                return "int".to_string();
+            }
             _ => {
                 unimplemented!("get_dart_fun_type_str: unknown type kind, {:?}", field_type);
             }
@@ -1202,8 +2323,8 @@ fn get_str_dart_api_type(field_type: &FieldType) -> String {
         return "Pointer<Void>".to_string();
     }
 
-    // 基础类型的指针
-    return get_str_native_api_type(field_type);
+    // 基础类型、POD 结构体的指针
+    get_str_native_api_type(gen_context, field_type)
 }
 
 /// Pointer.fromFunction 对于有返回值的函数，必须有个默认值，否则无法编译
@@ -1214,7 +2335,7 @@ fn get_str_dart_api_exception_default_value(field_type: &FieldType) -> String {
             TypeKind::Void => {
                 return "".to_string();
             }
-            TypeKind::Int64 => {
+            ref kind if kind.is_integer() => {
                 return "0".to_string();
             }
             TypeKind::Float => {
@@ -1229,9 +2350,16 @@ fn get_str_dart_api_exception_default_value(field_type: &FieldType) -> String {
             TypeKind::Bool => {
                 return "false".to_string();
             }
+            TypeKind::StdDuration | TypeKind::StdTimePoint => {
+                return "0".to_string();
+            }
             TypeKind::Class => {
                 return "nullptr".to_string();
             }
+            TypeKind::StdFunction => {
+                // dart 侧类型本身可空，不需要默认值
+                return "".to_string();
+            }
             _ => {
                 unimplemented!("get_dart_fun_type_str: unknown type kind, {:?}", field_type);
             }
@@ -1243,28 +2371,28 @@ fn get_str_dart_api_exception_default_value(field_type: &FieldType) -> String {
 }
 
 /// 返回native api中的参数列表
-fn get_str_native_api_params_decl(class: Option<&Class>, method: &Method) -> String {
+fn get_str_native_api_params_decl(gen_context: &GenContext, class: Option<&Class>, method: &Method) -> String {
     let mut param_strs = Vec::new();
     if gen_c::get_is_need_first_class_param(class, method) {
         param_strs.push("Pointer<Void>".to_string());
     }
     for param in &method.params {
-        param_strs.push(format!("{}", get_str_native_api_type(&param.field_type)));
+        param_strs.push(get_str_native_api_type(gen_context, &param.field_type));
     }
 
     return param_strs.join(", ");
 }
 
-fn get_str_native_api_type(field_type: &FieldType) -> String {
+fn get_str_native_api_type(gen_context: &GenContext, field_type: &FieldType) -> String {
     // 基础数据类型
     if field_type.ptr_level == 0 {
+        if let Some(native_type) = get_str_native_integer_type(&field_type.type_kind) {
+            return native_type.to_string();
+        }
         match field_type.type_kind {
             TypeKind::Void => {
                 return "Void".to_string();
             }
-            TypeKind::Int64 => {
-                return "Int64".to_string();
-            }
             TypeKind::Float => {
                 return "Float".to_string();
             }
@@ -1278,22 +2406,32 @@ fn get_str_native_api_type(field_type: &FieldType) -> String {
                 return "Bool".to_string();
             }
             TypeKind::Enum => {
-                // 枚举类型在 Native API 中使用 Int64 表示
-                return "Int64".to_string();
+                // 枚举类型在 C FFI 中使用 int 表示
+                return "Int32".to_string();
             }
             TypeKind::String => {
                 return "Pointer<Utf8>".to_string();
             }
+            TypeKind::U16String => {
+                return "Pointer<Uint16>".to_string();
+            }
+            TypeKind::StdDuration | TypeKind::StdTimePoint => {
+                return "Int64".to_string();
+            }
             TypeKind::Class => {
                 return "Pointer<Void>".to_string();
             }
+            TypeKind::PodStruct => {
+                // 按值传递的 Struct
+                return get_dart_type_name(&gen_context.dart_naming, &field_type.type_str);
+            }
             TypeKind::StdPtr => {
                 return "Pointer<Void>".to_string();
             }
-            TypeKind::StdVector => {
+            TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque => {
                 return "Pointer<Void>".to_string();
             }
-            TypeKind::StdMap => {
+            TypeKind::StdMap | TypeKind::StdMultimap => {
                 return "Pointer<Void>".to_string();
             }
             TypeKind::StdSet => {
@@ -1305,6 +2443,22 @@ fn get_str_native_api_type(field_type: &FieldType) -> String {
             TypeKind::StdUnorderedMap => {
                 return "Pointer<Void>".to_string();
             }
+            TypeKind::StdOptional => {
+                return "Pointer<Void>".to_string();
+            }
+            TypeKind::StdVariant => {
+                return "Pointer<Void>".to_string();
+            }
+            TypeKind::StdPair | TypeKind::StdTuple => {
+                return "Pointer<Void>".to_string();
+            }
+            TypeKind::StdUniquePtr | TypeKind::StdWeakPtr => {
+                return "Pointer<Void>".to_string();
+            }
+            TypeKind::StdFunction => {
+                // dart 闭包的 id
+                return "Int64".to_string();
+            }
             TypeKind::Ignored => {
                 // 被忽略的类型不应该出现在公开 API 中
                 // 如果出现了，说明有方法使用了不应该暴露的类型
@@ -1319,15 +2473,18 @@ fn get_str_native_api_type(field_type: &FieldType) -> String {
     if field_type.type_kind == TypeKind::Class {
         return "Pointer<Void>".to_string();
     }
+    // POD 结构体的指针，只支持一级指针
+    if field_type.type_kind == TypeKind::PodStruct {
+        return format!("Pointer<{}>", get_dart_type_name(&gen_context.dart_naming, &field_type.type_str));
+    }
 
-    // 基础类型的指针
+    // 基础类型的指针，其它类型的指针在 dart 中不能直接访问，作为 Pointer<Void> 传递
     let base_native = match field_type.type_kind {
-        TypeKind::Void => "Void",
-        TypeKind::Int64 => "Int64",
         TypeKind::Float => "Float",
         TypeKind::Double => "Double",
         TypeKind::Char => "Int8",
-        _ => unimplemented!("get_native_fun_type_str: unknown type kind in pointer"),
+        TypeKind::Bool => "Bool",
+        ref kind => get_str_native_integer_type(kind).unwrap_or("Void"),
     };
     let mut native_type = base_native.to_string();
     for _ in 0..field_type.ptr_level {
@@ -1336,104 +2493,560 @@ fn get_str_native_api_type(field_type: &FieldType) -> String {
     return native_type
 }
 
-/// 收集文件中所有引用的外部类型
-fn collect_referenced_types_from_file(file: &File, referenced_types: &mut Vec<String>) {
-    for child in &file.children {
-        collect_referenced_types_from_element(child, referenced_types);
+/// POD 结构体生成 dart 的 Struct，字段直接读写结构体的内存，
+/// create 在 dart 堆上创建，作为参数、返回值时按值复制
+fn get_str_dart_pod_struct(gen_context: &GenContext, class: &Class) -> String {
+    let struct_name = class.get_dart_name(&gen_context.dart_naming);
+    let mut fields_str = String::new();
+    let mut create_params = vec![];
+    let mut create_assigns = vec![];
+    for child in &class.children {
+        let HppElement::Field(field) = child else {
+            continue;
+        };
+        if let Some(comment) = &field.comment_str {
+            fields_str.push_str(&format!("    {}\n", comment));
+        }
+        let field_dart_type = get_str_dart_api_type(gen_context, &field.field_type);
+        // Struct 按字段的顺序布局，dart 中的字段名不影响内存布局，名字冲突时使用 c++ 中的字段名
+        let property_name = get_dart_field_property_name(gen_context, class, field).unwrap_or_else(|| field.name.clone());
+        if field.field_type.type_kind == TypeKind::PodStruct {
+            // 嵌套的结构体没有默认值，不传时保持全 0
+            fields_str.push_str(&format!("    external {} {};\n\n", field_dart_type, property_name));
+            create_params.push(format!("{}? {}", field_dart_type, property_name));
+            create_assigns.push(format!("        if ({} != null) {{
+            result.{} = {};
+        }}", property_name, property_name, property_name));
+            continue;
+        }
+        let (native_annotation, default_value) = match field.field_type.type_kind {
+            TypeKind::Char => ("Char", "0"),
+            TypeKind::Bool => ("Bool", "false"),
+            TypeKind::Float => ("Float", "0.0"),
+            TypeKind::Double => ("Double", "0.0"),
+            ref kind => (get_str_native_integer_type(kind).unwrap_or("Int64"), "0"),
+        };
+        fields_str.push_str(&format!("    @{}()\n    external {} {};\n\n", native_annotation, field_dart_type, property_name));
+        create_params.push(format!("{} {} = {}", field_dart_type, property_name, default_value));
+        create_assigns.push(format!("        result.{} = {};", property_name, property_name));
     }
+
+    format!("{}
+final class {} extends Struct {{
+{}    factory {}.create({{{}}}) {{
+        final result = Struct.create<{}>();
+{}
+        return result;
+    }}
+}}
+
+",
+        class.comment_str.as_deref().unwrap_or_default(),
+        struct_name,
+        fields_str,
+        struct_name, create_params.join(", "),
+        struct_name,
+        create_assigns.join("\n"),
+    )
 }
 
-/// 递归收集元素中引用的类型
-fn collect_referenced_types_from_element(element: &HppElement, referenced_types: &mut Vec<String>) {
-    match element {
+/// 数据类包含的字段和 dart 中的属性名，类不能生成数据类时返回 None
+/// 普通类需要有默认构造函数，所有字段都需要可以读写，并且是基础数据类型、字符串或有数据类的类（按值），
+/// 否则 toNative 创建的对象会丢失不支持的字段的值
+fn get_dart_data_class_fields<'a>(gen_context: &GenContext, class: &'a Class) -> Option<Vec<(&'a Field, String)>> {
+    match class.class_type {
+        ClassType::PodStruct => {}
+        ClassType::Normal => {
+            let has_default_constructor = class.children.iter().any(|child| matches!(child,
+                HppElement::Method(method) if method.method_type == MethodType::Constructor && method.params.is_empty()));
+            if !has_default_constructor {
+                return None;
+            }
+        }
+        _ => return None,
+    }
+
+    let mut fields = vec![];
+    for child in &class.children {
+        let HppElement::Field(field) = child else {
+            continue;
+        };
+        let field_type = &field.field_type;
+        if !field.has_setter() || field_type.ptr_level != 0 || field_type.is_reference || field_type.array_len.is_some() {
+            return None;
+        }
+        let is_data_field = match field_type.type_kind {
+            ref kind if kind.is_ffi_primitive() => true,
+            TypeKind::String | TypeKind::U16String => true,
+            TypeKind::Class | TypeKind::PodStruct => gen_context.find_class(&field_type.type_str)
+                .is_some_and(|field_class| get_dart_data_class_fields(gen_context, field_class).is_some()),
+            _ => false,
+        };
+        if !is_data_field {
+            return None;
+        }
+        // 属性名和其它成员冲突时只有 get_ / set_ 方法，也和数据类自己的方法冲突
+        let property_name = get_dart_field_property_name(gen_context, class, field)?;
+        if DART_DATA_CLASS_MEMBERS.contains(&property_name.as_str()) {
+            return None;
+        }
+        fields.push((field, property_name));
+    }
+    if fields.is_empty() {
+        return None;
+    }
+    Some(fields)
+}
+
+
+/// 数据类自己的成员，字段的属性名不能和它们相同
+const DART_DATA_CLASS_MEMBERS: &[&str] = &["fromNative", "toNative", "copyWith"];
+
+/// 类对应的不可变数据类，用于状态管理、比较是否需要刷新等，通过 fromNative / toNative 和 native 对象互相转换
+fn get_str_dart_data_class(gen_context: &GenContext, class: &Class) -> String {
+    let Some(fields) = get_dart_data_class_fields(gen_context, class) else {
+        return "".to_string();
+    };
+    let class_name = class.get_dart_name(&gen_context.dart_naming);
+    let data_class_name = format!("{}Data", class_name);
+    // 普通类使用只读视图，const 引用返回的对象也可以转换
+    let (native_type, native_create) = if class.class_type == ClassType::PodStruct {
+        (class_name.clone(), format!("{}.create()", class_name))
+    } else {
+        (format!("{}View", class_name), format!("{}.Constructor()", class_name))
+    };
+
+    let mut field_decls = vec![];
+    let mut ctor_params = vec![];
+    let mut from_native_args = vec![];
+    let mut to_native_stmts = vec![];
+    let mut copy_with_params = vec![];
+    let mut copy_with_args = vec![];
+    let mut equal_strs = vec![];
+    let mut hash_strs = vec![];
+    let mut to_string_strs = vec![];
+    for (field, name) in &fields {
+        let is_nested = matches!(field.field_type.type_kind, TypeKind::Class | TypeKind::PodStruct);
+        let field_type_str = if is_nested {
+            format!("{}Data", get_dart_type_name(&gen_context.dart_naming, &field.field_type.type_str))
+        } else {
+            get_str_dart_fun_decl_type(gen_context, &field.field_type)
+        };
+        let get_expr = format!("native.{}", name);
+        let (from_native_value, to_native_value) = if is_nested {
+            (format!("{}.fromNative({})", field_type_str, get_expr), format!("{}.toNative()", name))
+        } else {
+            (get_expr, name.to_string())
+        };
+
+        field_decls.push(format!("    final {} {};", field_type_str, name));
+        ctor_params.push(format!("required this.{}", name));
+        from_native_args.push(format!("{}: {}", name, from_native_value));
+        to_native_stmts.push(format!("        native.{} = {};", name, to_native_value));
+        copy_with_params.push(format!("{}? {}", field_type_str, name));
+        copy_with_args.push(format!("{}: {} ?? this.{}", name, name, name));
+        equal_strs.push(format!("other.{} == {}", name, name));
+        hash_strs.push(name.to_string());
+        to_string_strs.push(format!("{}: ${}", name, name));
+    }
+
+    format!("
+/// {} 的不可变数据类
+class {} {{
+{}
+
+    const {}({{{}}});
+
+    /// 读取 native 对象所有字段的快照
+    factory {}.fromNative({} native) {{
+        return {}({});
+    }}
+
+    /// 创建新的 native 对象并设置所有字段
+    {} toNative() {{
+        final native = {};
+{}
+        return native;
+    }}
+
+    {} copyWith({{{}}}) {{
+        return {}({});
+    }}
+
+    @override
+    bool operator ==(Object other) {{
+        return identical(this, other) || (other is {} && {});
+    }}
+
+    @override
+    int get hashCode => Object.hashAll([{}]);
+
+    @override
+    String toString() => '{}({})';
+}}
+
+",
+        class_name,
+        data_class_name,
+        field_decls.join("\n"),
+        data_class_name, ctor_params.join(", "),
+        data_class_name, native_type,
+        data_class_name, from_native_args.join(", "),
+        class_name,
+        native_create,
+        to_native_stmts.join("\n"),
+        data_class_name, copy_with_params.join(", "),
+        data_class_name, copy_with_args.join(", "),
+        data_class_name, equal_strs.join(" && "),
+        hash_strs.join(", "),
+        data_class_name, to_string_strs.join(", "),
+    )
+}
+
+/// ffiapi 中按值传递的 POD 结构体定义在类的 dart 文件中，需要导入
+fn get_str_dart_pod_struct_imports(gen_context: &GenContext, file: &File) -> String {
+    let mut referenced_types = Vec::new();
+    collect_referenced_types_from_file(gen_context, file, &mut referenced_types);
+
+    let mut import_files = vec![];
+    for hpp_element in &gen_context.hpp_elements {
+        let HppElement::File(pod_struct_file) = hpp_element else {
+            continue;
+        };
+        let is_referenced = pod_struct_file.children.iter().any(|child| matches!(child,
+            HppElement::Class(class) if class.class_type == ClassType::PodStruct && referenced_types.contains(&class.get_dart_name(&gen_context.dart_naming))));
+        let Some(file_stem) = Path::new(&pod_struct_file.path).file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if is_referenced && !import_files.contains(&file_stem.to_string()) {
+            import_files.push(file_stem.to_string());
+        }
+    }
+
+    let mut imports_str = String::new();
+    for import_file in import_files {
+        imports_str.push_str(&format!("import '{}.dart';\n", import_file));
+    }
+    imports_str
+}
+
+/// 常量：生成 dart const，类的静态成员常量生成在类中（static），全局常量生成为顶层声明
+fn get_str_dart_constant(gen_context: &GenContext, class: Option<&Class>, constant: &Constant) -> String {
+    let (indent, static_modifier) = if class.is_some() { ("    ", "static ") } else { ("", "") };
+    let (dart_type, literal) = match &constant.value {
+        ConstantValue::Int(int_value) => ("int", int_value.to_string()),
+        // dart 的 int 为 64 位有符号整数，超出范围的无符号整数使用十六进制字面量，位模式和 c++ 中相同
+        ConstantValue::UInt(uint_value) if i64::try_from(*uint_value).is_ok() => ("int", uint_value.to_string()),
+        ConstantValue::UInt(uint_value) => ("int", format!("0x{:X}", uint_value)),
+        ConstantValue::Float(float_value) => ("double", float_value.clone()),
+        ConstantValue::Bool(bool_value) => ("bool", bool_value.to_string()),
+        ConstantValue::String(string_value) => ("String", get_str_dart_string_literal(string_value)),
+    };
+
+    let mut constant_str = "".to_string();
+    if let Some(comment) = &constant.comment_str {
+        constant_str.push_str(&format!("{}{}\n", indent, comment));
+    }
+    let constant_name = get_dart_member_name(&gen_context.dart_naming, &constant.name, gen_context.dart_naming.lower_camel_members);
+    constant_str.push_str(&format!("{}{}const {} {} = {};\n", indent, static_modifier, dart_type, constant_name, literal));
+    if class.is_none() {
+        constant_str.push('\n');
+    }
+
+    constant_str
+}
+
+/// 类的字段：生成 lowerCamelCase 的 getter / setter 属性，ffi 函数仍然使用字段名，如 ffi_Item_get_item_name
+/// const 字段、string_view、const char* 字段只有 getter，std::function 字段只有 setter
+fn get_str_dart_field(gen_context: &GenContext, class: Option<&Class>, field: &Field) -> String {
+    let Some(property_name) = class.and_then(|cur_class| get_dart_field_property_name(gen_context, cur_class, field)) else {
+        return get_str_dart_field_methods(gen_context, class, field);
+    };
+
+    let mut field_str = "".to_string();
+    if let Some(comment) = &field.comment_str {
+        field_str.push_str(&format!("    {}\n", comment));
+    }
+    // get
+    let get_method = Method::new_get_for_field(field);
+    field_str.push_str(&format!("    {} get {} {{
+        {}
+    }}
+",
+        get_str_dart_fun_decl_type(gen_context, &get_method.return_type), property_name,
+        get_str_dart_fun_body(gen_context, class, &get_method),
+    ));
+    // set
+    if field.has_setter() {
+        let set_method = get_dart_named_method(gen_context, class, &Method::new_set_for_field(field));
+        field_str.push_str(&format!("    set {}({}) {{
+        {}
+    }}
+",
+            property_name, get_str_dart_fun_params_decl(gen_context, class, &set_method),
+            get_str_dart_fun_body(gen_context, class, &set_method),
+        ));
+    }
+    field_str.push('\n');
+
+    field_str
+}
+
+/// 属性名冲突时，字段仍然生成 get_xxx() / set_xxx() 方法
+fn get_str_dart_field_methods(gen_context: &GenContext, class: Option<&Class>, field: &Field) -> String {
+    let mut field_str = "".to_string();
+    // get
+    let get_method = Method::new_get_for_field(field);
+    field_str.push_str(&format!("{}\n", get_str_dart_fun(gen_context, class, &get_method)));
+    // set，string_view、const char* 字段只读
+    if field.has_setter() {
+        let set_method = Method::new_set_for_field(field);
+        field_str.push_str(&format!("{}\n", get_str_dart_fun(gen_context, class, &set_method)));
+    }
+
+    field_str
+}
+
+/// 生成的 dart 类中固定的成员，字段的属性名不能和它们相同
+const DART_CLASS_RESERVED_MEMBERS: [&str; 14] = [
+    "getNativePtr", "nativeLifecycleLink", "nativeLifecycleUnlink", "nativeRelease", "FromNative", "nativeToObjMap",
+    "_nativePtr", "_nativePtrOrNull", "_isNativeOwner", "_finalizer",
+    "hashCode", "toString", "runtimeType", "noSuchMethod",
+];
+
+/// 字段在 dart 中的属性名，和类中其它方法、字段、静态成员的 dart 名字冲突时为 None，
+/// 如字段 name 和方法 name()，字段 item_name 和方法 itemName()，此时字段仍然生成 get_xxx() / set_xxx() 方法
+fn get_dart_field_property_name(gen_context: &GenContext, class: &Class, field: &Field) -> Option<String> {
+    let property_name = get_str_dart_property_name(gen_context, &field.name);
+    let is_conflict = |member_name: &str| member_name == property_name;
+    if DART_CLASS_RESERVED_MEMBERS.iter().any(|member_name| is_conflict(member_name)) {
+        return None;
+    }
+    for child in &class.children {
+        let member_name = match child {
+            HppElement::Field(other_field) if other_field.name != field.name => get_str_dart_property_name(gen_context, &other_field.name),
+            HppElement::Method(method) if method.method_type == MethodType::Constructor => method.name.clone(),
+            HppElement::Method(method) => get_str_dart_fun_name(gen_context, Some(class), method),
+            HppElement::Variable(variable) => get_dart_member_name(&gen_context.dart_naming, &variable.name, gen_context.dart_naming.lower_camel_members),
+            HppElement::Constant(constant) => get_dart_member_name(&gen_context.dart_naming, &constant.name, gen_context.dart_naming.lower_camel_members),
+            _ => continue,
+        };
+        if is_conflict(&member_name) {
+            return None;
+        }
+    }
+    Some(property_name)
+}
+
+/// 字段在 dart 中的属性名，总是转换为 lowerCamelCase，如 item_name -> itemName，见 get_dart_member_name
+fn get_str_dart_property_name(gen_context: &GenContext, field_name: &str) -> String {
+    get_dart_member_name(&gen_context.dart_naming, field_name, true)
+}
+
+/// 全局变量、类的静态成员变量：生成 getter / setter，类中为 static，全局变量为顶层声明
+fn get_str_dart_variable(gen_context: &GenContext, class: Option<&Class>, variable: &Variable) -> String {
+    let (indent, static_modifier) = if class.is_some() { ("    ", "static ") } else { ("", "") };
+    let dart_type = get_str_dart_fun_decl_type(gen_context, &variable.field_type);
+    let variable_name = get_dart_member_name(&gen_context.dart_naming, &variable.name, gen_context.dart_naming.lower_camel_members);
+
+    let mut variable_str = "".to_string();
+    if let Some(comment) = &variable.comment_str {
+        variable_str.push_str(&format!("{}{}\n", indent, comment));
+    }
+    // get
+    let get_method = Method::new_get_for_variable(variable);
+    variable_str.push_str(&format!("{}{}{} get {} {{
+{}    {}
+{}}}
+",
+        indent, static_modifier, dart_type, variable_name,
+        indent, get_str_dart_fun_body(gen_context, class, &get_method),
+        indent,
+    ));
+    // set，const 变量、string_view、const char* 变量没有 setter
+    if variable.has_setter() {
+        let set_method = Method::new_set_for_variable(variable);
+        variable_str.push_str(&format!("{}{}set {}({} {}) {{
+{}    {}
+{}}}
+",
+            indent, static_modifier, variable_name, dart_type, variable.name,
+            indent, get_str_dart_fun_body(gen_context, class, &set_method),
+            indent,
+        ));
+    }
+    variable_str.push('\n');
+
+    variable_str
+}
+
+/// dart 字符串字面量，转义 \、"、$ 和控制字符
+fn get_str_dart_string_literal(value: &str) -> String {
+    let mut literal = "\"".to_string();
+    for c in value.chars() {
+        match c {
+            '\\' => literal.push_str("\\\\"),
+            '"' => literal.push_str("\\\""),
+            '$' => literal.push_str("\\$"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            _ => literal.push(c),
+        }
+    }
+    literal.push('"');
+
+    literal
+}
+
+/// 整数类型对应的 dart ffi NativeType，不是整数类型时返回 None
+fn get_str_native_integer_type(type_kind: &TypeKind) -> Option<&'static str> {
+    let native_type = match type_kind {
+        TypeKind::Int8 => "Int8",
+        TypeKind::Int16 => "Int16",
+        TypeKind::Int32 => "Int32",
+        TypeKind::Int64 => "Int64",
+        TypeKind::UInt8 => "Uint8",
+        TypeKind::UInt16 => "Uint16",
+        TypeKind::UInt32 => "Uint32",
+        TypeKind::UInt64 => "Uint64",
+        _ => return None,
+    };
+    Some(native_type)
+}
+
+/// 收集文件中所有引用的外部类型
+fn collect_referenced_types_from_file(gen_context: &GenContext, file: &File, referenced_types: &mut Vec<String>) {
+    for child in &file.children {
+        collect_referenced_types_from_element(gen_context, child, referenced_types);
+    }
+}
+
+/// 递归收集元素中引用的类型
+fn collect_referenced_types_from_element(gen_context: &GenContext, element: &HppElement, referenced_types: &mut Vec<String>) {
+    match element {
         HppElement::Class(class) => {
             for child in &class.children {
-                collect_referenced_types_from_element(child, referenced_types);
+                collect_referenced_types_from_element(gen_context, child, referenced_types);
             }
         },
         HppElement::Method(method) => {
             // 收集返回类型
-            collect_referenced_types_from_field_type(&method.return_type, referenced_types);
+            collect_referenced_types_from_field_type(gen_context, &method.return_type, referenced_types);
             // 收集参数类型
             for param in &method.params {
-                collect_referenced_types_from_field_type(&param.field_type, referenced_types);
+                collect_referenced_types_from_field_type(gen_context, &param.field_type, referenced_types);
             }
         },
         HppElement::Field(field) => {
-            collect_referenced_types_from_field_type(&field.field_type, referenced_types);
+            collect_referenced_types_from_field_type(gen_context, &field.field_type, referenced_types);
+        },
+        HppElement::Variable(variable) => {
+            collect_referenced_types_from_field_type(gen_context, &variable.field_type, referenced_types);
         },
         _ => {}
     }
 }
 
 /// 从字段类型中收集引用的类型
-fn collect_referenced_types_from_field_type(field_type: &FieldType, referenced_types: &mut Vec<String>) {
+fn collect_referenced_types_from_field_type(gen_context: &GenContext, field_type: &FieldType, referenced_types: &mut Vec<String>) {
     match field_type.type_kind {
-        TypeKind::Class => {
+        TypeKind::Class | TypeKind::PodStruct => {
             let clean_type = field_type.type_str
                 .replace("const ", "")
                 .replace("const&", "")
                 .replace("&", "")
                 .replace("*", "")
-                .replace(" ", "")
-                .replace("::", "");
+                .replace(" ", "");
             
             // 排除string类型和基本类型
-            if clean_type != "stdstring" && clean_type != "string" && clean_type != "" && !referenced_types.contains(&clean_type) {
-                referenced_types.push(clean_type);
+            if clean_type != "std::string" && clean_type != "string" && !clean_type.is_empty() {
+                let dart_type = get_dart_type_name(&gen_context.dart_naming, &clean_type);
+                if !referenced_types.contains(&dart_type) {
+                    referenced_types.push(dart_type);
+                }
             }
         },
         TypeKind::StdPtr => {
-            let ptr_type = format!("StdPtr_{}", field_type.type_str);
+            let ptr_type = get_dart_type_name(&gen_context.dart_naming, &format!("StdPtr_{}", field_type.type_str));
             if !referenced_types.contains(&ptr_type) {
                 referenced_types.push(ptr_type);
             }
             // 也收集基础类型
-            if !referenced_types.contains(&field_type.type_str) {
-                referenced_types.push(field_type.type_str.clone());
+            let base_type = get_dart_type_name(&gen_context.dart_naming, &field_type.type_str);
+            if !referenced_types.contains(&base_type) {
+                referenced_types.push(base_type);
             }
         },
-        TypeKind::StdVector => {
+        TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque | TypeKind::StdSet | TypeKind::StdUnorderedSet | TypeKind::StdOptional => {
             if let Some(value_type) = &field_type.value_type {
-                let vector_type = format!("StdVector_{}", value_type.type_str);
-                if !referenced_types.contains(&vector_type) {
-                    referenced_types.push(vector_type);
+                let container_type = get_str_dart_fun_type(gen_context, field_type);
+                if !referenced_types.contains(&container_type) {
+                    referenced_types.push(container_type);
                 }
                 // 递归收集值类型
-                collect_referenced_types_from_field_type(value_type, referenced_types);
+                collect_referenced_types_from_field_type(gen_context, value_type, referenced_types);
             }
         },
-        TypeKind::StdMap => {
+        TypeKind::StdMap | TypeKind::StdMultimap | TypeKind::StdUnorderedMap => {
             if let Some(key_type) = &field_type.key_type {
                 if let Some(value_type) = &field_type.value_type {
-                    let map_type = format!("StdMap_{}_{}", key_type.type_str, value_type.type_str);
+                    let map_type = get_str_dart_fun_type(gen_context, field_type);
                     if !referenced_types.contains(&map_type) {
                         referenced_types.push(map_type);
                     }
                     // 递归收集键类型和值类型
-                    collect_referenced_types_from_field_type(key_type, referenced_types);
-                    collect_referenced_types_from_field_type(value_type, referenced_types);
+                    collect_referenced_types_from_field_type(gen_context, key_type, referenced_types);
+                    collect_referenced_types_from_field_type(gen_context, value_type, referenced_types);
                 }
             }
         },
-        TypeKind::StdSet => {
-            if let Some(value_type) = &field_type.value_type {
-                let set_type = format!("StdSet_{}", value_type.type_str);
-                if !referenced_types.contains(&set_type) {
-                    referenced_types.push(set_type);
-                }
-                // 递归收集值类型
-                collect_referenced_types_from_field_type(value_type, referenced_types);
+        TypeKind::StdUniquePtr => {
+            let mut class_type = field_type.clone();
+            class_type.type_kind = TypeKind::Class;
+            collect_referenced_types_from_field_type(gen_context, &class_type, referenced_types);
+        },
+        TypeKind::StdWeakPtr => {
+            let weakptr_type = get_str_dart_fun_type(gen_context, field_type);
+            if !referenced_types.contains(&weakptr_type) {
+                referenced_types.push(weakptr_type);
             }
+            // 也收集基础类型
+            let mut class_type = field_type.clone();
+            class_type.type_kind = TypeKind::Class;
+            collect_referenced_types_from_field_type(gen_context, &class_type, referenced_types);
         },
-        TypeKind::StdUnorderedSet => {
+        TypeKind::StdVariant => {
+            let variant_type = get_dart_type_name(&gen_context.dart_naming, &field_type.get_variant_type_str());
+            if !referenced_types.contains(&variant_type) {
+                referenced_types.push(variant_type);
+            }
+            // 递归收集备选类型
+            for alternative_type in &field_type.param_types {
+                collect_referenced_types_from_field_type(gen_context, alternative_type, referenced_types);
+            }
+        },
+        TypeKind::StdPair | TypeKind::StdTuple => {
+            let tuple_type = get_dart_type_name(&gen_context.dart_naming, &field_type.get_tuple_type_str());
+            if !referenced_types.contains(&tuple_type) {
+                referenced_types.push(tuple_type);
+            }
+            // 递归收集元素类型
+            for element_type in &field_type.param_types {
+                collect_referenced_types_from_field_type(gen_context, element_type, referenced_types);
+            }
+        },
+        TypeKind::StdFunction => {
+            let function_type = get_dart_type_name(&gen_context.dart_naming, &field_type.get_function_type_str());
+            if !referenced_types.contains(&function_type) {
+                referenced_types.push(function_type);
+            }
+            // 递归收集返回值类型和参数类型
             if let Some(value_type) = &field_type.value_type {
-                let set_type = format!("StdUnorderedSet_{}", value_type.type_str);
-                if !referenced_types.contains(&set_type) {
-                    referenced_types.push(set_type);
-                }
-                // 递归收集值类型
-                collect_referenced_types_from_field_type(value_type, referenced_types);
+                collect_referenced_types_from_field_type(gen_context, value_type, referenced_types);
+            }
+            for param_type in &field_type.param_types {
+                collect_referenced_types_from_field_type(gen_context, param_type, referenced_types);
             }
         },
         _ => {} // 基本类型不需要处理
@@ -1453,7 +3066,7 @@ fn find_type_source_file(gen_context: &GenContext, type_name: &str, current_file
             }
             
             // 检查文件中是否定义了这个类型
-            if file_contains_type(file, type_name) {
+            if file_contains_type(gen_context, file, type_name) {
                 return Some(file_stem.to_string());
             }
         }
@@ -1462,9 +3075,9 @@ fn find_type_source_file(gen_context: &GenContext, type_name: &str, current_file
 }
 
 /// 检查文件中是否包含指定类型的定义
-fn file_contains_type(file: &File, type_name: &str) -> bool {
+fn file_contains_type(gen_context: &GenContext, file: &File, type_name: &str) -> bool {
     for child in &file.children {
-        if element_contains_type(child, type_name) {
+        if element_contains_type(gen_context, child, type_name) {
             return true;
         }
     }
@@ -1472,39 +3085,40 @@ fn file_contains_type(file: &File, type_name: &str) -> bool {
 }
 
 /// 检查元素中是否包含指定类型的定义
-fn element_contains_type(element: &HppElement, type_name: &str) -> bool {
+fn element_contains_type(gen_context: &GenContext, element: &HppElement, type_name: &str) -> bool {
     match element {
         HppElement::Class(class) => {
             // 检查类名是否匹配
-            if class.type_str == type_name {
+            let dart_class_name = class.get_dart_name(&gen_context.dart_naming);
+            if dart_class_name == type_name {
                 return true;
             }
-            // 检查StdPtr和StdVector生成的类型
-            if type_name.starts_with("StdPtr_") && format!("StdPtr_{}", class.type_str) == type_name {
+            // 检查StdPtr和StdVector生成的类型，生成的类名使用 c++ 中的类名组成
+            if type_name.starts_with("StdPtr_") && get_dart_type_name(&gen_context.dart_naming, &format!("StdPtr_{}", class.type_str)) == type_name {
                 return true;
             }
-            if type_name.starts_with("StdVector_") && format!("StdVector_{}", class.type_str) == type_name {
+            if type_name.starts_with("StdVector_") && get_dart_type_name(&gen_context.dart_naming, &format!("StdVector_{}", class.type_str)) == type_name {
                 return true;
             }
             // 递归检查子元素
             for child in &class.children {
-                if element_contains_type(child, type_name) {
+                if element_contains_type(gen_context, child, type_name) {
                     return true;
                 }
             }
         },
-        HppElement::Field(_) | HppElement::Method(_) => {
+        HppElement::Field(_) | HppElement::Method(_) | HppElement::Constant(_) | HppElement::Variable(_) => {
             // 字段和方法不定义类型，只引用类型
             return false;
         },
         HppElement::Enum(enum_def) => {
             // 检查 enum 名称是否匹配
-            return enum_def.name == type_name;
+            return get_dart_type_name(&gen_context.dart_naming, &enum_def.name) == type_name;
         },
         HppElement::File(file) => {
             // 递归检查文件中的子元素
             for child in &file.children {
-                if element_contains_type(child, type_name) {
+                if element_contains_type(gen_context, child, type_name) {
                     return true;
                 }
             }
@@ -1513,22 +3127,243 @@ fn element_contains_type(element: &HppElement, type_name: &str) -> bool {
     false
 }
 
+/// 为StdVector类生成便利方法，元素为基础数据类型时和 TypedData 互相转换
+fn generate_stdvector_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
+    let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
+    if value_type.ptr_level != 0 {
+        return String::new();
+    }
+    let Some(typed_list_type) = get_str_dart_typed_list_type(value_type) else { return String::new(); };
+
+    format!(r#"
+    // 便利构造函数 - 从dart的TypedData创建，只复制一次
+    {}.fromTypedList({} list) {{
+        _nativePtr = ffi_{}_Constructor();
+        nativeLifecycleLink();
+        resize(list.length);
+        asTypedList().setAll(0, list);
+    }}
+
+    // 直接访问native内存的视图，不复制，vector改变大小或者释放后失效
+    {} asTypedList() {{
+        final length = size();
+        if (length == 0) {{
+            return {}(0);
+        }}
+        return data().asTypedList(length);
+    }}
+
+    // 复制为dart的TypedData
+    {} toTypedList() {{
+        return {}.fromList(asTypedList());
+    }}
+"#,
+        class.get_dart_name(&gen_context.dart_naming), typed_list_type, class.get_ffi_name(),
+        typed_list_type, typed_list_type,
+        typed_list_type, typed_list_type,
+    )
+}
+
+/// 为容器类生成遍历的方法，使用 ffi 的 iter_xxx 方法，map 类容器的元素为 (键, 值) 的 record
+fn generate_container_iteration_methods(gen_context: &GenContext, class: &Class) -> String {
+    let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
+    let value_dart_type = get_str_dart_fun_decl_type(gen_context, value_type);
+
+    match class.class_type {
+        ClassType::StdMap | ClassType::StdMultimap | ClassType::StdUnorderedMap => {
+            // 如果模板参数解析失败，没有迭代器方法
+            let Some(key_type) = class.key_type.as_ref() else { return String::new(); };
+            let key_dart_type = get_str_dart_fun_decl_type(gen_context, key_type);
+
+            // multimap 同一个键可以有多个值，转换为 Map<K, List<V>>
+            let to_map_str = if class.class_type == ClassType::StdMultimap {
+                format!(r#"
+    // 转换为Dart Map，同一个键的值放到一个List中
+    Map<{}, List<{}>> toMap() {{
+        final result = <{}, List<{}>>{{}};
+        for (final (key, value) in entries) {{
+            result.putIfAbsent(key, () => []).add(value);
+        }}
+        return result;
+    }}
+
+    // 转换为 (键, 值) 的List
+    List<({}, {})> toList() => entries.toList();
+"#,
+                    key_dart_type, value_dart_type,
+                    key_dart_type, value_dart_type,
+                    key_dart_type, value_dart_type,
+                )
+            } else {
+                format!(r#"
+    // 转换为Dart Map
+    Map<{}, {}> toMap() {{
+        return {{for (final (key, value) in entries) key: value}};
+    }}
+"#,
+                    key_dart_type, value_dart_type,
+                )
+            };
+
+            format!(r#"
+    // 遍历所有 (键, 值)，遍历过程中不能修改容器
+    Iterable<({}, {})> get entries => NativeIterable<({}, {})>(iter_begin, iter_valid, iter_next, (it) => (iter_key(it), iter_value(it)), iter_end);
+
+    // 遍历所有键
+    Iterable<{}> get keys => NativeIterable<{}>(iter_begin, iter_valid, iter_next, iter_key, iter_end);
+
+    // 遍历所有值
+    Iterable<{}> get values => NativeIterable<{}>(iter_begin, iter_valid, iter_next, iter_value, iter_end);
+
+    void forEach(void Function({} key, {} value) action) {{
+        for (final (key, value) in entries) {{
+            action(key, value);
+        }}
+    }}
+{}"#,
+                key_dart_type, value_dart_type, key_dart_type, value_dart_type,
+                key_dart_type, key_dart_type,
+                value_dart_type, value_dart_type,
+                key_dart_type, value_dart_type,
+                to_map_str,
+            )
+        }
+        _ => {
+            // set 类的容器可以转换为 Set
+            let to_set_str = if matches!(class.class_type, ClassType::StdSet | ClassType::StdUnorderedSet) {
+                format!(r#"
+    // 转换为Dart Set
+    Set<{}> toSet() => values.toSet();
+"#,
+                    value_dart_type,
+                )
+            } else {
+                "".to_string()
+            };
+
+            format!(r#"
+    // 遍历所有元素，遍历过程中不能修改容器
+    Iterable<{}> get values => NativeIterable<{}>(iter_begin, iter_valid, iter_next, iter_value, iter_end);
+
+    void forEach(void Function({} value) action) {{
+        values.forEach(action);
+    }}
+
+    // 转换为Dart List
+    List<{}> toList() => values.toList();
+{}"#,
+                value_dart_type, value_dart_type,
+                value_dart_type,
+                value_dart_type,
+                to_set_str,
+            )
+        }
+    }
+}
+
+/// 从 dart 集合创建容器的构造函数体
+/// 元素可以批量复制时，分配连续的内存后一次 ffi 调用复制所有元素，否则逐个插入
+/// iterable_strs 为每个元素参数（键、值）对应的 dart Iterable，count_str 为元素个数，insert_str 为逐个插入的代码
+fn get_str_dart_container_from_collection_body(gen_context: &GenContext, class: &Class, iterable_strs: &[&str], count_str: &str, insert_str: &str) -> String {
+    let bulk_element_types = class.get_bulk_element_types();
+    if bulk_element_types.is_empty() {
+        return format!("        _nativePtr = ffi_{}_Constructor();
+        nativeLifecycleLink();
+        {}", class.get_ffi_name(), insert_str);
+    }
+
+    // 先在 dart 中完成元素的转换，转换出错时还没有分配 native 内存
+    // native 内存在 finally 中释放，复制元素或 ffi 调用出错时不会泄漏
+    let mut encode_strs = vec![];
+    let mut alloc_strs = vec![];
+    let mut fill_strs = vec![];
+    let mut arg_strs = vec![];
+    let mut free_strs = vec![];
+    for ((name, element_type), iterable_str) in bulk_element_types.iter().zip(iterable_strs) {
+        if element_type.type_kind == TypeKind::String {
+            // 所有字符串的 utf8 连续存放，每个字符串的长度单独传递
+            encode_strs.push(format!("        final _c_{}_encoded = [for (final value in {}) utf8.encode(value)];
+        final _c_{}_units = [for (final units in _c_{}_encoded) ...units];",
+                name, iterable_str,
+                name, name));
+            alloc_strs.push(format!("        final _c_{} = malloc<Uint8>(_c_{}_units.length + 1);
+        final _c_{}_lengths = malloc<Uint64>(count + 1);",
+                name, name,
+                name));
+            fill_strs.push(format!("            _c_{}.asTypedList(_c_{}_units.length).setAll(0, _c_{}_units);
+            _c_{}_lengths.asTypedList(count).setAll(0, [for (final units in _c_{}_encoded) units.length]);",
+                name, name, name,
+                name, name));
+            arg_strs.push(format!("_c_{}.cast<Utf8>(), _c_{}_lengths", name, name));
+            free_strs.push(format!("            malloc.free(_c_{});
+            malloc.free(_c_{}_lengths);", name, name));
+        } else {
+            encode_strs.push(format!("        final _c_{}_values = {}.toList();", name, iterable_str));
+            alloc_strs.push(format!("        final _c_{} = malloc<{}>(count + 1);",
+                name, get_str_native_api_type(gen_context, element_type)));
+            fill_strs.push(format!("            _c_{}.asTypedList(count).setAll(0, _c_{}_values);", name, name));
+            arg_strs.push(format!("_c_{}", name));
+            free_strs.push(format!("            malloc.free(_c_{});", name));
+        }
+    }
+
+    format!("        final count = {};
+{}
+{}
+        try {{
+{}
+            _nativePtr = ffi_{}_Constructor_from_buffer({}, count);
+        }} finally {{
+{}
+        }}
+        nativeLifecycleLink();",
+        count_str,
+        encode_strs.join("\n"),
+        alloc_strs.join("\n"),
+        fill_strs.join("\n"),
+        class.get_ffi_name(), arg_strs.join(", "),
+        free_strs.join("\n"),
+    )
+}
+
+/// 为StdVector、StdList、StdDeque类生成从Dart List创建的构造函数
+fn generate_stdsequence_from_list_method(gen_context: &GenContext, class: &Class) -> String {
+    let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
+    let value_dart_type = get_str_dart_fun_type(gen_context, value_type);
+
+    format!(r#"
+    // 便利构造函数 - 从Dart List创建
+    {}.fromList(List<{}> list) {{
+{}
+    }}
+"#,
+        class.get_dart_name(&gen_context.dart_naming), value_dart_type,
+        get_str_dart_container_from_collection_body(gen_context, class, &["list"], "list.length", "for (var value in list) {
+            push_back(value);
+        }"),
+    )
+}
+
+/// map 的 [] 和 find() 的返回值类型相同，找不到 key 时 native 对象为 null
+fn get_str_dart_map_find_return_type(gen_context: &GenContext, class: &Class) -> Option<String> {
+    class.children.iter().find_map(|element| match element {
+        HppElement::Method(method) if method.name == "find" => Some(get_str_dart_fun_return_type(gen_context, method)),
+        _ => None,
+    })
+}
+
 /// 为StdMap类生成便利方法
-fn generate_stdmap_convenience_methods(class: &Class) -> String {
+fn generate_stdmap_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
     // 如果模板参数解析失败，不生成便利方法
     let Some(key_type) = class.key_type.as_ref() else { return String::new(); };
     let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
-    let key_dart_type = get_str_dart_fun_type(key_type);
-    let value_dart_type = get_str_dart_fun_type(value_type);
+    let key_dart_type = get_str_dart_fun_type(gen_context, key_type);
+    let value_dart_type = get_str_dart_fun_type(gen_context, value_type);
     
     format!(r#"
     // 便利构造函数 - 从Dart Map创建
     {}.fromMap(Map<{}, {}> map) {{
-        _nativePtr = ffi_{}_Constructor();
-        nativeLifecycleLink();
-        for (var entry in map.entries) {{
-            insert(entry.key, entry.value);
-        }}
+{}
     }}
     
     // length属性
@@ -1548,39 +3383,27 @@ fn generate_stdmap_convenience_methods(class: &Class) -> String {
     bool contains(dynamic key) {{
         return count(key) > 0;
     }}
-    
-    // 转换为Dart Map
-    Map<{}, {}> toMap() {{
-        Map<{}, {}> result = {{}};
-        // 注意：这里需要通过FFI迭代来实现
-        // 暂时返回空Map，需要额外的FFI支持来实现迭代
-        return result;
-    }}
-"#, 
-        class.type_str, 
-        key_dart_type, value_dart_type,
-        class.type_str,
-        value_dart_type,
+"#,
+        class.get_dart_name(&gen_context.dart_naming),
         key_dart_type, value_dart_type,
-        key_dart_type, value_dart_type
+        get_str_dart_container_from_collection_body(gen_context, class, &["map.keys", "map.values"], "map.length", "for (var entry in map.entries) {
+            insert(entry.key, entry.value);
+        }"),
+        get_str_dart_map_find_return_type(gen_context, class).unwrap_or(value_dart_type.clone()),
     )
 }
 
 /// 为StdUnorderedMap类生成便利方法
-fn generate_stdunorderedmap_convenience_methods(class: &Class) -> String {
+fn generate_stdunorderedmap_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
     let Some(key_type) = class.key_type.as_ref() else { return String::new(); };
     let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
-    let key_dart_type = get_str_dart_fun_type(key_type);
-    let value_dart_type = get_str_dart_fun_type(value_type);
+    let key_dart_type = get_str_dart_fun_type(gen_context, key_type);
+    let value_dart_type = get_str_dart_fun_type(gen_context, value_type);
     
     format!(r#"
     // 便利构造函数 - 从Dart Map创建
     {}.fromMap(Map<{}, {}> map) {{
-        _nativePtr = ffi_{}_Constructor();
-        nativeLifecycleLink();
-        for (var entry in map.entries) {{
-            insert(entry.key, entry.value);
-        }}
+{}
     }}
     
     // length属性
@@ -1600,37 +3423,56 @@ fn generate_stdunorderedmap_convenience_methods(class: &Class) -> String {
     bool contains(dynamic key) {{
         return count(key) > 0;
     }}
-    
-    // 转换为Dart Map
-    Map<{}, {}> toMap() {{
-        Map<{}, {}> result = {{}};
-        // 注意：这里需要通过FFI迭代来实现
-        // 暂时返回空Map，需要额外的FFI支持来实现迭代
-        return result;
-    }}
-"#, 
-        class.type_str, 
+"#,
+        class.get_dart_name(&gen_context.dart_naming),
         key_dart_type, value_dart_type,
-        class.type_str,
-        value_dart_type,
+        get_str_dart_container_from_collection_body(gen_context, class, &["map.keys", "map.values"], "map.length", "for (var entry in map.entries) {
+            insert(entry.key, entry.value);
+        }"),
+        get_str_dart_map_find_return_type(gen_context, class).unwrap_or(value_dart_type.clone()),
+    )
+}
+
+/// 为StdMultimap类生成便利方法，同一个键可以有多个值，使用 (键, 值) 的 record 创建
+fn generate_stdmultimap_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
+    // 如果模板参数解析失败，不生成便利方法
+    let Some(key_type) = class.key_type.as_ref() else { return String::new(); };
+    let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
+    let key_dart_type = get_str_dart_fun_decl_type(gen_context, key_type);
+    let value_dart_type = get_str_dart_fun_decl_type(gen_context, value_type);
+
+    format!(r#"
+    // 便利构造函数 - 从 (键, 值) 列表创建
+    {}.fromEntries(Iterable<({}, {})> entries) {{
+        final entryList = entries.toList();
+{}
+    }}
+
+    // length属性
+    int get length => size();
+
+    // contains方法
+    bool contains(dynamic key) {{
+        return count(key) > 0;
+    }}
+"#,
+        class.get_dart_name(&gen_context.dart_naming),
         key_dart_type, value_dart_type,
-        key_dart_type, value_dart_type
+        get_str_dart_container_from_collection_body(gen_context, class, &["entryList.map((entry) => entry.$1)", "entryList.map((entry) => entry.$2)"], "entryList.length", "for (final (key, value) in entryList) {
+            insert(key, value);
+        }"),
     )
 }
 
 /// 为StdSet类生成便利方法
-fn generate_stdset_convenience_methods(class: &Class) -> String {
+fn generate_stdset_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
     let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
-    let value_dart_type = get_str_dart_fun_type(value_type);
+    let value_dart_type = get_str_dart_fun_type(gen_context, value_type);
     
     format!(r#"
     // 便利构造函数 - 从Dart Set创建
     {}.fromSet(Set<{}> set) {{
-        _nativePtr = ffi_{}_Constructor();
-        nativeLifecycleLink();
-        for (var value in set) {{
-            insert(value);
-        }}
+{}
     }}
     
     // length属性
@@ -1640,36 +3482,24 @@ fn generate_stdset_convenience_methods(class: &Class) -> String {
     bool contains(dynamic value) {{
         return count(value) > 0;
     }}
-    
-    // 转换为Dart Set
-    Set<{}> toSet() {{
-        Set<{}> result = {{}};
-        // 注意：这里需要通过FFI迭代来实现
-        // 暂时返回空Set，需要额外的FFI支持来实现迭代
-        return result;
-    }}
-"#, 
-        class.type_str, 
-        value_dart_type,
-        class.type_str,
+"#,
+        class.get_dart_name(&gen_context.dart_naming),
         value_dart_type,
-        value_dart_type
+        get_str_dart_container_from_collection_body(gen_context, class, &["set"], "set.length", "for (var value in set) {
+            insert(value);
+        }"),
     )
 }
 
 /// 为StdUnorderedSet类生成便利方法
-fn generate_stdunorderedset_convenience_methods(class: &Class) -> String {
+fn generate_stdunorderedset_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
     let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
-    let value_dart_type = get_str_dart_fun_type(value_type);
+    let value_dart_type = get_str_dart_fun_type(gen_context, value_type);
     
     format!(r#"
     // 便利构造函数 - 从Dart Set创建
     {}.fromSet(Set<{}> set) {{
-        _nativePtr = ffi_{}_Constructor();
-        nativeLifecycleLink();
-        for (var value in set) {{
-            insert(value);
-        }}
+{}
     }}
     
     // length属性
@@ -1679,43 +3509,174 @@ fn generate_stdunorderedset_convenience_methods(class: &Class) -> String {
     bool contains(dynamic value) {{
         return count(value) > 0;
     }}
-    
-    // 转换为Dart Set
-    Set<{}> toSet() {{
-        Set<{}> result = {{}};
-        // 注意：这里需要通过FFI迭代来实现
-        // 暂时返回空Set，需要额外的FFI支持来实现迭代
-        return result;
-    }}
-"#, 
-        class.type_str, 
+"#,
+        class.get_dart_name(&gen_context.dart_naming),
         value_dart_type,
-        class.type_str,
+        get_str_dart_container_from_collection_body(gen_context, class, &["set"], "set.length", "for (var value in set) {
+            insert(value);
+        }"),
+    )
+}
+
+/// 为StdOptional类生成便利方法
+fn generate_stdoptional_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
+    let Some(value_type) = class.value_type.as_ref() else { return String::new(); };
+    let value_dart_type = get_str_dart_fun_decl_type(gen_context, value_type);
+
+    format!(r#"
+    // 便利构造函数 - 从dart的可空类型创建
+    {}.fromDart({}? value) {{
+        _nativePtr = ffi_{}_Constructor();
+        nativeLifecycleLink();
+        if (value != null) {{
+            emplace(value);
+        }}
+    }}
+
+    // 转换为dart的可空类型
+    {}? toDart() {{
+        return has_value() ? value() : null;
+    }}
+"#,
+        class.get_dart_name(&gen_context.dart_naming),
         value_dart_type,
+        class.get_ffi_name(),
         value_dart_type
     )
 }
 
+/// 为StdVariant类生成和 sealed class 之间的转换
+fn generate_stdvariant_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
+    let Some(variant_type) = class.value_type.as_ref() else { return String::new(); };
+    let sealed_name = get_str_dart_fun_decl_type(gen_context, variant_type);
+
+    let mut from_dart_cases = Vec::new();
+    let mut to_dart_cases = Vec::new();
+    for (index, alternative_type) in variant_type.param_types.iter().enumerate() {
+        // std::monostate 没有值
+        if alternative_type.type_kind == TypeKind::Void {
+            from_dart_cases.push(format!("            {}{}() => {}.from_{}(),", sealed_name, index, class.get_dart_name(&gen_context.dart_naming), index));
+            to_dart_cases.push(format!("            {} => {}{}(),", index, sealed_name, index));
+        } else {
+            from_dart_cases.push(format!("            {}{}(:final value) => {}.from_{}(value),", sealed_name, index, class.get_dart_name(&gen_context.dart_naming), index));
+            to_dart_cases.push(format!("            {} => {}{}(get_{}()),", index, sealed_name, index, index));
+        }
+    }
+
+    format!(r#"
+    // 便利构造函数 - 从dart的sealed class创建
+    factory {}.fromDart({} value) {{
+        return switch (value) {{
+{}
+        }};
+    }}
+
+    // 转换为dart的sealed class
+    {} toDart() {{
+        return switch (index()) {{
+{}
+            _ => throw StateError('{} is valueless'),
+        }};
+    }}
+"#,
+        class.get_dart_name(&gen_context.dart_naming), sealed_name,
+        from_dart_cases.join("
+"),
+        sealed_name,
+        to_dart_cases.join("
+"),
+        variant_type.type_str
+    )
+}
+
+/// std::pair、std::tuple 和 dart record 之间的转换
+fn generate_stdtuple_convenience_methods(gen_context: &GenContext, class: &Class) -> String {
+    let Some(tuple_type) = class.value_type.as_ref() else { return String::new(); };
+    let record_name = get_str_dart_fun_decl_type(gen_context, tuple_type);
+
+    let mut make_params = Vec::new();
+    let mut record_values = Vec::new();
+    for index in 0..tuple_type.param_types.len() {
+        make_params.push(format!("value.${}", index + 1));
+        record_values.push(format!("get_{}()", index));
+    }
+    let trailing_comma = if record_values.len() == 1 { "," } else { "" };
+
+    format!(r#"
+    // 便利构造函数 - 从dart的record创建
+    factory {}.fromDart({} value) {{
+        return {}.make({});
+    }}
+
+    // 转换为dart的record
+    {} toDart() {{
+        return ({}{});
+    }}
+"#,
+        class.get_dart_name(&gen_context.dart_naming), record_name,
+        class.get_dart_name(&gen_context.dart_naming), make_params.join(", "),
+        record_name,
+        record_values.join(", "), trailing_comma
+    )
+}
+
+/// std::variant 对应的 sealed class，每个备选类型一个子类，可以使用 switch 穷举匹配
+fn get_str_dart_stdvariant_sealed_classes(gen_context: &GenContext, class: &Class) -> String {
+    let Some(variant_type) = class.value_type.as_ref() else { return String::new(); };
+    let sealed_name = get_str_dart_fun_decl_type(gen_context, variant_type);
+
+    let mut sealed_classes = format!("
+/// {}
+sealed class {} {{
+    const {}();
+}}
+", variant_type.type_str, sealed_name, sealed_name);
+    for (index, alternative_type) in variant_type.param_types.iter().enumerate() {
+        let alternative_name = format!("{}{}", sealed_name, index);
+        // std::monostate 没有值
+        if alternative_type.type_kind == TypeKind::Void {
+            sealed_classes.push_str(&format!("
+/// {}
+final class {} extends {} {{
+    const {}();
+}}
+", alternative_type.full_str, alternative_name, sealed_name, alternative_name));
+        } else {
+            sealed_classes.push_str(&format!("
+/// {}
+final class {} extends {} {{
+    final {} value;
+    const {}(this.value);
+}}
+", alternative_type.full_str, alternative_name, sealed_name, get_str_dart_fun_decl_type(gen_context, alternative_type), alternative_name));
+        }
+    }
+    sealed_classes.push('\n');
+
+    sealed_classes
+}
+
 /// 生成 Dart enum 代码
-fn gen_dart_enum(enum_def: &Enum) -> String {
+fn gen_dart_enum(gen_context: &GenContext, enum_def: &Enum) -> String {
     let comment = enum_def.comment_str.as_ref().map(|c| format!("{}\n", c)).unwrap_or_default();
 
     if enum_def.is_scoped {
         // enum class → 生成 Dart enum（强类型）
-        gen_dart_scoped_enum(enum_def, &comment)
+        gen_dart_scoped_enum(gen_context, enum_def, &comment)
     } else {
         // 普通 enum → 生成 class + static const（兼容）
-        gen_dart_unscoped_enum(enum_def, &comment)
+        gen_dart_unscoped_enum(gen_context, enum_def, &comment)
     }
 }
 
 /// 为 enum class 生成 Dart enum
-fn gen_dart_scoped_enum(enum_def: &Enum, comment: &str) -> String {
+fn gen_dart_scoped_enum(gen_context: &GenContext, enum_def: &Enum, comment: &str) -> String {
+    let dart_name = get_dart_type_name(&gen_context.dart_naming, &enum_def.name);
     let mut enum_values = Vec::new();
 
     for (name, value) in &enum_def.values {
-        // 转换为 lowerCamelCase（Dart 枚举值规范）
-        let dart_name = to_lower_camel_case(name);
+        // 转换为 lowerCamelCase（Dart 枚举值规范），去掉命名规则中的前缀，如 kRed -> red
+        let dart_name = get_dart_member_name(&gen_context.dart_naming, name, true);
         enum_values.push(format!("  {}({})", dart_name, value));
     }
 
@@ -1739,16 +3700,16 @@ r#"{}enum {} {{
 
 "#,
         comment,
-        enum_def.name,
+        dart_name,
         enum_values.join(",\n"),
-        enum_def.name,
-        enum_def.name,
-        enum_def.name
+        dart_name,
+        dart_name,
+        dart_name
     )
 }
 
 /// 为普通 enum 生成 Dart class
-fn gen_dart_unscoped_enum(enum_def: &Enum, comment: &str) -> String {
+fn gen_dart_unscoped_enum(gen_context: &GenContext, enum_def: &Enum, comment: &str) -> String {
     let mut const_values = Vec::new();
 
     for (name, value) in &enum_def.values {
@@ -1764,27 +3725,7 @@ class {} {{
 
 "#,
         comment,
-        enum_def.name,
+        get_dart_type_name(&gen_context.dart_naming, &enum_def.name),
         const_values.join("\n")
     )
 }
-
-/// 将字符串转换为 lowerCamelCase
-fn to_lower_camel_case(s: &str) -> String {
-    let parts: Vec<&str> = s.split('_').collect();
-    if parts.is_empty() {
-        return s.to_lowercase();
-    }
-
-    let mut result = parts[0].to_lowercase();
-    for part in &parts[1..] {
-        if !part.is_empty() {
-            let mut chars = part.chars();
-            if let Some(first) = chars.next() {
-                result.push(first.to_uppercase().next().unwrap());
-                result.push_str(&chars.as_str().to_lowercase());
-            }
-        }
-    }
-    result
-}
@@ -32,6 +32,27 @@ struct Args {
     /// 额外的 clang 编译参数（可以指定多个，用空格分隔）
     #[arg(long)]
     clang_args: Option<String>,
+
+    /// dart 使用 @Native 注解绑定 ffi 函数，参数为 native assets 的 asset id（如 package:my_plugin/my_plugin）
+    /// 不指定时使用 DynamicLibrary.lookup，需要先调用 setDylib
+    #[arg(long)]
+    dart_native_asset: Option<String>,
+
+    /// 为普通类和 POD 结构体生成 dart 的不可变数据类（XxxData），包含 ==、hashCode、toString、copyWith 和 native 对象的转换
+    #[arg(long)]
+    dart_data_class: bool,
+
+    /// dart 中的类、枚举名转换为 UpperCamelCase（如 HTTPClient -> HttpClient）
+    #[arg(long)]
+    dart_upper_camel_types: bool,
+
+    /// dart 中普通类的方法、参数、全局函数、常量、变量名转换为 lowerCamelCase（如 get_user_id -> getUserId）
+    #[arg(long)]
+    dart_lower_camel_members: bool,
+
+    /// dart 中成员名去掉的前缀，多个用逗号分隔（如 k,m_，kMaxSize -> MaxSize、m_count -> count）
+    #[arg(long)]
+    dart_strip_prefix: Option<String>,
 }
 
 fn main() {
@@ -54,8 +75,9 @@ fn run() -> Result<(), String> {
     let content = fs::read_to_string(&args.input)
         .map_err(|e| format!("无法读取输入文件 '{}': {}", args.input, e))?;
 
-    // 解析 %include 指令
+    // 解析 %include、%buffer 指令
     let mut input_content_files = vec![];
+    let mut buffer_params = vec![];
     for line in content.lines() {
         if line.trim_start().starts_with("%include") && !line.trim_end().ends_with(".i\"") {
             if let (Some(start), Some(end)) = (line.find('"'), line.rfind('"')) {
@@ -64,6 +86,11 @@ fn run() -> Result<(), String> {
                 }
             }
         }
+        else if line.trim_start().starts_with("%buffer") {
+            let buffer_param = parse_buffer_directive(line)
+                .ok_or_else(|| format!("无法解析 %buffer 指令 '{}'，格式为 %buffer Class::method(ptr, len)", line.trim()))?;
+            buffer_params.push(buffer_param);
+        }
     }
 
     if input_content_files.is_empty() {
@@ -97,6 +124,15 @@ fn run() -> Result<(), String> {
         .map(|idx| &input_filename[..idx])
         .unwrap_or(input_filename);
     gen_context.module_name = module_name.to_string();
+    gen_context.buffer_params = buffer_params;
+    gen_context.dart_native_asset = args.dart_native_asset.clone();
+    gen_context.dart_data_class = args.dart_data_class;
+    gen_context.dart_naming = gen_context::DartNaming {
+        upper_camel_types: args.dart_upper_camel_types,
+        lower_camel_members: args.dart_lower_camel_members,
+        strip_prefixes: args.dart_strip_prefix.as_deref().unwrap_or_default()
+            .split(',').map(|prefix| prefix.trim().to_string()).filter(|prefix| !prefix.is_empty()).collect(),
+    };
 
     // 构建 include 路径
     // 1. 默认包含 .i 文件所在的目录
@@ -138,9 +174,11 @@ fn run() -> Result<(), String> {
         let h_file_str = h_file.to_str()
             .ok_or_else(|| format!("无效的文件路径: {:?}", h_file))?;
 
-        parser::parse_hpp(&mut gen_context, h_file_str, &include_paths, &args.cpp_std, &extra_clang_args);
+        parser::parse_hpp(&mut gen_context, h_file_str, &include_paths, &args.cpp_std, &extra_clang_args)?;
     }
 
+    gen_context.has_sync_callback = gen_dart::get_has_sync_callback(&gen_context.hpp_elements);
+
     // 第二阶段：统一生成代码
     // println!("正在生成 C 绑定代码...");
     gen_c::gen_c(&gen_context, gen_out_dir);
@@ -148,6 +186,45 @@ fn run() -> Result<(), String> {
     // println!("正在生成 Dart 绑定代码...");
     gen_dart::gen_dart(&gen_context, gen_out_dir);
 
+    // 使用 isLeaf 调用的函数
+    let leaf_function_names = gen_dart::get_leaf_function_names(&gen_context);
+    if !leaf_function_names.is_empty() {
+        println!("isLeaf 调用的函数（{} 个）：", leaf_function_names.len());
+        for name in &leaf_function_names {
+            println!("  {}", name);
+        }
+    }
+
     println!("✓ 代码生成完成！输出目录: {}", gen_out_dir);
     Ok(())
 }
+
+/// 解析 %buffer 指令，如 %buffer Image::load(data, len)
+fn parse_buffer_directive(line: &str) -> Option<gen_context::BufferParam> {
+    let directive = line.trim().strip_prefix("%buffer")?.trim();
+    let (method_name, params) = directive.split_once('(')?;
+    let (ptr_param, len_param) = params.trim_end_matches(';').trim().strip_suffix(')')?.split_once(',')?;
+    let buffer_param = gen_context::BufferParam {
+        method_name: method_name.trim().to_string(),
+        ptr_param: ptr_param.trim().to_string(),
+        len_param: len_param.trim().to_string(),
+    };
+    if buffer_param.method_name.is_empty() || buffer_param.ptr_param.is_empty() || buffer_param.len_param.is_empty() {
+        return None;
+    }
+    Some(buffer_param)
+}
+
+#[test]
+fn test_parse_buffer_directive() {
+    let buffer_param = parse_buffer_directive("  %buffer Image::load(data, len);").unwrap();
+    assert_eq!(buffer_param, gen_context::BufferParam {
+        method_name: "Image::load".to_string(),
+        ptr_param: "data".to_string(),
+        len_param: "len".to_string(),
+    });
+    assert_eq!(parse_buffer_directive("%buffer crc32(buf,size)").unwrap().method_name, "crc32");
+    assert_eq!(parse_buffer_directive("%buffer Image::load(data)"), None);
+    assert_eq!(parse_buffer_directive("%buffer (data, len)"), None);
+    assert_eq!(parse_buffer_directive("%include \"image.hpp\""), None);
+}
@@ -14,6 +14,8 @@ fn simplify_type_for_naming(type_str: &str) -> String {
         .replace("::", "_")
         .replace("<", "_")
         .replace(">", "_")
+        .replace("(", "_")
+        .replace(")", "_")
         .replace(",", "_");
     
     // 如果结果为空或只有下划线，使用默认名称
@@ -46,7 +48,27 @@ fn is_sync_callback_from_comment(comment: &Option<String>) -> bool {
     false
 }
 
-pub fn parse_hpp(out_gen_context: &mut GenContext, hpp_path: &str, include_path: &str, cpp_std: &str, extra_clang_args: &[String]) {
+/// 根据注释判断 dart 中是否使用 isLeaf 调用
+/// 检查注释中的 @leaf 或 @no_leaf 标记，没有标记时返回 None，按签名自动判断
+fn is_leaf_from_comment(comment: &Option<String>) -> Option<bool> {
+    if has_comment_tag(comment, "@no_leaf") {
+        return Some(false);
+    }
+    if has_comment_tag(comment, "@leaf") {
+        return Some(true);
+    }
+    None
+}
+
+#[test]
+fn test_is_leaf_from_comment() {
+    assert_eq!(is_leaf_from_comment(&Some("/// @leaf".to_string())), Some(true));
+    assert_eq!(is_leaf_from_comment(&Some("/// @no_leaf".to_string())), Some(false));
+    assert_eq!(is_leaf_from_comment(&Some("/// @leafy @no_leafy".to_string())), None);
+    assert_eq!(is_leaf_from_comment(&None), None);
+}
+
+pub fn parse_hpp(out_gen_context: &mut GenContext, hpp_path: &str, include_path: &str, cpp_std: &str, extra_clang_args: &[String]) -> Result<(), String> {
     let clang = clang::Clang::new().unwrap();
     let index = clang::Index::new(&clang, true, false);
 
@@ -74,6 +96,8 @@ pub fn parse_hpp(out_gen_context: &mut GenContext, hpp_path: &str, include_path:
 
     let translation_unit = index.parser(hpp_path)
         .arguments(&clang_args_refs)
+        // 需要解析 #define 常量
+        .detailed_preprocessing_record(true)
         .parse().unwrap();
 
     // 检查 clang 诊断信息，打印错误和致命错误
@@ -95,6 +119,9 @@ pub fn parse_hpp(out_gen_context: &mut GenContext, hpp_path: &str, include_path:
     let mut file_element = HppElement::File(file);
     visit_parse_clang_entity(&mut file_element, &entity, 0);
     // println!("{:#?}", file_element);
+    hoist_nested_types(&mut file_element);
+    check_unsupported_pure_virtual_methods(&file_element)?;
+    apply_buffer_params(&mut file_element, &out_gen_context.buffer_params);
 
     let mut elements = vec![];
     post_process_hpp_element(out_gen_context, &mut elements, &file_element);
@@ -103,6 +130,23 @@ pub fn parse_hpp(out_gen_context: &mut GenContext, hpp_path: &str, include_path:
     }
 
     out_gen_context.hpp_elements.push(file_element);
+    Ok(())
+}
+
+/// 回调类的纯虚函数无法桥接时，生成的 Impl 子类无法实例化，直接报错而不是生成无法编译的代码
+fn check_unsupported_pure_virtual_methods(file_element: &HppElement) -> Result<(), String> {
+    let HppElement::File(file) = file_element else {
+        return Ok(());
+    };
+    let method_descs: Vec<&String> = file.children.iter()
+        .filter_map(|child| if let HppElement::Class(class) = child { Some(class) } else { None })
+        .flat_map(|class| class.unsupported_pure_virtual_methods.iter())
+        .collect();
+    if method_descs.is_empty() {
+        return Ok(());
+    }
+    Err(format!("{} 中回调类的纯虚函数使用了回调不支持的类型，无法生成可以实例化的 Impl 子类:\n  {}",
+        file.path, method_descs.iter().map(|desc| desc.as_str()).collect::<Vec<_>>().join("\n  ")))
 }
 // 注意: 这个单元测试已经不维护了
 // 主要测试手段是 Flutter 集成测试: tests/flutter_test_project/run_test.sh
@@ -111,7 +155,7 @@ pub fn parse_hpp(out_gen_context: &mut GenContext, hpp_path: &str, include_path:
 #[ignore]
 fn test_parse_hpp() {
     let mut gen_context = GenContext::default();
-    parse_hpp(&mut gen_context, "./tests/parser_test/test.hpp", "./tests/parser_test", "c++20", &[]);
+    parse_hpp(&mut gen_context, "./tests/parser_test/test.hpp", "./tests/parser_test", "c++20", &[]).unwrap();
     let result = format!("{:#?}", gen_context);
     let expected = std::fs::read_to_string("./tests/parser_test/ut_result/parse_hpp.txt").unwrap();
     assert_eq!(result, expected);
@@ -144,6 +188,9 @@ fn visit_parse_clang_entity(out_hpp_element: &mut HppElement, entity: &clang::En
         clang::EntityKind::FieldDecl => handle_clang_FieldDecl(out_hpp_element, entity, indent),
         // 不属于类的独立函数
         clang::EntityKind::FunctionDecl => handle_clang_FunctionDecl(out_hpp_element, entity, indent),
+        // 全局变量、类的静态成员变量
+        clang::EntityKind::VarDecl => handle_clang_VarDecl(out_hpp_element, entity),
+        clang::EntityKind::MacroDefinition => handle_clang_MacroDefinition(out_hpp_element, entity),
         _ => {
             for child in entity.get_children() {
                 visit_parse_clang_entity(out_hpp_element, &child, indent + 1);
@@ -177,6 +224,15 @@ fn handle_clang_ClassDecl(out_hpp_element: &mut HppElement, entity: &clang::Enti
             return;
         }
     }
+    // std::hash 的特化，不生成类，只标记被 hash 的类
+    if entity.get_name().unwrap_or_default() == "hash" {
+        if let Some(semantic_parent) = entity.get_semantic_parent() {
+            if semantic_parent.get_kind() == clang::EntityKind::Namespace && semantic_parent.get_name().unwrap_or_default() == "std" {
+                mark_std_hash_class(out_hpp_element, entity);
+                return;
+            }
+        }
+    }
     // 跳过 std namespace 的类
     if let Some(semantic_parent) = entity.get_semantic_parent() {
         if let Some(parent_name) = semantic_parent.get_name() {
@@ -186,7 +242,15 @@ fn handle_clang_ClassDecl(out_hpp_element: &mut HppElement, entity: &clang::Enti
         }
     }
     
-    let class_name = entity.get_name().unwrap_or_default();
+    // 嵌套类型使用限定名，如 Http::Request
+    let mut class_name = entity.get_name().unwrap_or_default();
+    if let HppElement::Class(_) = out_hpp_element {
+        // 匿名的嵌套 struct/union 无法在类外部引用，跳过
+        if class_name.is_empty() {
+            return;
+        }
+        class_name = get_nested_type_name(entity).unwrap_or(class_name);
+    }
     let mut class = Class::default();
     class.type_str = class_name.clone();
     {
@@ -205,6 +269,10 @@ fn handle_clang_ClassDecl(out_hpp_element: &mut HppElement, entity: &clang::Enti
         if class_name.contains("Callback") {
             class.class_type = ClassType::Callback;
         }
+        // 只有基础数据类型字段的结构体，按值传递
+        if class.class_type == ClassType::Normal && entity.get_type().is_some_and(|class_type| is_pod_struct_type(&class_type)) {
+            class.class_type = ClassType::PodStruct;
+        }
     }
     class.comment_str = entity.get_comment();
     class.souce_file_path = entity.get_location().unwrap().get_presumed_location().0;
@@ -212,6 +280,12 @@ fn handle_clang_ClassDecl(out_hpp_element: &mut HppElement, entity: &clang::Enti
     for child in entity.get_children() {
         visit_parse_clang_entity(&mut element, &child, indent + 1);
     }
+    // POD 结构体按值传递，不需要构造、析构函数和 StdPtr
+    if let HppElement::Class(Class { class_type: ClassType::PodStruct, .. }) = element {
+        out_hpp_element.add_child(element);
+        return;
+    }
+
     // 确保 class 必须有构造和析构函数
     element.ensure_constructor();
     element.ensure_destructor();
@@ -223,411 +297,191 @@ fn handle_clang_ClassDecl(out_hpp_element: &mut HppElement, entity: &clang::Enti
     out_hpp_element.add_child(stdptr_element);
 }
 
-fn post_process_hpp_element(out_gen_context: &mut GenContext, out_hpp_elements: &mut Vec<HppElement>, cur_hpp_element: &HppElement) {
-    match cur_hpp_element {
+/// 标记特化了 std::hash 的类，dart 中为它生成 hashCode
+fn mark_std_hash_class(out_hpp_element: &mut HppElement, entity: &clang::Entity<'_>) {
+    let HppElement::File(file) = out_hpp_element else {
+        return;
+    };
+    let Some(template_args) = entity.get_type().and_then(|hash_type| hash_type.get_template_argument_types()) else {
+        return;
+    };
+    let Some(hashed_type) = template_args.first() else {
+        return;
+    };
+    let hashed_type_str = FieldType::from_clang_type(hashed_type).type_str;
+    mark_std_hash_class_in_children(&mut file.children, &hashed_type_str);
+}
+
+fn mark_std_hash_class_in_children(children: &mut [HppElement], hashed_type_str: &str) {
+    for child in children.iter_mut() {
+        if let HppElement::Class(class) = child {
+            if class.type_str == hashed_type_str {
+                class.has_std_hash = true;
+            }
+            // 嵌套类此时还没有提升到文件层级
+            mark_std_hash_class_in_children(&mut class.children, hashed_type_str);
+        }
+    }
+}
+
+/// 嵌套在类中的类型（class/struct/enum）提升到文件层级，
+/// 之后的代码生成把它们当作独立的类型处理，类型名保留限定名（如 Http::Request）
+fn hoist_nested_types(file_element: &mut HppElement) {
+    let HppElement::File(file) = file_element else {
+        return;
+    };
+    let mut nested_elements = vec![];
+    for child in file.children.iter_mut() {
+        take_nested_types(child, &mut nested_elements);
+    }
+    file.children.extend(nested_elements);
+}
+
+/// .i 文件中的 %buffer 指令，标记方法的指针参数和长度参数，dart 中合并为 TypedData
+fn apply_buffer_params(element: &mut HppElement, buffer_params: &[BufferParam]) {
+    match element {
         HppElement::File(file) => {
-            for child in &file.children {
-                post_process_hpp_element(out_gen_context, out_hpp_elements, child);
+            for child in &mut file.children {
+                apply_buffer_params(child, buffer_params);
             }
         }
         HppElement::Class(class) => {
-            for child in &class.children {
-                post_process_hpp_element(out_gen_context, out_hpp_elements, child);
+            // 回调类的方法由 c++ 调用 dart，不支持
+            if class.is_callback() {
+                return;
             }
-        }
-        HppElement::Method(method) => {
-            // 当用到了某个类型的 std::vector 时，需要生成这个 std::vector 类对应的方法
-            if method.return_type.type_kind == TypeKind::StdVector {
-                let stdvector_element = HppElement::new_stdvector_class_element(&method.return_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdvector_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
+            let class_name = class.type_str.clone();
+            for class_child in &mut class.children {
+                match class_child {
+                    HppElement::Method(method) => {
+                        let method_name = format!("{}::{}", class_name, method.name);
+                        apply_buffer_params_to_method(method, &method_name, buffer_params);
                     }
-                });
-                
-                if !already_exists {
-                    match &stdvector_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdvector_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
+                    // 还没有提升到文件层级的嵌套类
+                    HppElement::Class(_) => {
+                        apply_buffer_params(class_child, buffer_params);
                     }
+                    _ => {}
                 }
             }
-            // 处理 std::map 返回类型
-            else if method.return_type.type_kind == TypeKind::StdMap {
-                let stdmap_element = HppElement::new_stdmap_class_element(&method.return_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdmap_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdmap_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdmap_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
+        }
+        HppElement::Method(method) => {
+            let method_name = method.name.clone();
+            apply_buffer_params_to_method(method, &method_name, buffer_params);
+        }
+        _ => {}
+    }
+}
+
+fn apply_buffer_params_to_method(method: &mut Method, method_name: &str, buffer_params: &[BufferParam]) {
+    for buffer_param in buffer_params.iter().filter(|buffer_param| buffer_param.method_name == method_name) {
+        let is_len_valid = method.params.iter().any(|param| param.name == buffer_param.len_param
+            && param.field_type.ptr_level == 0 && param.field_type.type_kind.is_integer());
+        let Some(ptr_param) = method.params.iter_mut().find(|param| param.name == buffer_param.ptr_param) else {
+            eprintln!("[%buffer] {} 中没有参数 {}", method_name, buffer_param.ptr_param);
+            continue;
+        };
+        if !is_len_valid || ptr_param.field_type.ptr_level != 1 || !ptr_param.field_type.type_kind.is_typed_data_element() {
+            eprintln!("[%buffer] {} 的参数 {}、{} 不是基础数据类型的指针和整数长度", method_name, buffer_param.ptr_param, buffer_param.len_param);
+            continue;
+        }
+        ptr_param.field_type.buffer_len_param = Some(buffer_param.len_param.clone());
+    }
+}
+
+fn take_nested_types(element: &mut HppElement, out_nested_elements: &mut Vec<HppElement>) {
+    let HppElement::Class(class) = element else {
+        return;
+    };
+    let children = std::mem::take(&mut class.children);
+    for mut child in children {
+        match child {
+            HppElement::Class(_) => {
+                take_nested_types(&mut child, out_nested_elements);
+                out_nested_elements.push(child);
             }
-            // 处理 std::unordered_map 返回类型
-            else if method.return_type.type_kind == TypeKind::StdUnorderedMap {
-                let stdunorderedmap_element = HppElement::new_stdunorderedmap_class_element(&method.return_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdunorderedmap_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdunorderedmap_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdunorderedmap_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
+            HppElement::Enum(_) => {
+                out_nested_elements.push(child);
             }
-            // 处理 std::set 返回类型
-            else if method.return_type.type_kind == TypeKind::StdSet {
-                let stdset_element = HppElement::new_stdset_class_element(&method.return_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdset_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdset_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdset_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
+            _ => {
+                class.children.push(child);
             }
-            // 处理 std::unordered_set 返回类型
-            else if method.return_type.type_kind == TypeKind::StdUnorderedSet {
-                let stdunorderedset_element = HppElement::new_stdunorderedset_class_element(&method.return_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdunorderedset_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdunorderedset_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdunorderedset_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
+        }
+    }
+}
+
+/// 类型对应的生成类（容器、optional、std::function、weak_ptr、variant、pair、tuple），其它类型为 None
+fn new_generated_class_element(field_type: &FieldType) -> Option<HppElement> {
+    let generated_element = match field_type.type_kind {
+        TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque => HppElement::new_stdvector_class_element(field_type),
+        TypeKind::StdMap | TypeKind::StdMultimap => HppElement::new_stdmap_class_element(field_type),
+        TypeKind::StdUnorderedMap => HppElement::new_stdunorderedmap_class_element(field_type),
+        TypeKind::StdSet => HppElement::new_stdset_class_element(field_type),
+        TypeKind::StdUnorderedSet => HppElement::new_stdunorderedset_class_element(field_type),
+        TypeKind::StdOptional => HppElement::new_stdoptional_class_element(field_type),
+        TypeKind::StdFunction => HppElement::new_stdfunction_class_element(field_type),
+        TypeKind::StdWeakPtr => HppElement::new_stdweakptr_class_element(field_type),
+        TypeKind::StdVariant => HppElement::new_stdvariant_class_element(field_type),
+        TypeKind::StdPair | TypeKind::StdTuple => HppElement::new_stdtuple_class_element(field_type),
+        _ => return None,
+    };
+    Some(generated_element)
+}
+
+/// 当用到了某个类型的容器、optional 等时，需要生成对应的类，已经添加过时跳过
+/// 容器、optional 的键、值类型，以及生成类的方法中用到的类型（如 weak_ptr 的 lock() 返回的 optional）也一起添加
+fn add_generated_class_element(out_gen_context: &mut GenContext, out_hpp_elements: &mut Vec<HppElement>, field_type: &FieldType) {
+    let Some(generated_element) = new_generated_class_element(field_type) else {
+        return;
+    };
+    let HppElement::Class(new_cls) = &generated_element else {
+        return;
+    };
+    let already_exists = out_hpp_elements.iter().any(|element| matches!(element, HppElement::Class(cls) if cls.type_str == new_cls.type_str));
+    if already_exists {
+        return;
+    }
+    if matches!(field_type.type_kind,
+        TypeKind::StdVector | TypeKind::StdList | TypeKind::StdDeque | TypeKind::StdMap | TypeKind::StdMultimap |
+        TypeKind::StdUnorderedMap | TypeKind::StdSet | TypeKind::StdUnorderedSet | TypeKind::StdOptional) {
+        out_hpp_elements.push(generated_element);
+        // 元素类型中用到的容器类型
+        post_process_container_element_types(out_gen_context, out_hpp_elements, field_type);
+    } else {
+        post_process_hpp_element(out_gen_context, out_hpp_elements, &generated_element);
+        out_hpp_elements.push(generated_element);
+    }
+}
+
+fn post_process_hpp_element(out_gen_context: &mut GenContext, out_hpp_elements: &mut Vec<HppElement>, cur_hpp_element: &HppElement) {
+    match cur_hpp_element {
+        HppElement::File(file) => {
+            for child in &file.children {
+                post_process_hpp_element(out_gen_context, out_hpp_elements, child);
             }
+        }
+        HppElement::Class(class) => {
+            for child in &class.children {
+                post_process_hpp_element(out_gen_context, out_hpp_elements, child);
+            }
+        }
+        HppElement::Method(method) => {
+            add_generated_class_element(out_gen_context, out_hpp_elements, &method.return_type);
             for param in &method.params {
-                if param.field_type.type_kind == TypeKind::StdVector {
-                        let stdvector_element = HppElement::new_stdvector_class_element(&param.field_type);
-                        let already_exists = out_hpp_elements.iter().any(|element| {
-                            match element {
-                                HppElement::Class(cls) => {
-                                    match &stdvector_element {
-                                        HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                        _ => false,
-                                    }
-                                },
-                                _ => false,
-                            }
-                        });
-                        
-                        if !already_exists {
-                            match &stdvector_element {
-                                HppElement::Class(_) => {
-                                    out_hpp_elements.push(stdvector_element);
-                                }
-                                _ffi => {
-                                    unimplemented!("post_process_hpp_element unimplemented");
-                                }
-                            }
-                        }
-                }
-                // 处理 std::map 参数类型
-                else if param.field_type.type_kind == TypeKind::StdMap {
-                        let stdmap_element = HppElement::new_stdmap_class_element(&param.field_type);
-                        let already_exists = out_hpp_elements.iter().any(|element| {
-                            match element {
-                                HppElement::Class(cls) => {
-                                    match &stdmap_element {
-                                        HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                        _ => false,
-                                    }
-                                },
-                                _ => false,
-                            }
-                        });
-                        
-                        if !already_exists {
-                            match &stdmap_element {
-                                HppElement::Class(_) => {
-                                    out_hpp_elements.push(stdmap_element);
-                                }
-                                _ffi => {
-                                    unimplemented!("post_process_hpp_element unimplemented");
-                                }
-                            }
-                        }
-                }
-                // 处理 std::unordered_map 参数类型
-                else if param.field_type.type_kind == TypeKind::StdUnorderedMap {
-                        let stdunorderedmap_element = HppElement::new_stdunorderedmap_class_element(&param.field_type);
-                        let already_exists = out_hpp_elements.iter().any(|element| {
-                            match element {
-                                HppElement::Class(cls) => {
-                                    match &stdunorderedmap_element {
-                                        HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                        _ => false,
-                                    }
-                                },
-                                _ => false,
-                            }
-                        });
-                        
-                        if !already_exists {
-                            match &stdunorderedmap_element {
-                                HppElement::Class(_) => {
-                                    out_hpp_elements.push(stdunorderedmap_element);
-                                }
-                                _ffi => {
-                                    unimplemented!("post_process_hpp_element unimplemented");
-                                }
-                            }
-                        }
-                }
-                // 处理 std::set 参数类型
-                else if param.field_type.type_kind == TypeKind::StdSet {
-                        let stdset_element = HppElement::new_stdset_class_element(&param.field_type);
-                        let already_exists = out_hpp_elements.iter().any(|element| {
-                            match element {
-                                HppElement::Class(cls) => {
-                                    match &stdset_element {
-                                        HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                        _ => false,
-                                    }
-                                },
-                                _ => false,
-                            }
-                        });
-                        
-                        if !already_exists {
-                            match &stdset_element {
-                                HppElement::Class(_) => {
-                                    out_hpp_elements.push(stdset_element);
-                                }
-                                _ffi => {
-                                    unimplemented!("post_process_hpp_element unimplemented");
-                                }
-                            }
-                        }
-                }
-                // 处理 std::unordered_set 参数类型
-                else if param.field_type.type_kind == TypeKind::StdUnorderedSet {
-                        let stdunorderedset_element = HppElement::new_stdunorderedset_class_element(&param.field_type);
-                        let already_exists = out_hpp_elements.iter().any(|element| {
-                            match element {
-                                HppElement::Class(cls) => {
-                                    match &stdunorderedset_element {
-                                        HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                        _ => false,
-                                    }
-                                },
-                                _ => false,
-                            }
-                        });
-                        
-                        if !already_exists {
-                            match &stdunorderedset_element {
-                                HppElement::Class(_) => {
-                                    out_hpp_elements.push(stdunorderedset_element);
-                                }
-                                _ffi => {
-                                    unimplemented!("post_process_hpp_element unimplemented");
-                                }
-                            }
-                        }
-                }
+                add_generated_class_element(out_gen_context, out_hpp_elements, &param.field_type);
             }
         }
         HppElement::Field(field) => {
-            if field.field_type.type_kind == TypeKind::StdVector {
-                let stdvector_element = HppElement::new_stdvector_class_element(&field.field_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdvector_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdvector_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdvector_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
-            }
-            // 处理 std::map 字段类型
-            else if field.field_type.type_kind == TypeKind::StdMap {
-                let stdmap_element = HppElement::new_stdmap_class_element(&field.field_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdmap_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdmap_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdmap_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
-            }
-            // 处理 std::unordered_map 字段类型
-            else if field.field_type.type_kind == TypeKind::StdUnorderedMap {
-                let stdunorderedmap_element = HppElement::new_stdunorderedmap_class_element(&field.field_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdunorderedmap_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdunorderedmap_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdunorderedmap_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
-            }
-            // 处理 std::set 字段类型
-            else if field.field_type.type_kind == TypeKind::StdSet {
-                let stdset_element = HppElement::new_stdset_class_element(&field.field_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdset_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdset_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdset_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
-            }
-            // 处理 std::unordered_set 字段类型
-            else if field.field_type.type_kind == TypeKind::StdUnorderedSet {
-                let stdunorderedset_element = HppElement::new_stdunorderedset_class_element(&field.field_type);
-                let already_exists = out_hpp_elements.iter().any(|element| {
-                    match element {
-                        HppElement::Class(cls) => {
-                            match &stdunorderedset_element {
-                                HppElement::Class(new_cls) => cls.type_str == new_cls.type_str,
-                                _ => false,
-                            }
-                        },
-                        _ => false,
-                    }
-                });
-                
-                if !already_exists {
-                    match &stdunorderedset_element {
-                        HppElement::Class(_) => {
-                            out_hpp_elements.push(stdunorderedset_element);
-                        }
-                        _ffi => {
-                            unimplemented!("post_process_hpp_element unimplemented");
-                        }
-                    }
-                }
-            }
+            add_generated_class_element(out_gen_context, out_hpp_elements, &field.field_type);
+        }
+        HppElement::Variable(variable) => {
+            // 变量的 getter / setter 用到的容器类型，和字段一样处理
+            let field = Field {
+                name: variable.name.clone(),
+                field_type: variable.field_type.clone(),
+                comment_str: None,
+            };
+            post_process_hpp_element(out_gen_context, out_hpp_elements, &HppElement::Field(field));
         }
         _ => {
             // do nothing
@@ -635,6 +489,19 @@ fn post_process_hpp_element(out_gen_context: &mut GenContext, out_hpp_elements:
     }
 }
 
+/// 容器、optional 的键、值类型也是容器时（如 std::optional<std::vector<int>>、std::map<std::string, std::vector<Item>>），
+/// 同样需要生成容器类，多层嵌套时递归生成
+fn post_process_container_element_types(out_gen_context: &mut GenContext, out_hpp_elements: &mut Vec<HppElement>, field_type: &FieldType) {
+    for element_type in [field_type.key_type.as_ref(), field_type.value_type.as_ref()].into_iter().flatten() {
+        let field = Field {
+            name: "value".to_string(),
+            field_type: (**element_type).clone(),
+            comment_str: None,
+        };
+        post_process_hpp_element(out_gen_context, out_hpp_elements, &HppElement::Field(field));
+    }
+}
+
 fn handle_clang_Constructor(out_hpp_element: &mut HppElement, entity: &clang::Entity<'_>, indent: usize) {
     match out_hpp_element {
         HppElement::Class(class) => {
@@ -650,6 +517,8 @@ fn handle_clang_Constructor(out_hpp_element: &mut HppElement, entity: &clang::En
                     let simplified_type = simplify_type_for_naming(&param.field_type.type_str);
                     method_name.push_str(&format!("_{}", simplified_type));
                 }
+                // 构造函数的名字只包含声明的参数，之后再加上长度参数，和普通方法一样支持字符串中间的 0
+                add_len_params(updated_method, !class.is_callback());
                 updated_method.comment_str = entity.get_comment();
                 updated_method.method_type = MethodType::Constructor;
                 updated_method.name = method_name;
@@ -702,10 +571,20 @@ fn handle_clang_Method(out_hpp_element: &mut HppElement, entity: &clang::Entity<
             return;
         }
     }
+    // 重载操作符，只 bridge dart 中有对应操作符的那些
+    let mut operator_str = None;
     if let Some(name) = entity.get_name() {
-        if name.starts_with("operator") {
-            // 说明 entity 是一个重载操作符方法，不 bridge 重载函数
-            return;
+        if let Some(cur_operator_str) = name.strip_prefix("operator") {
+            let param_count = entity.get_arguments().map(|args| args.len()).unwrap_or(0);
+            if get_operator_method_name(cur_operator_str.trim(), param_count).is_none() {
+                return;
+            }
+            if let HppElement::Class(class) = out_hpp_element {
+                if class.is_callback() {
+                    return;
+                }
+            }
+            operator_str = Some(cur_operator_str.trim().to_string());
         }
         // 跳过以下划线开头的方法（通常是内部/私有方法）
         if name.starts_with("_") {
@@ -740,6 +619,19 @@ fn handle_clang_Method(out_hpp_element: &mut HppElement, entity: &clang::Entity<
     method.comment_str = entity.get_comment();
     // 检查是否为静态方法
     method.is_static = entity.is_static_method();
+    method.is_const = entity.is_const_method();
+    if let Some(cur_operator_str) = operator_str {
+        let param_count = entity.get_arguments().map(|args| args.len()).unwrap_or(0);
+        method.name = get_operator_method_name(&cur_operator_str, param_count).unwrap();
+        method.operator_str = Some(cur_operator_str);
+        // dart 中不能重载，同名的操作符只保留第一个
+        if let HppElement::Class(class) = out_hpp_element {
+            let is_exists = class.children.iter().any(|child| matches!(child, HppElement::Method(m) if m.name == method.name));
+            if is_exists {
+                return;
+            }
+        }
+    }
 
     // 检查是否为同步回调（仅对回调类的方法有效）
     if let HppElement::Class(class) = out_hpp_element {
@@ -747,11 +639,24 @@ fn handle_clang_Method(out_hpp_element: &mut HppElement, entity: &clang::Entity<
             method.is_sync_callback = is_sync_callback_from_comment(&method.comment_str);
         }
     }
+    method.is_leaf = is_leaf_from_comment(&method.comment_str);
 
     // 跳过返回值类型被忽略的方法
     if method.return_type.type_kind == TypeKind::Ignored {
         return;
     }
+    // std::function 只支持作为参数，不能返回给 dart
+    if method.return_type.type_kind == TypeKind::StdFunction {
+        return;
+    }
+    // std::array 只支持作为字段、变量，按值返回的数组在调用结束后就释放了
+    if method.return_type.is_std_array() {
+        return;
+    }
+    // std::span 只支持作为参数，返回值引用的内存无法确定生命周期
+    if method.return_type.is_std_span() {
+        return;
+    }
 
     // Callback 方法现在支持所有返回值类型了
     // - void 返回值使用异步调用（Dart_PostCObject_DL）
@@ -769,12 +674,95 @@ fn handle_clang_Method(out_hpp_element: &mut HppElement, entity: &clang::Entity<
                 // 跳过包含被忽略类型的方法
                 return;
             }
+            if param.field_type.is_std_array() {
+                return;
+            }
+        }
+        // 回调类中无法桥接的方法不生成，纯虚函数不生成时 Impl 子类无法实例化，解析结束后报错
+        if let HppElement::Class(class) = out_hpp_element {
+            if class.is_callback() {
+                if let Some(unsupported_type) = get_callback_unsupported_type(method) {
+                    let method_desc = format!("{}::{}（{}）", class.type_str, method.name, unsupported_type);
+                    if entity.is_pure_virtual_method() {
+                        class.unsupported_pure_virtual_methods.push(method_desc);
+                    } else {
+                        eprintln!("[callback] 跳过 {}，回调方法不支持这个类型", method_desc);
+                    }
+                    return;
+                }
+            }
         }
     }
 
+    // 重载操作符的参数经常不写名字，补上
+    if let HppElement::Method(ref mut method) = element {
+        if method.operator_str.is_some() {
+            let param_count = method.params.len();
+            for (index, param) in method.params.iter_mut().enumerate() {
+                if param.name.is_empty() {
+                    param.name = if param_count == 1 { "other".to_string() } else { format!("arg{}", index) };
+                }
+            }
+        }
+    }
+
+    // std::span 拆分为指针和长度参数，std::string、std::string_view、std::u16string 参数传递显式的长度，支持字符串中间的 0
+    let is_callback_class = matches!(out_hpp_element, HppElement::Class(class) if class.is_callback());
+    if let HppElement::Method(ref mut method) = element {
+        add_len_params(method, !is_callback_class);
+    }
+
     out_hpp_element.add_child(element);
 }
 
+/// 重载操作符在 ffi 中使用的函数名，dart 中没有对应操作符的返回 None
+/// param_count 用于区分一元和二元操作符（如 -a 和 a - b）
+/// 回调类的方法由 c++ 调用 dart，std::function、std::unique_ptr、std::weak_ptr、std::span、std::u16string、POD 结构体参数无法从 c++ 传给 dart，
+/// std::string_view 的参数、返回值在 dart 中使用时可能已经失效，返回第一个不支持的类型
+fn get_callback_unsupported_type(method: &Method) -> Option<String> {
+    for param in &method.params {
+        let is_unsupported = matches!(param.field_type.type_kind,
+            TypeKind::StdFunction | TypeKind::StdUniquePtr | TypeKind::StdWeakPtr | TypeKind::U16String | TypeKind::PodStruct)
+            || param.field_type.is_std_span() || param.field_type.string_kind == StringKind::StringView;
+        if is_unsupported {
+            return Some(param.field_type.full_str.clone());
+        }
+    }
+    let is_unsupported_return = matches!(method.return_type.type_kind, TypeKind::U16String | TypeKind::PodStruct)
+        || method.return_type.is_borrowed_string();
+    if is_unsupported_return {
+        return Some(method.return_type.full_str.clone());
+    }
+    None
+}
+
+fn get_operator_method_name(operator_str: &str, param_count: usize) -> Option<String> {
+    let name = match (operator_str, param_count) {
+        ("+", 1) => "add",
+        ("-", 1) => "sub",
+        ("-", 0) => "neg",
+        ("*", 1) => "mul",
+        ("/", 1) => "div",
+        ("%", 1) => "mod",
+        ("==", 1) => "eq",
+        ("<", 1) => "lt",
+        ("<=", 1) => "le",
+        (">", 1) => "gt",
+        (">=", 1) => "ge",
+        ("&", 1) => "bit_and",
+        ("|", 1) => "bit_or",
+        ("^", 1) => "bit_xor",
+        ("~", 0) => "bit_not",
+        ("<<", 1) => "shl",
+        (">>", 1) => "shr",
+        ("[]", 1) => "index",
+        ("()", _) => "call",
+        _ => return None,
+    };
+
+    Some(format!("operator_{}", name))
+}
+
 fn handle_clang_ParmDecl(out_hpp_element: &mut HppElement, entity: &clang::Entity<'_>) {
     match out_hpp_element {
         HppElement::Method(method) => {
@@ -789,7 +777,6 @@ fn handle_clang_ParmDecl(out_hpp_element: &mut HppElement, entity: &clang::Entit
             // }
 
             param.field_type = FieldType::from_clang_type(&entity.get_type());
-
             method.params.push(param);
         }
         _ => {
@@ -817,6 +804,10 @@ fn handle_clang_FieldDecl(out_hpp_element: &mut HppElement, entity: &clang::Enti
     if field.field_type.type_kind == TypeKind::Ignored {
         return;
     }
+    // std::span 只支持作为参数
+    if field.field_type.is_std_span() {
+        return;
+    }
 
     let mut element = HppElement::Field(field);
     for child in entity.get_children() {
@@ -876,13 +867,70 @@ fn handle_clang_FunctionDecl(out_hpp_element: &mut HppElement, entity: &clang::E
     method.return_type = FieldType::from_clang_type(&entity.get_result_type());
     method.comment_str = entity.get_comment();
 
+    // std::span 只支持作为参数，返回值引用的内存无法确定生命周期
+    if method.return_type.is_std_span() {
+        return;
+    }
+
     let mut element = HppElement::Method(method);
     for child in entity.get_children() {
         visit_parse_clang_entity(&mut element, &child, indent + 1);
     }
+    // std::span 拆分为指针和长度参数，std::string、std::string_view、std::u16string 参数传递显式的长度，支持字符串中间的 0
+    if let HppElement::Method(ref mut method) = element {
+        add_len_params(method, true);
+    }
     out_hpp_element.add_child(element);
 }
 
+/// 在 std::span 参数后面加上长度参数，c++ 中再用指针和长度构造 std::span
+/// is_string_len 时字符串参数后面也加上长度参数，见 FieldType.buffer_len_param
+/// 头文件中经常已经有 data、data_len 这样的参数，长度参数的名字不能和已有的参数重复
+fn add_len_params(method: &mut Method, is_string_len: bool) {
+    let mut used_names: Vec<String> = method.params.iter().map(|param| param.name.clone()).collect();
+    let mut params = Vec::new();
+    for mut param in method.params.drain(..) {
+        // const char* 以 0 结尾，不需要长度
+        let is_string = (param.field_type.type_kind == TypeKind::String && param.field_type.string_kind != StringKind::CString)
+            || param.field_type.type_kind == TypeKind::U16String;
+        if !(param.field_type.is_std_span() || (is_string_len && is_string)) {
+            params.push(param);
+            continue;
+        }
+        let len_param = MethodParam {
+            name: get_unique_param_name(&format!("{}_len", param.name), &used_names),
+            field_type: FieldType::new_size_t(),
+        };
+        used_names.push(len_param.name.clone());
+        param.field_type.buffer_len_param = Some(len_param.name.clone());
+        params.push(param);
+        params.push(len_param);
+    }
+    // 字符串返回值通过最后的输出参数返回长度
+    let is_string_return = (method.return_type.type_kind == TypeKind::String && method.return_type.string_kind != StringKind::CString)
+        || method.return_type.type_kind == TypeKind::U16String;
+    if is_string_len && is_string_return && method.return_type.ptr_level == 0 {
+        let ret_len_param = MethodParam {
+            name: get_unique_param_name("ret_len", &used_names),
+            field_type: FieldType { ptr_level: 1, ..FieldType::new_size_t() },
+        };
+        method.return_type.buffer_len_param = Some(ret_len_param.name.clone());
+        params.push(ret_len_param);
+    }
+    method.params = params;
+}
+
+/// 和已有参数不重复的参数名，重复时加上数字后缀，如 data_len1
+fn get_unique_param_name(name: &str, used_names: &[String]) -> String {
+    let mut unique_name = name.to_string();
+    let mut index = 1;
+    while used_names.contains(&unique_name) {
+        unique_name = format!("{}{}", name, index);
+        index += 1;
+    }
+    unique_name
+}
+
 fn handle_clang_EnumDecl(out_hpp_element: &mut HppElement, entity: &clang::Entity<'_>, _indent: usize) {
     // 如果是前向声明，跳过
     if !entity.is_definition() {
@@ -904,6 +952,8 @@ fn handle_clang_EnumDecl(out_hpp_element: &mut HppElement, entity: &clang::Entit
     if name.is_empty() {
         return; // 匿名 enum，跳过
     }
+    // 嵌套在类中的 enum 使用限定名，如 Image::Format
+    let name = get_nested_type_name(entity).unwrap_or(name);
 
     // 检查是否为 enum class（scoped enum）
     let is_scoped = entity.is_scoped();
@@ -930,3 +980,223 @@ fn handle_clang_EnumDecl(out_hpp_element: &mut HppElement, entity: &clang::Entit
 
     out_hpp_element.add_child(HppElement::Enum(enum_def));
 }
+
+/// 全局变量和类的静态成员变量，编译期能求值的 const 变量作为常量，其余的作为变量
+fn handle_clang_VarDecl(out_hpp_element: &mut HppElement, entity: &clang::Entity<'_>) {
+    // 只处理全局（命名空间）变量和类的静态成员，函数体内的局部变量跳过
+    match out_hpp_element {
+        HppElement::File(file) => {
+            if file.path != entity.get_location().unwrap().get_presumed_location().0 {
+                return;
+            }
+        }
+        HppElement::Class(_) => {
+            if let Some(access) = entity.get_accessibility() {
+                if access != clang::Accessibility::Public {
+                    return;
+                }
+            }
+        }
+        _ => {
+            return;
+        }
+    }
+
+    let name = entity.get_name().unwrap_or_default();
+    if name.is_empty() || name.starts_with("_") {
+        return;
+    }
+    let Some(clang_type) = entity.get_type() else {
+        return;
+    };
+    let field_type = FieldType::from_clang_type(&Some(clang_type));
+    // std::function 变量的 getter 无法生成
+    if field_type.type_kind == TypeKind::Ignored || field_type.type_kind == TypeKind::StdFunction {
+        return;
+    }
+    let is_const = clang_type.is_const_qualified();
+
+    // 编译期能求值的 const 变量作为常量
+    if is_const {
+        if let Some(value) = entity.evaluate().and_then(|result| get_constant_value(&field_type, result)) {
+            let constant = Constant {
+                name,
+                field_type,
+                value,
+                comment_str: entity.get_comment(),
+            };
+            out_hpp_element.add_child(HppElement::Constant(constant));
+            return;
+        }
+    }
+
+    // 其余的作为变量，生成 getter / setter
+    let variable = Variable {
+        name,
+        cpp_name: get_qualified_name(entity),
+        field_type,
+        is_const,
+        comment_str: entity.get_comment(),
+    };
+    out_hpp_element.add_child(HppElement::Variable(variable));
+}
+
+/// clang 的求值结果转换为常量的值
+fn get_constant_value(field_type: &FieldType, result: clang::EvaluationResult) -> Option<ConstantValue> {
+    match result {
+        clang::EvaluationResult::SignedInteger(value) => {
+            if field_type.type_kind == TypeKind::Bool {
+                return Some(ConstantValue::Bool(value != 0));
+            }
+            Some(ConstantValue::Int(value))
+        }
+        clang::EvaluationResult::UnsignedInteger(value) => {
+            if field_type.type_kind == TypeKind::Bool {
+                return Some(ConstantValue::Bool(value != 0));
+            }
+            Some(ConstantValue::UInt(value))
+        }
+        clang::EvaluationResult::Float(value) => {
+            if !value.is_finite() {
+                return None;
+            }
+            Some(ConstantValue::Float(format!("{:?}", value)))
+        }
+        clang::EvaluationResult::String(value) => {
+            Some(ConstantValue::String(value.to_string_lossy().to_string()))
+        }
+        _ => {
+            None
+        }
+    }
+}
+
+/// 简单的 #define 常量，如 #define VERSION 3、#define SDK_KEY "abc"，
+/// 只处理值为单个字面量（可以带负号和括号）的宏，函数宏和表达式宏跳过
+fn handle_clang_MacroDefinition(out_hpp_element: &mut HppElement, entity: &clang::Entity<'_>) {
+    if entity.is_builtin_macro() || entity.is_function_like_macro() {
+        return;
+    }
+    let HppElement::File(file) = out_hpp_element else {
+        return;
+    };
+    let Some(location) = entity.get_location() else {
+        return;
+    };
+    if file.path != location.get_presumed_location().0 {
+        return;
+    }
+
+    let name = entity.get_name().unwrap_or_default();
+    if name.is_empty() || name.starts_with("_") {
+        return;
+    }
+    let Some(range) = entity.get_range() else {
+        return;
+    };
+    // 第一个 token 是宏的名字
+    let mut tokens: Vec<String> = range.tokenize().iter().skip(1).map(|token| token.get_spelling()).collect();
+    while tokens.len() >= 2 && tokens.first().unwrap() == "(" && tokens.last().unwrap() == ")" {
+        tokens = tokens[1..tokens.len() - 1].to_vec();
+    }
+    let literal = tokens.join("");
+    let Some(value) = get_macro_constant_value(&literal) else {
+        return;
+    };
+
+    let field_type = match value {
+        ConstantValue::Int(_) => FieldType { full_str: "int64_t".to_string(), type_str: "int64_t".to_string(), type_kind: TypeKind::Int64, ..Default::default() },
+        ConstantValue::UInt(_) => FieldType { full_str: "uint64_t".to_string(), type_str: "uint64_t".to_string(), type_kind: TypeKind::UInt64, ..Default::default() },
+        ConstantValue::Float(_) => FieldType { full_str: "double".to_string(), type_str: "double".to_string(), type_kind: TypeKind::Double, ..Default::default() },
+        ConstantValue::Bool(_) => FieldType { full_str: "bool".to_string(), type_str: "bool".to_string(), type_kind: TypeKind::Bool, ..Default::default() },
+        ConstantValue::String(_) => FieldType { full_str: "std::string".to_string(), type_str: "String".to_string(), type_kind: TypeKind::String, ..Default::default() },
+    };
+    let constant = Constant {
+        name,
+        field_type,
+        value,
+        comment_str: entity.get_comment(),
+    };
+    out_hpp_element.add_child(HppElement::Constant(constant));
+}
+
+#[test]
+fn test_get_macro_constant_value() {
+    assert_eq!(get_macro_constant_value("3"), Some(ConstantValue::Int(3)));
+    assert_eq!(get_macro_constant_value("-0x10"), Some(ConstantValue::Int(-16)));
+    assert_eq!(get_macro_constant_value("-9223372036854775808"), Some(ConstantValue::Int(i64::MIN)));
+    assert_eq!(get_macro_constant_value("-9223372036854775809"), None);
+    assert_eq!(get_macro_constant_value("0xFFFFFFFFFFFFFFFFull"), Some(ConstantValue::UInt(u64::MAX)));
+    assert_eq!(get_macro_constant_value("\"abc\""), Some(ConstantValue::String("abc".to_string())));
+    assert_eq!(get_macro_constant_value("VERSION"), None);
+}
+
+/// 解析宏的字面量值，不是字面量时返回 None
+fn get_macro_constant_value(literal: &str) -> Option<ConstantValue> {
+    if literal.is_empty() {
+        return None;
+    }
+    match literal {
+        "true" => return Some(ConstantValue::Bool(true)),
+        "false" => return Some(ConstantValue::Bool(false)),
+        _ => {}
+    }
+
+    // 字符串，只处理普通的 "..."
+    if literal.len() >= 2 && literal.starts_with('"') && literal.ends_with('"') {
+        let content = &literal[1..literal.len() - 1];
+        let value = content
+            .replace("\\\\", "\u{0}")
+            .replace("\\\"", "\"")
+            .replace("\\n", "\n")
+            .replace("\\t", "\t")
+            .replace("\u{0}", "\\");
+        return Some(ConstantValue::String(value));
+    }
+
+    let (is_negative, number) = match literal.strip_prefix('-') {
+        Some(number) => (true, number),
+        None => (false, literal),
+    };
+    let lower_number = number.to_lowercase();
+    if !lower_number.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+
+    // 整数，去掉 u、l 后缀
+    let int_str = lower_number.trim_end_matches(['u', 'l']);
+    let int_value = if let Some(hex) = int_str.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16).ok()
+    } else if let Some(bin) = int_str.strip_prefix("0b") {
+        u64::from_str_radix(bin, 2).ok()
+    } else if int_str.len() > 1 && int_str.starts_with('0') {
+        u64::from_str_radix(&int_str[1..], 8).ok()
+    } else {
+        int_str.parse::<u64>().ok()
+    };
+    if let Some(value) = int_value {
+        // 负数超出 i64 范围时不是有效的常量，正数超出 i64 范围时作为无符号整数
+        if is_negative {
+            return 0i64.checked_sub_unsigned(value).map(ConstantValue::Int);
+        }
+        return Some(match i64::try_from(value) {
+            Ok(value) => ConstantValue::Int(value),
+            Err(_) => ConstantValue::UInt(value),
+        });
+    }
+
+    // 浮点数，去掉 f、l 后缀
+    if lower_number.starts_with("0x") {
+        return None;
+    }
+    let float_str = lower_number.trim_end_matches(['f', 'l']);
+    if let Ok(value) = float_str.parse::<f64>() {
+        if !value.is_finite() {
+            return None;
+        }
+        let value = if is_negative { -value } else { value };
+        return Some(ConstantValue::Float(format!("{:?}", value)));
+    }
+
+    None
+}
@@ -1,4 +1,5 @@
 import 'dart:ffi'; // Import dart:ffi
+import 'dart:typed_data';
 
 import 'package:flutter_test/flutter_test.dart';
 import 'package:flutter/material.dart';
@@ -97,8 +98,8 @@ void main() {
 
       // Test getStruct and processStruct
       final simpleStruct = t.getStruct();
-      expect(simpleStruct.get_id(), 101);
-      expect(simpleStruct.get_name(), "StructName");
+      expect(simpleStruct.id, 101);
+      expect(simpleStruct.name, "StructName");
       // Modify the struct before sending it back (if mutable, otherwise create new)
       // Assuming SimpleStruct is mutable or we create a new one for processStruct
       // Note: Direct modification might not be possible if it's a final class in Dart.
@@ -172,8 +173,8 @@ void main() {
       final testStruct = SimpleStruct.Constructor();
       t.triggerGetStructCallback(10240, "st");
       await Future.delayed(Duration(milliseconds: 100));
-      expect(callbackImpl_onGetStruct_value?.get_id(), 10240);
-      expect(callbackImpl_onGetStruct_value?.get_name(), "st");
+      expect(callbackImpl_onGetStruct_value?.id, 10240);
+      expect(callbackImpl_onGetStruct_value?.name, "st");
 
       // Trigger the vector callback
       StdVector_float? callbackImpl_onGetVector_value = null;
@@ -186,7 +187,8 @@ void main() {
       expect(callbackImpl_onGetVector_value?.size(), 0);
 
       // Trigger the const callback
-      Pointer<Int8>? callbackImpl_onGetConst_value = null;
+      // unsigned char is Uint8 in dart ffi
+      Pointer<Uint8>? callbackImpl_onGetConst_value = null;
       int callbackImpl_onGetConst_size = 0;
       callbackImpl.onGetConst_block = (v, size) {
         callbackImpl_onGetConst_value = v;
@@ -354,8 +356,8 @@ void main() {
       // expect(sharedStructPtr.ref.id, 202); // Example if it's a Pointer<SimpleStruct>
       // expect(sharedStructPtr.ref.name.toDartString(), "SharedStructName"); // Example
       // OR if the generated code returns a Dart class instance directly:
-      expect(sharedStructPtr.get().get_id(), 202);
-      expect(sharedStructPtr.get().get_name(), "SharedStructName");
+      expect(sharedStructPtr.get().id, 202);
+      expect(sharedStructPtr.get().name, "SharedStructName");
 
       // Test processSharedStruct
       t.processSharedStruct(sharedStructPtr); // Pass the obtained shared_ptr back
@@ -375,11 +377,12 @@ void main() {
         3: "Three"
       };
       
-      // Convert to StdMap using convenience constructor
+      // Bulk convenience constructor copies the whole collection in one call
       final stdInputMap = StdMap_int_String.fromMap(inputMap);
-      
-      // Test sending StdMap to C++ and getting result back
-      final resultMap = t.testStdMap(stdInputMap);
+      expect(stdInputMap.length, inputMap.length);
+
+      // Dart collections are passed to C++ directly and getting result back
+      final resultMap = t.testStdMap(inputMap);
       expect(resultMap, isA<StdMap_String_int>());
       expect(resultMap.length, 3);
       expect(resultMap["One"], 1);
@@ -399,11 +402,12 @@ void main() {
         "Three": 3
       };
       
-      // Convert to StdUnorderedMap using convenience constructor
+      // Bulk convenience constructor copies the whole collection in one call
       final stdInputMap = StdUnorderedMap_String_int.fromMap(inputMap);
-      
-      // Test sending StdUnorderedMap to C++ and getting result back
-      final resultMap = t.testStdUnorderedMap(stdInputMap);
+      expect(stdInputMap.length, inputMap.length);
+
+      // Dart collections are passed to C++ directly and getting result back
+      final resultMap = t.testStdUnorderedMap(inputMap);
       expect(resultMap, isA<StdUnorderedMap_int_String>());
       expect(resultMap.length, 3);
       expect(resultMap[1], "One");
@@ -419,11 +423,12 @@ void main() {
       // Create a Set<String> to send to C++
       final inputSet = <String>{"Apple", "Banana", "Cherry"};
       
-      // Convert to StdSet using convenience constructor
+      // Bulk convenience constructor copies the whole collection in one call
       final stdInputSet = StdSet_String.fromSet(inputSet);
-      
-      // Test sending StdSet to C++ and getting result back
-      final resultSet = t.testStdSet(stdInputSet);
+      expect(stdInputSet.length, inputSet.length);
+
+      // Dart collections are passed to C++ directly and getting result back
+      final resultSet = t.testStdSet(inputSet);
       expect(resultSet, isA<StdSet_int>());
       // Set should contain lengths of strings
       expect(resultSet.contains(5), true); // "Apple" length
@@ -431,17 +436,277 @@ void main() {
       expect(resultSet.contains(6), true); // "Cherry" length
     });
 
+    test('test container iteration and conversion', () async {
+      final vec = ContainerFactory.makeVector();
+      expect(vec.toList(), [1, 2, 3]);
+      final seen = <int>[];
+      vec.forEach(seen.add);
+      expect(seen, [1, 2, 3]);
+      // Breaking out early leaves the native iterator to the finalizer, iterating again starts over
+      for (final value in vec.values) {
+        if (value == 2) {
+          break;
+        }
+      }
+      expect(vec.values.take(2).toList(), [1, 2]);
+      expect(StdVector_int.fromList([4, 5]).toList(), [4, 5]);
+
+      final map = ContainerFactory.makeMap();
+      expect(map.toMap(), {'a': 1, 'b': 2});
+      expect(map.entries.toList(), [('a', 1), ('b', 2)]);
+      expect(map.keys.toList(), ['a', 'b']);
+      final sums = <String, int>{};
+      map.forEach((key, value) => sums[key] = value * 10);
+      expect(sums, {'a': 10, 'b': 20});
+      expect(StdMap_String_int.fromMap({'x': 7}).toMap(), {'x': 7});
+
+      // Values with the same key are grouped in toMap() and kept apart in toList()
+      final multimap = ContainerFactory.makeMultimap();
+      expect(multimap.toMap(), {'a': [1, 2], 'b': [3]});
+      expect(multimap.toList(), [('a', 1), ('a', 2), ('b', 3)]);
+      expect(StdMultimap_String_int.fromEntries([('k', 1), ('k', 2)]).toList(), [('k', 1), ('k', 2)]);
+
+      final set = ContainerFactory.makeSet();
+      expect(set.toSet(), {1, 2, 3});
+      expect(set.toList(), [1, 2, 3]);
+      expect(StdSet_int.fromSet({8, 9}).toSet(), {8, 9});
+    });
+
+    test('test std::list, std::deque, std::multimap and nested containers', () async {
+      expect(SequenceUtils.reversed(['a', 'b', 'c']).toList(), ['c', 'b', 'a']);
+      expect(SequenceUtils.withFront([2, 3], 1).toList(), [1, 2, 3]);
+
+      final byLength = SequenceUtils.byLength(['a', 'bb', 'cc']);
+      expect(byLength.length, 3);
+      expect(byLength.count(2), 2);
+      expect(byLength.toMap(), {1: ['a'], 2: ['bb', 'cc']});
+
+      final grid = SequenceUtils.grid(2, 3);
+      expect(grid.toList().map((row) => row.toList()).toList(), [[0, 1, 2], [3, 4, 5]]);
+
+      final groups = SequenceUtils.groups();
+      expect(groups['odd']?.toList(), [1, 3]);
+      expect(groups.keys.toList(), ['even', 'odd']);
+    });
+
+    test('test std::pair and std::tuple records', () async {
+      final t = TestClass.Constructor();
+      expect(t.swapPair(("a", 1)), (1, "a"));
+      expect(t.makeTuple(2, 0.5, true), (2, 0.5, true));
+    });
+
+    test('test std::variant sealed classes', () async {
+      final number = VariantEcho.echo(const StdVariant_int_StringValue0(3));
+      expect(number, isA<StdVariant_int_StringValue0>());
+      expect((number as StdVariant_int_StringValue0).value, 3);
+
+      // The sealed class can be matched exhaustively
+      final text = VariantEcho.echo(const StdVariant_int_StringValue1('hi'));
+      final described = switch (text) {
+        StdVariant_int_StringValue0(value: final value) => 'int $value',
+        StdVariant_int_StringValue1(value: final value) => 'string $value',
+      };
+      expect(described, 'string hi');
+      expect(VariantEcho.alternativeIndex(const StdVariant_int_StringValue1('x')), 1);
+    });
+
+    test('test std::chrono as Duration and DateTime', () async {
+      expect(TimeUtils.doubled(const Duration(milliseconds: 1500)), const Duration(seconds: 3));
+
+      final start = DateTime.utc(2024, 1, 1);
+      final later = TimeUtils.addSeconds(start, 90);
+      expect(later, DateTime.utc(2024, 1, 1, 0, 1, 30));
+      expect(later.isUtc, isTrue);
+    });
+
+    test('test typedef and using aliases', () async {
+      const UserId id = 1;
+      final NameList names = UserDirectory.names(id, 2);
+      expect(names, isA<StdVector_String>());
+      expect(names.toList(), ['user1', 'user2']);
+
+      final MaybeName found = UserDirectory.findName(id);
+      expect(found, 'root');
+      expect(UserDirectory.findName(2), isNull);
+    });
+
+    test('test std::optional params', () async {
+      final t = TestClass.Constructor();
+      expect(t.optionalOrDefault(5, 1), 5);
+      expect(t.optionalOrDefault(null, 1), 1);
+    });
+
+    test('test unique_ptr ownership', () async {
+      final t = TestClass.Constructor();
+
+      final s = t.makeUniqueStruct(7);
+      // const& only borrows, the dart object is still usable
+      expect(t.peekUniqueStruct(s), 7);
+      expect(s.id, 7);
+      // By value takes ownership, later use throws instead of crashing
+      expect(t.consumeUniqueStruct(s), 7);
+      expect(() => s.id, throwsStateError);
+      expect(() => t.consumeUniqueStruct(s), throwsStateError);
+
+      // A borrowed wrapper does not own its native object and cannot transfer it
+      final owner = t.makeUniqueStruct(8);
+      final borrowed = SimpleStruct.FromNative(owner.getNativePtr());
+      expect(() => t.consumeUniqueStruct(borrowed), throwsStateError);
+      expect(owner.id, 8);
+    });
+
+    test('test StdMap with class values', () async {
+      final t = TestClass.Constructor();
+
+      final m = t.testStdMapStructValue(["a", "b"]);
+      expect(m.length, 2);
+      expect(m["b"]?.id, 1);
+      expect(m.find("a")?.name, "a");
+      // Missing keys return null instead of a default constructed object
+      expect(m["missing"], isNull);
+    });
+
+    test('test operators', () async {
+      final a = Vec2.Constructor()
+        ..x = 1
+        ..y = 2;
+      final b = Vec2.Constructor()
+        ..x = 1
+        ..y = 2;
+      final sum = a + b;
+      expect(sum.x, 2);
+      expect(sum.y, 4);
+      // Equal objects must have equal hash codes
+      expect(a == b, isTrue);
+      expect(a.hashCode, b.hashCode);
+      expect(a == sum, isFalse);
+    });
+
+    test('test by-value class return is owned by dart', () async {
+      final before = TrackedItem.liveCount();
+      final item = TrackedItem.make(3);
+      expect(item.value, 3);
+      expect(TrackedItem.liveCount(), before + 1);
+      // Only the owner of the native copy can hand it over, borrowed objects throw here
+      final owner = TrackedItem.FromNative(item.nativeRelease());
+      owner.Destructor();
+      expect(TrackedItem.liveCount(), before);
+    });
+
+    test('test fixed-length array fields', () async {
+      final holder = ArrayHolder.Constructor();
+      holder.values = Int32List.fromList([1, 2, 3]);
+      holder.weights = Float32List.fromList([0.5, 1.5]);
+
+      final values = holder.values;
+      expect(values, [1, 2, 3]);
+      expect(holder.weights, [0.5, 1.5]);
+      // The getter returns a copy that stays valid after the holder is released
+      values[0] = 10;
+      expect(holder.values[0], 1);
+
+      expect(() => holder.values = Int32List(2), throwsArgumentError);
+    });
+
+    test('test fixed-length array aliases', () async {
+      final holder = AliasArrayHolder.Constructor();
+      holder.position = Int32List.fromList([1, 2, 3]);
+      holder.gains = Float32List.fromList([0.25, 0.75]);
+      expect(holder.position, [1, 2, 3]);
+      expect(holder.gains, [0.25, 0.75]);
+    });
+
+    test('test constants', () async {
+      expect(kAnswer, 42);
+      // UINT64_MAX is emitted as a hex literal with the same bit pattern
+      expect(kAllBits, -1);
+      expect(TEST_MAX_COUNT, -1);
+      expect(TEST_OFFSET, -8);
+    });
+
+    test('test global variables', () async {
+      gCounter = 5;
+      expect(gCounter, 5);
+      // const variables that can't be evaluated at compile time are read through getters
+      expect(kExternLimit, 7);
+      expect(kGreeting, 'hello');
+      // Namespace-scope variables are accessed by their qualified name in the shim
+      expect(gLevel, 1);
+      gLevel = 3;
+      expect(gLevel, 3);
+    });
+
+    test('test %buffer params', () async {
+      final sink = ByteSink.Constructor();
+      expect(sink.write(Uint8List.fromList([1, 2, 3])), 6);
+      expect(sink.write(Uint8List(0)), 6);
+    });
+
+    test('test std::function fields', () async {
+      final holder = FunctionHolder.Constructor();
+      expect(holder.transform, isNull);
+      expect(holder.apply(1), -1);
+
+      int doubleIt(int v) => v * 2;
+      holder.transform = doubleIt;
+      expect(holder.apply(21), 42);
+      expect(holder.transform, same(doubleIt));
+
+      holder.transform = null;
+      expect(holder.transform, isNull);
+      expect(holder.apply(1), -1);
+    });
+
+    test('test field properties', () async {
+      final item = NamedItem.Constructor();
+
+      // Fields are dart properties in lowerCamelCase
+      item.count = 3;
+      expect(item.count, 3);
+
+      // item_name collides with the itemName() method, so it keeps get_/set_ methods
+      item.set_item_name("a");
+      expect(item.get_item_name(), "a");
+      expect(item.itemName(), "method:a");
+    });
+
+    test('test strings with embedded NUL', () async {
+      final t = TestClass.Constructor();
+
+      // Strings are passed and returned with explicit lengths
+      expect(t.echoBytes("a\u0000b"), "a\u0000b");
+      expect(t.echoBytes("中文"), "中文");
+
+      // The generated length parameter does not collide with data_len
+      expect(t.countBytes("abc", 10), 13);
+
+      // Constructor string params keep embedded NULs as well
+      final blob = Blob.Constructor_String("a\u0000b");
+      expect(blob.size(), 3);
+    });
+
+    test('test POD struct by value', () async {
+      final t = TestClass.Constructor();
+
+      // @pod structs are dart Structs, created without native allocation
+      final result = t.addPodPoint(PodPoint.create(x: 1, y: 2), PodPoint.create(x: 3, y: 4));
+      expect(result, isA<PodPoint>());
+      expect(result.x, 4);
+      expect(result.y, 6);
+    });
+
     test('test StdUnorderedSet', () async {
       final t = TestClass.Constructor();
       
       // Create a Set<int> to send to C++
       final inputSet = <int>{10, 20, 30};
       
-      // Convert to StdUnorderedSet using convenience constructor
+      // Bulk convenience constructor copies the whole collection in one call
       final stdInputSet = StdUnorderedSet_int.fromSet(inputSet);
-      
-      // Test sending StdUnorderedSet to C++ and getting result back
-      final resultSet = t.testStdUnorderedSet(stdInputSet);
+      expect(stdInputSet.length, inputSet.length);
+
+      // Dart collections are passed to C++ directly and getting result back
+      final resultSet = t.testStdUnorderedSet(inputSet);
       expect(resultSet, isA<StdUnorderedSet_String>());
       // Set should contain string representations of numbers
       expect(resultSet.contains("10"), true);
@@ -456,10 +721,10 @@ void main() {
       final point = Point.Constructor();
       
       // Test setting and getting values
-      point.set_x(10);
-      point.set_y(20);
-      expect(point.get_x(), 10);
-      expect(point.get_y(), 20);
+      point.x = 10;
+      point.y = 20;
+      expect(point.x, 10);
+      expect(point.y, 20);
     });
 
     test('test SimpleA class', () async {
@@ -475,13 +740,13 @@ void main() {
 
       // Test Point interaction
       final point = Point.Constructor();
-      point.set_x(100);
-      point.set_y(200);
+      point.x = 100;
+      point.y = 200;
       simpleA.setPosition(point);
 
       final retrievedPoint = simpleA.getPosition();
-      expect(retrievedPoint.get_x(), 100);
-      expect(retrievedPoint.get_y(), 200);
+      expect(retrievedPoint.x, 100);
+      expect(retrievedPoint.y, 200);
 
       // Test Color enum
       simpleA.setColor(Color.red);
@@ -529,8 +794,8 @@ void main() {
       
       // Test complex cross-file method
       final inputPoint = Point.Constructor();
-      inputPoint.set_x(10);
-      inputPoint.set_y(20);
+      inputPoint.x = 10;
+      inputPoint.y = 20;
       
       final resultPoint = simpleB.processWithA(simpleA, inputPoint);
       expect(resultPoint, isNotNull);
@@ -3,3 +3,5 @@
 %include "simple_a.hpp"
 %include "simple_b.hpp"
 %include "test.hpp"
+
+%buffer ByteSink::write(data, len)
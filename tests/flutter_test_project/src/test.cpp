@@ -3,6 +3,10 @@
 #include <vector>   // Add include for std::vector
 #include <string>   // Add include for std::string
 
+int gCounter = 0;
+extern const int kExternLimit = 7;
+int app::gLevel = 1;
+
 TestClass::TestClass() {
     std::cout << "TestClass Constructor called" << std::endl;
 }
@@ -194,6 +198,42 @@ std::map<std::string, std::string> TestClass::testStdMapStringString(std::map<st
     return result;
 }
 
+std::pair<int, std::string> TestClass::swapPair(std::pair<std::string, int> p) {
+    return std::make_pair(p.second, p.first);
+}
+
+std::tuple<int, double, bool> TestClass::makeTuple(int i, double d, bool b) {
+    return std::make_tuple(i, d, b);
+}
+
+int TestClass::optionalOrDefault(std::optional<int> value, int fallback) {
+    return value.value_or(fallback);
+}
+
+std::unique_ptr<SimpleStruct> TestClass::makeUniqueStruct(int id) {
+    return std::unique_ptr<SimpleStruct>(new SimpleStruct{id, "unique"});
+}
+
+int TestClass::consumeUniqueStruct(std::unique_ptr<SimpleStruct> s) {
+    return s ? s->id : -1;
+}
+
+int TestClass::peekUniqueStruct(const std::unique_ptr<SimpleStruct>& s) {
+    return s ? s->id : -1;
+}
+
+std::unique_ptr<int> TestClass::makeUniqueInt(int value) {
+    return std::make_unique<int>(value);
+}
+
+std::map<std::string, SimpleStruct> TestClass::testStdMapStructValue(std::vector<std::string> names) {
+    std::map<std::string, SimpleStruct> result;
+    for (size_t i = 0; i < names.size(); i++) {
+        result[names[i]] = SimpleStruct{(int)i, names[i]};
+    }
+    return result;
+}
+
 // Test callback methods with return values
 int TestClass::testCallbackComputeSum(int a, int b) {
     std::cout << "C++: Testing callback onComputeSum(" << a << ", " << b << ")" << std::endl;
@@ -250,3 +290,15 @@ int TestClass::testCallbackCalculateAsync(int x, int y) {
     std::cout << "C++: No callback registered" << std::endl;
     return 0;
 }
+
+PodPoint TestClass::addPodPoint(PodPoint a, PodPoint b) {
+    return PodPoint{a.x + b.x, a.y + b.y};
+}
+
+std::string TestClass::echoBytes(const std::string& data) {
+    return data;
+}
+
+size_t TestClass::countBytes(const std::string& data, size_t data_len) {
+    return data.size() + data_len;
+}
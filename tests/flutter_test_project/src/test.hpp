@@ -9,6 +9,16 @@
 #include <set>      // Add include for std::set
 #include <unordered_set> // Add include for std::unordered_set
 #include <memory>   // Add include for std::shared_ptr
+#include <array>
+#include <optional>
+#include <utility>
+#include <tuple>
+#include <functional>
+#include <variant>
+#include <list>
+#include <deque>
+#include <chrono>
+#include <cstdint>
 
 // Define a simple struct for testing
 struct SimpleStruct {
@@ -16,6 +26,169 @@ struct SimpleStruct {
     std::string name;
 };
 
+// Field whose dart property name collides with a method keeps get_/set_ accessors
+struct NamedItem {
+    std::string item_name;
+    int count = 0;
+    std::string itemName() const { return "method:" + item_name; }
+};
+
+// Operator overloads, without std::hash the dart hashCode is a constant
+struct Vec2 {
+    int x = 0;
+    int y = 0;
+    Vec2 operator+(const Vec2& other) const { return Vec2{x + other.x, y + other.y}; }
+    bool operator==(const Vec2& other) const { return x == other.x && y == other.y; }
+};
+
+// Fixed-length array fields, dart getters return a copy
+struct ArrayHolder {
+    int values[3] = {0, 0, 0};
+    std::array<float, 2> weights = {0.0f, 0.0f};
+};
+
+// typedef / using aliases map to the underlying types and keep their names as dart typedefs
+using UserId = int64_t;
+typedef std::vector<std::string> NameList;
+using MaybeName = std::optional<std::string>;
+struct UserDirectory {
+    static NameList names(UserId first, UserId count) {
+        NameList result;
+        for (UserId id = first; id < first + count; id++) {
+            result.push_back("user" + std::to_string(id));
+        }
+        return result;
+    }
+    static MaybeName findName(UserId id) {
+        if (id == 1) {
+            return "root";
+        }
+        return std::nullopt;
+    }
+};
+
+// Array aliases keep the copy semantics, dart uses Int32List / Float32List instead of the alias names
+typedef int Vec3[3];
+using Weights = std::array<float, 2>;
+struct AliasArrayHolder {
+    Vec3 position = {0, 0, 0};
+    Weights gains = {0.0f, 0.0f};
+};
+
+// std::function field, the dart getter returns the closure that was set, or null
+struct FunctionHolder {
+    std::function<int(int)> transform;
+    int apply(int value) const { return transform ? transform(value) : -1; }
+};
+
+// Constants, unsigned values above INT64_MAX keep their bit pattern in dart
+constexpr int kAnswer = 42;
+constexpr uint64_t kAllBits = 0xFFFFFFFFFFFFFFFFull;
+#define TEST_MAX_COUNT 0xFFFFFFFFFFFFFFFFull
+#define TEST_OFFSET (-8)
+
+// Global variables; const values that can't be evaluated at compile time get a getter
+extern int gCounter;
+extern const int kExternLimit;
+inline const std::string kGreeting = "hello";
+namespace app {
+extern int gLevel;
+}
+
+// Pointer and length parameters merged into a TypedData by %buffer in TestModule.i
+struct ByteSink {
+    size_t total = 0;
+    size_t write(const uint8_t* data, size_t len) {
+        for (size_t i = 0; i < len; i++) {
+            total += data[i];
+        }
+        return total;
+    }
+};
+
+// Containers returned by value, iterated and converted to dart collections
+struct ContainerFactory {
+    static std::vector<int> makeVector() { return {1, 2, 3}; }
+    static std::map<std::string, int> makeMap() { return {{"a", 1}, {"b", 2}}; }
+    static std::multimap<std::string, int> makeMultimap() { return {{"a", 1}, {"a", 2}, {"b", 3}}; }
+    static std::set<int> makeSet() { return {3, 1, 2}; }
+};
+
+// std::list, std::deque, std::multimap and nested containers
+struct SequenceUtils {
+    static std::list<std::string> reversed(const std::list<std::string>& values) {
+        return std::list<std::string>(values.rbegin(), values.rend());
+    }
+    static std::deque<int> withFront(std::deque<int> values, int front) {
+        values.push_front(front);
+        return values;
+    }
+    static std::multimap<size_t, std::string> byLength(const std::vector<std::string>& words) {
+        std::multimap<size_t, std::string> result;
+        for (const auto& word : words) {
+            result.insert({word.size(), word});
+        }
+        return result;
+    }
+    static std::vector<std::vector<int>> grid(int rows, int cols) {
+        std::vector<std::vector<int>> result(rows);
+        for (int row = 0; row < rows; row++) {
+            for (int col = 0; col < cols; col++) {
+                result[row].push_back(row * cols + col);
+            }
+        }
+        return result;
+    }
+    static std::map<std::string, std::vector<int>> groups() { return {{"even", {2, 4}}, {"odd", {1, 3}}}; }
+};
+
+// std::variant is a dart sealed class with one subclass per alternative
+struct VariantEcho {
+    static std::variant<int, std::string> echo(const std::variant<int, std::string>& value) { return value; }
+    static size_t alternativeIndex(const std::variant<int, std::string>& value) { return value.index(); }
+};
+
+// std::chrono durations and system_clock time points are dart Duration and UTC DateTime
+struct TimeUtils {
+    static std::chrono::milliseconds doubled(std::chrono::milliseconds value) { return value * 2; }
+    static std::chrono::system_clock::time_point addSeconds(std::chrono::system_clock::time_point time, int seconds) {
+        return time + std::chrono::seconds(seconds);
+    }
+};
+
+// Counts live instances, a by-value return is a copy released with its dart object
+struct TrackedItem {
+    int value = 0;
+    TrackedItem() { ++counter(); }
+    TrackedItem(const TrackedItem& other) : value(other.value) { ++counter(); }
+    ~TrackedItem() { --counter(); }
+    static TrackedItem make(int value) {
+        TrackedItem item;
+        item.value = value;
+        return item;
+    }
+    static int liveCount() { return counter(); }
+private:
+    static int& counter() {
+        static int count = 0;
+        return count;
+    }
+};
+
+// Constructor string params are passed with explicit lengths too
+struct Blob {
+    std::string data;
+    explicit Blob(const std::string& bytes) : data(bytes) {}
+    size_t size() const { return data.size(); }
+};
+
+// POD struct passed by value, opt-in with @pod
+/// @pod
+struct PodPoint {
+    int x;
+    int y;
+};
+
 // Define a callback interface (abstract class)
 class MyCallback {
 public:
@@ -95,6 +268,23 @@ public:
     // Test string-to-string map
     std::map<std::string, std::string> testStdMapStringString(std::map<std::string, std::string> m);
 
+    // std::pair and std::tuple as dart records
+    std::pair<int, std::string> swapPair(std::pair<std::string, int> p);
+    std::tuple<int, double, bool> makeTuple(int i, double d, bool b);
+
+    // std::optional params, null is passed as an empty optional
+    int optionalOrDefault(std::optional<int> value, int fallback);
+
+    // unique_ptr ownership: by value takes ownership, const& only borrows
+    std::unique_ptr<SimpleStruct> makeUniqueStruct(int id);
+    int consumeUniqueStruct(std::unique_ptr<SimpleStruct> s);
+    int peekUniqueStruct(const std::unique_ptr<SimpleStruct>& s);
+    // unique_ptr to a non-class type has no dart class, the method is skipped
+    std::unique_ptr<int> makeUniqueInt(int value);
+
+    // Test map with class values, find() returns null for missing keys
+    std::map<std::string, SimpleStruct> testStdMapStructValue(std::vector<std::string> names);
+
     // Test callback methods with return values
     int testCallbackComputeSum(int a, int b);
     double testCallbackComputeAverage(double x, double y);
@@ -105,6 +295,13 @@ public:
 
     // Test async callback with return value
     int testCallbackCalculateAsync(int x, int y);
+
+    // Test POD struct passed by value
+    PodPoint addPodPoint(PodPoint a, PodPoint b);
+
+    // Test strings with embedded NUL, and a declared parameter named like the generated length
+    std::string echoBytes(const std::string& data);
+    size_t countBytes(const std::string& data, size_t data_len);
 };
 
 #endif // TEST_HPP
\ No newline at end of file